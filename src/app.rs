@@ -7,6 +7,13 @@ use crate::features::data::state::ImportState;
 use crate::features::cat::logic::CatListState;
 use crate::features::enemy::logic::state::EnemyListState;
 use crate::features::stage::logic::state::StageListState;
+use crate::features::combo::logic::state::ComboListState;
+use crate::features::lineup::logic::state::LineupState;
+use crate::features::gacha::logic::state::GachaState;
+use crate::features::schedule::logic::state::ScheduleState;
+use crate::features::planner::logic::state::PlannerState;
+use crate::features::items::logic::state::ItemsState;
+use crate::features::farming::logic::state::FarmingState;
 use crate::features::mods::logic::state::ModState;
 use crate::features::settings::logic::Settings;
 
@@ -31,6 +38,13 @@ pub struct BattleCatsApp {
     pub(crate) cat_list_state: CatListState,
     pub(crate) enemy_list_state: EnemyListState,
     pub(crate) stage_list_state: StageListState,
+    pub(crate) combo_list_state: ComboListState,
+    pub(crate) lineup_state: LineupState,
+    pub(crate) gacha_state: GachaState,
+    pub(crate) schedule_state: ScheduleState,
+    pub(crate) planner_state: PlannerState,
+    pub(crate) items_state: ItemsState,
+    pub(crate) farming_state: FarmingState,
     pub(crate) mod_state: ModState,
     pub settings: Settings,
 }
@@ -44,6 +58,13 @@ impl Default for BattleCatsApp {
             cat_list_state: CatListState::default(),
             enemy_list_state: EnemyListState::default(),
             stage_list_state: StageListState::default(),
+            combo_list_state: ComboListState::default(),
+            lineup_state: LineupState::default(),
+            gacha_state: GachaState::default(),
+            schedule_state: ScheduleState::default(),
+            planner_state: PlannerState::default(),
+            items_state: ItemsState::default(),
+            farming_state: FarmingState::default(),
             mod_state: ModState::default(),
             settings: Settings::default(),
             updater: Updater::default(),
@@ -100,7 +121,8 @@ impl eframe::App for BattleCatsApp {
         self.enemy_list_state.update_data();
         self.stage_list_state.update_data();
 
-        self.stage_list_state.sync_enemies(&self.enemy_list_state.enemies);
+        self.stage_list_state.sync_enemies(&self.enemy_list_state.enemies, self.enemy_list_state.generation);
+        self.cat_list_state.sync_drops(&self.stage_list_state.drop_index);
 
         if self.cat_list_state.scan_receiver.is_some() || self.enemy_list_state.scan_receiver.is_some() || self.stage_list_state.scan_receiver.is_some() {
             ctx.request_repaint();
@@ -6,6 +6,13 @@ use crate::features::home;
 use crate::features::cat::logic::show as show_cats;
 use crate::features::enemy::logic::state::show as show_enemies;
 use crate::features::stage::ui::master::show as show_stages;
+use crate::features::combo::ui::master::show as show_combos;
+use crate::features::lineup::ui::master::show as show_lineup;
+use crate::features::gacha::ui::master::show as show_gacha;
+use crate::features::schedule::ui::master::{show as show_schedule, ScheduleAction};
+use crate::features::planner::ui::master::show as show_planner;
+use crate::features::items::ui::master::show as show_items;
+use crate::features::farming::ui::master::show as show_farming;
 use crate::features::mods::ui::frame::show as show_mods;
 use crate::features::data::ui::manager::show as show_data;
 use crate::features::settings::ui::show as show_settings;
@@ -16,6 +23,13 @@ pub enum Page {
     Cats,
     Enemies,
     Stages,
+    Combos,
+    Lineup,
+    Gacha,
+    Events,
+    Planner,
+    Items,
+    Farming,
     Mods,
     Data,
     Settings,
@@ -28,6 +42,13 @@ impl Page {
             Self::Cats => "Cats",
             Self::Enemies => "Enemies",
             Self::Stages => "Stages",
+            Self::Combos => "Combos",
+            Self::Lineup => "Lineup",
+            Self::Gacha => "Gacha",
+            Self::Events => "Events",
+            Self::Planner => "Planner",
+            Self::Items => "Items",
+            Self::Farming => "Farming",
             Self::Mods => "Mods",
             Self::Data => "Data",
             Self::Settings => "Settings",
@@ -40,6 +61,13 @@ pub const ALL_PAGES: &[Page] = &[
     Page::Cats,
     Page::Enemies,
     #[cfg(debug_assertions)] Page::Stages,
+    Page::Combos,
+    Page::Lineup,
+    Page::Gacha,
+    Page::Events,
+    Page::Planner,
+    Page::Items,
+    Page::Farming,
     Page::Mods,
     Page::Data,
     Page::Settings,
@@ -83,8 +111,66 @@ pub fn draw(app: &mut BattleCatsApp, ctx: &egui::Context) {
     match app.current_page {
         Page::Home => home::show(ctx, &mut app.drag_guard),
         Page::Cats => show_cats(ctx, &mut app.cat_list_state, &mut app.settings, &app.param),
-        Page::Enemies => show_enemies(ctx, &mut app.enemy_list_state, &mut app.settings, &app.param),            
+        Page::Enemies => {
+            let clicked_stage = show_enemies(ctx, &mut app.enemy_list_state, &mut app.settings, &app.param, &app.stage_list_state.enemy_index);
+            if let Some(stage_key) = clicked_stage {
+                app.stage_list_state.select_stage(&stage_key);
+                app.current_page = Page::Stages;
+            }
+        },
         Page::Stages => show_stages(ctx, &mut app.stage_list_state, &mut app.settings),
+        Page::Combos => {
+            let clicked_unit = show_combos(ctx, &mut app.combo_list_state, &app.cat_list_state.cats, &app.cat_list_state.combo_data);
+            if let Some((cat_id, form)) = clicked_unit {
+                app.cat_list_state.select_cat(cat_id, form);
+                app.current_page = Page::Cats;
+            }
+        },
+        Page::Lineup => show_lineup(ctx, &mut app.lineup_state, &app.cat_list_state.cats, &app.cat_list_state.combo_data, &app.stage_list_state.registry),
+        Page::Gacha => {
+            let clicked_unit = show_gacha(ctx, &mut app.gacha_state, &app.cat_list_state.cats, &app.cat_list_state.gacha_data);
+            if let Some((cat_id, form)) = clicked_unit {
+                app.cat_list_state.select_cat(cat_id, form);
+                app.current_page = Page::Cats;
+            }
+        },
+        Page::Events => {
+            let action = show_schedule(
+                ctx,
+                &mut app.schedule_state,
+                &app.cat_list_state.cats,
+                &app.cat_list_state.gacha_data,
+                &app.stage_list_state.registry,
+                &app.stage_list_state.item_name_registry,
+            );
+            match action {
+                Some(ScheduleAction::OpenBanner(kind, id)) => {
+                    app.gacha_state.kind = kind;
+                    app.gacha_state.selected_banner = Some(id);
+                    app.current_page = Page::Gacha;
+                },
+                Some(ScheduleAction::OpenCat(cat_id)) => {
+                    app.cat_list_state.select_cat(cat_id, 0);
+                    app.current_page = Page::Cats;
+                },
+                None => {},
+            }
+        },
+        Page::Planner => show_planner(ctx, &mut app.planner_state, &app.cat_list_state.cats, &app.stage_list_state),
+        Page::Items => {
+            let clicked_stage = show_items(ctx, &mut app.items_state, &app.stage_list_state);
+            if let Some(stage_key) = clicked_stage {
+                app.stage_list_state.select_stage(&stage_key);
+                app.current_page = Page::Stages;
+            }
+        },
+        Page::Farming => {
+            let clicked_stage = show_farming(ctx, &mut app.farming_state, &app.stage_list_state, &app.cat_list_state.cats);
+            if let Some(stage_key) = clicked_stage {
+                app.stage_list_state.select_stage(&stage_key);
+                app.current_page = Page::Stages;
+            }
+        },
         Page::Mods => show_mods(ctx, &mut app.mod_state, &mut app.settings),
         Page::Data => {
             egui::CentralPanel::default().show(ctx, |ui| {
@@ -14,6 +14,11 @@ impl BattleCatsApp {
         self.cat_list_state.img022_sheets.clear();
         self.cat_list_state.sprite_sheet = SpriteSheet::default();
         self.cat_list_state.gatya_item_textures.clear();
+        self.combo_list_state.icon_cache.clear();
+        self.lineup_state.icon_cache.clear();
+        self.gacha_state.icon_cache.clear();
+        self.planner_state.item_cache.clear();
+        self.items_state.icon_cache.clear();
         
         self.enemy_list_state.anim_viewer.loaded_id.clear();
         self.enemy_list_state.detail_texture = None;
@@ -45,6 +50,7 @@ impl BattleCatsApp {
 
         self.stage_list_state.registry.clear_cache();
         self.stage_list_state.restart_scan(config);
+        self.schedule_state.reload(&self.settings.general.language_priority);
         
         self.param = load_param(Path::new("game/tables"), &self.settings.general.language_priority).unwrap_or_default();
     }
@@ -47,6 +47,7 @@ impl BattleCatsApp {
                 cat
             }).collect();
             app.cat_list_state.initialized = true;
+            crate::features::cat::logic::loader::load_tables(&mut app.cat_list_state, priority);
         } else {
             app.cat_list_state.restart_scan(app.settings.scanner_config());
         }
@@ -61,6 +62,7 @@ impl BattleCatsApp {
         }
 
         app.stage_list_state.restart_scan(app.settings.scanner_config());
+        app.schedule_state.reload(&app.settings.general.language_priority);
 
         if needs_validation {
             let (tx, rx) = std::sync::mpsc::channel();
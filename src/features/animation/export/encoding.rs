@@ -10,9 +10,8 @@ use zip::write::FileOptions;
 use webp_animation::Encoder as WebpEncoder;
 use gif::{Encoder as GifEncoder, Frame as GifFrame, Repeat as GifRepeat, DisposalMethod};
 
-use crate::features::animation::logic::canvas::GlowRenderer;
-use crate::global::formats::imgcut::SpriteSheet;
-use crate::features::animation::logic::transform::WorldTransform;
+
+pub const MAX_SUPERSAMPLE: u32 = 4;
 
 // SHARED DATA STRUCTURES
 #[derive(Clone, Debug)]
@@ -181,19 +180,23 @@ pub fn encode_native(
     is_success
 }
 
-pub fn render_frame(
-    renderer: &mut GlowRenderer,
+// Offscreen pass shared by single units and scenes, `draw` paints everything into the target
+// Supersampled passes render `scale` times larger, so `draw` has to multiply its zoom by it
+pub fn render_layers(
     gl: &glow::Context,
-    width: u32,
-    height: u32,
-    parts: &[WorldTransform],
-    sheet: &SpriteSheet,
-    pan: egui::Vec2,
-    zoom: f32,
+    output_width: u32,
+    output_height: u32,
     bg_color: [u8; 4],
+    supersample: u32,
+    draw: impl FnOnce(&glow::Context, egui::Rect, f32),
 ) -> Vec<u8> {
     unsafe {
         gl.disable(glow::SCISSOR_TEST);
+
+        // Big regions fall back to a lower factor rather than past what the driver can allocate
+        let max_size = gl.get_parameter_i32(glow::MAX_TEXTURE_SIZE).max(1) as u32;
+        let factor = supersample.clamp(1, MAX_SUPERSAMPLE).min(max_size / output_width.max(output_height).max(1)).max(1);
+        let (width, height) = (output_width * factor, output_height * factor);
         
         let framebuffer = gl.create_framebuffer().expect("Failed to create OpenGL framebuffer");
         gl.bind_framebuffer(glow::FRAMEBUFFER, Some(framebuffer));
@@ -217,7 +220,7 @@ pub fn render_frame(
         gl.clear_color(r, g, b, a);
         gl.clear(glow::COLOR_BUFFER_BIT);
         
-        renderer.paint(gl, egui::Rect::from_min_size(egui::pos2(0.0, 0.0), egui::vec2(width as f32, height as f32)), parts, sheet, pan, zoom, true);
+        draw(gl, egui::Rect::from_min_size(egui::pos2(0.0, 0.0), egui::vec2(width as f32, height as f32)), factor as f32);
         gl.pixel_store_i32(glow::PACK_ALIGNMENT, 1);
         
         let mut pixel_buffer = vec![0u8; (width * height * 4) as usize];
@@ -230,8 +233,36 @@ pub fn render_frame(
         gl.enable(glow::SCISSOR_TEST);
         gl.pixel_store_i32(glow::PACK_ALIGNMENT, 4);
         
-        pixel_buffer
+        downsample(&pixel_buffer, output_width, output_height, factor)
+    }
+}
+
+// Box filter, every output pixel is the average of its factor × factor block
+// The renderer blends premultiplied, so colour and alpha can be averaged as they are
+pub fn downsample(pixels: &[u8], width: u32, height: u32, factor: u32) -> Vec<u8> {
+    if factor <= 1 { return pixels.to_vec(); }
+
+    let (width, height, factor) = (width as usize, height as usize, factor as usize);
+    let source_width = width * factor;
+    let samples = (factor * factor) as u32;
+    let mut output = vec![0u8; width * height * 4];
+
+    for y in 0..height {
+        for x in 0..width {
+            let mut sums = [0u32; 4];
+            for sample_y in 0..factor {
+                let row_start = ((y * factor + sample_y) * source_width + x * factor) * 4;
+                for pixel in pixels[row_start..row_start + factor * 4].chunks_exact(4) {
+                    for (sum, value) in sums.iter_mut().zip(pixel) { *sum += *value as u32; }
+                }
+            }
+            let output_index = (y * width + x) * 4;
+            for (channel, sum) in sums.iter().enumerate() {
+                output[output_index + channel] = ((sum + samples / 2) / samples) as u8;
+            }
+        }
     }
+    output
 }
 
 pub fn prepare_image(mut pixel_buffer: Vec<u8>, width: u32, height: u32, is_opaque_bg: bool) -> RgbaImage {
@@ -1,111 +1,161 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
 use std::sync::{mpsc, Arc, atomic::{AtomicBool, Ordering}};
 use std::thread;
-use std::time::{Duration, Instant};
 
-use crate::global::formats::mamodel::Model;
-use crate::global::formats::maanim::Animation;
-use crate::features::animation::logic::{animator, transform};
+use crate::global::formats::mamodel::{Model, ModelPart};
+use crate::global::formats::maanim::{self, Animation, AnimModification};
+use crate::features::animation::logic::animator;
 use crate::features::animation::export::state::LoopStatus;
 
-const TIMEOUT_SECONDS: u64 = 180;
+// Cycles longer than this can't be checked frame by frame, so they're reported as unconfirmed
+const CONFIRM_LIMIT: i64 = 50_000;
+// Animation frames are game frames, whatever rate the export runs at
+const GAME_FPS: i64 = 30;
+
+#[derive(Debug, Clone, Copy)]
+pub struct FoundLoop {
+    pub start: i32,
+    pub length: i64,
+    // False when the loop was too long to check by hashing, the curves alone still give it exactly
+    pub confirmed: bool,
+}
+
+pub struct LoopReport {
+    pub anim_index: usize,
+    pub result: Result<FoundLoop, String>,
+}
+
+pub fn frames_to_ms(frames: i64) -> i64 {
+    frames * 1000 / GAME_FPS
+}
 
 pub fn start_search(
     model: Model,
     anim: Animation,
-    tolerance: i32,
     min_loop: i32,
     max_loop: Option<i32>,
     status_tx: mpsc::Sender<LoopStatus>,
     abort_flag: Arc<AtomicBool>
 ) {
     thread::spawn(move || {
-        let start_time = Instant::now();
-        // Store Matrix [f32; 9] and Opacity (f32) for every part
-        let mut frame_states: Vec<Vec<([f32; 9], f32)>> = Vec::new();
-        
-        let mut current_frame = 0;
-        
-        loop {
-            // Check Abort or Timeout
-            if abort_flag.load(Ordering::Relaxed) {
-                let _ = status_tx.send(LoopStatus::Error("Aborted".to_string()));
-                break;
-            }
-            if start_time.elapsed().as_secs() > TIMEOUT_SECONDS {
-                let _ = status_tx.send(LoopStatus::Error("Timed out (3 mins)".to_string()));
-                break;
+        let FoundLoop { start: loop_start, length: loop_length, confirmed } = match find_loop(&model, &anim, &status_tx, &abort_flag) {
+            Ok(found) => found,
+            Err(message) => {
+                let _ = status_tx.send(LoopStatus::Error(message));
+                return;
             }
+        };
 
-            // Solve current frame
-            let f = current_frame as f32;
-            let parts = animator::animate(&model, &anim, f);
-            let world_parts = transform::solve_hierarchy(&parts, &model);
-            
-            // Extract comparable state (Matrix + Opacity)
-            // We compare indices 0,1,3,4 (Scale/Rot/Shear) and 6,7 (Translation X/Y)
-            let mut current_state = Vec::with_capacity(world_parts.len());
-            for part in &world_parts {
-                current_state.push((part.matrix, part.opacity));
-            }
-
-            // Compare against history
-            // We look backwards to find the first frame that matches current
-            let mut found_match = None;
-
-            for (past_frame_idx, past_state) in frame_states.iter().enumerate() {
-                let loop_len = current_frame - past_frame_idx as i32;
-                
-                // Min Loop Check
-                if loop_len < min_loop { continue; }
-                
-                // Max Loop Check
-                if let Some(max) = max_loop {
-                    if loop_len > max { continue; }
-                }
-
-                let mut diff_sum = 0.0;
-                
-                for (i, (c_mat, c_op)) in current_state.iter().enumerate() {
-                    if let Some((p_mat, p_op)) = past_state.get(i) {
-                        // Compare Matrix Elements
-                        diff_sum += (c_mat[6] - p_mat[6]).abs();
-                        diff_sum += (c_mat[7] - p_mat[7]).abs();
-
-                        // We weight these higher (x100) because small changes in scale/rot are very visible
-                        diff_sum += (c_mat[0] - p_mat[0]).abs() * 100.0;
-                        diff_sum += (c_mat[1] - p_mat[1]).abs() * 100.0;
-                        diff_sum += (c_mat[3] - p_mat[3]).abs() * 100.0;
-                        diff_sum += (c_mat[4] - p_mat[4]).abs() * 100.0;
-
-                        // Opacity, weight by 255 for "alpha byte" roughly
-                        diff_sum += (c_op - p_op).abs() * 255.0;
-                    }
-                }
-
-                if diff_sum <= tolerance as f32 {
-                    found_match = Some(past_frame_idx as i32);
-                    break; 
-                }
-            }
+        // Any whole number of cycles loops too, short ones get stretched up to the minimum
+        let cycles = ((min_loop.max(1) as i64).saturating_add(loop_length - 1) / loop_length).max(1);
+        let export_length = loop_length.saturating_mul(cycles);
+        let is_too_long = max_loop.is_some_and(|max| export_length > max as i64) || loop_start as i64 + export_length > i32::MAX as i64;
 
-            if let Some(start_f) = found_match {
-                let _ = status_tx.send(LoopStatus::Found(start_f, current_frame));
-                break;
-            }
+        if is_too_long {
+            let _ = status_tx.send(LoopStatus::NotFound);
+        } else {
+            let _ = status_tx.send(LoopStatus::Found(loop_start, loop_start + export_length as i32 - 1, confirmed));
+        }
+    });
+}
 
-            // Save state and continue
-            frame_states.push(current_state);
-            current_frame += 1;
+// Finds the loop of every animation the unit has, one report each as they finish
+pub fn start_report(model: Model, anims: Vec<(usize, PathBuf)>, report_tx: mpsc::Sender<LoopReport>) {
+    thread::spawn(move || {
+        let (status_tx, _status_rx) = mpsc::channel();
+        let abort_flag = AtomicBool::new(false);
 
-            // Report progress
-            if current_frame % 5 == 0 {
-                let _ = status_tx.send(LoopStatus::Searching(current_frame as usize));
-            }
-            
-            // Yield to prevent freezing
-            if current_frame % 100 == 0 {
-                thread::sleep(Duration::from_millis(1));
-            }
+        for (anim_index, path) in anims {
+            let result = match Animation::load(&path) {
+                Some(anim) => find_loop(&model, &anim, &status_tx, &abort_flag),
+                None => Err("Could not load".to_string()),
+            };
+            // The exporter dropped the receiver, nobody is waiting for the rest
+            if report_tx.send(LoopReport { anim_index, result }).is_err() { return; }
         }
     });
-}
\ No newline at end of file
+}
+
+// The curves give the loop, the animator wraps every track with rem_euclid so it always holds
+// Hashing the frames then trims it down to the shortest one, when it isn't too long to check
+pub fn find_loop(model: &Model, anim: &Animation, status_tx: &mpsc::Sender<LoopStatus>, abort_flag: &AtomicBool) -> Result<FoundLoop, String> {
+    let (loop_start, loop_length) = anim.curves.iter()
+        .filter(|curve| curve.part_id < model.parts.len())
+        .map(track_cycle)
+        .fold((0, 1), |(start, length), (settle, period)| (start.max(settle), maanim::lcm(length, period)));
+
+    let frames_needed = (loop_start as i64).saturating_add(loop_length.saturating_mul(2));
+    if frames_needed > CONFIRM_LIMIT {
+        return Ok(FoundLoop { start: loop_start, length: loop_length, confirmed: false });
+    }
+
+    let mut hashes = Vec::with_capacity(frames_needed as usize);
+    for frame in 0..frames_needed as usize {
+        if abort_flag.load(Ordering::Relaxed) { return Err("Aborted".to_string()); }
+        hashes.push(hash_state(&animator::animate(model, anim, frame as f32)));
+        if frame % 100 == 0 {
+            let _ = status_tx.send(LoopStatus::Searching(frame));
+        }
+    }
+
+    let window = loop_length as usize;
+    let repeats_from = |start: usize, period: usize| (start..start + window).all(|frame| hashes[frame] == hashes[frame + period]);
+
+    let mut start = loop_start as usize;
+    if !repeats_from(start, window) { return Err("Loop could not be confirmed".to_string()); }
+
+    // The shortest loop always divides the one from the curves, so only its factors need trying
+    let mut period = window;
+    for factor in prime_factors(window) {
+        while period.is_multiple_of(factor) && repeats_from(start, period / factor) {
+            period /= factor;
+        }
+    }
+
+    while start > 0 && hashes[start - 1] == hashes[start - 1 + period] {
+        start -= 1;
+    }
+    Ok(FoundLoop { start: start as i32, length: period as i64, confirmed: true })
+}
+
+// (settle frame, period) of one track, looping keyframes that repeat themselves get their shorter period
+fn track_cycle(curve: &AnimModification) -> (i32, i64) {
+    let (settle, period) = curve.cycle();
+    if period <= 1 { return (settle, 1); }
+
+    let first_frame = curve.keyframes.first().map_or(0, |keyframe| keyframe.frame);
+    let samples: Vec<Option<f32>> = (0..period).map(|offset| animator::sample_curve(curve, (first_frame + offset) as f32)).collect();
+    let shortest = (1..period)
+        .filter(|candidate| period % candidate == 0)
+        .find(|candidate| (0..period).all(|offset| samples[offset as usize] == samples[((offset + candidate) % period) as usize]))
+        .unwrap_or(period);
+    (settle, shortest as i64)
+}
+
+fn prime_factors(mut number: usize) -> Vec<usize> {
+    let mut factors = Vec::new();
+    let mut divisor = 2;
+    while divisor * divisor <= number {
+        if number.is_multiple_of(divisor) {
+            factors.push(divisor);
+            while number.is_multiple_of(divisor) { number /= divisor; }
+        }
+        divisor += 1;
+    }
+    if number > 1 { factors.push(number); }
+    factors
+}
+
+// Only fields the animator can change, the rest never differ between frames
+fn hash_state(parts: &[ModelPart]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for part in parts {
+        (part.parent_id, part.unit_id, part.sprite_index, part.drawing_layer, part.flip_x, part.flip_y).hash(&mut hasher);
+        for value in [part.position_x, part.position_y, part.pivot_x, part.pivot_y, part.scale_x, part.scale_y, part.rotation, part.alpha] {
+            value.to_bits().hash(&mut hasher);
+        }
+    }
+    hasher.finish()
+}
@@ -1,15 +1,21 @@
 use eframe::egui;
-use crate::global::formats::mamodel::Model;
+use crate::global::formats::mamodel::{Model, ModelPart};
 use crate::global::formats::maanim::Animation;
 use crate::global::formats::imgcut::SpriteSheet;
 use crate::features::animation::export::encoding::{self, ExportConfig, ExportFormat, EncoderMessage, EncoderStatus};
 use crate::features::animation::export::state::{ExporterState, ExportMode};
-use crate::features::animation::logic::{animator, smooth, transform}; 
+use crate::features::animation::logic::{animator, bounds, onion, overlay, smooth, transform}; 
+use crate::features::animation::logic::camera::CameraView;
 use crate::features::animation::logic::canvas::GlowRenderer;
+use crate::features::animation::logic::scene::Scene;
+use crate::features::animation::logic::transform::WorldTransform;
 use crate::features::animation::export::leader;
 use std::sync::{Arc, Mutex, mpsc, atomic::{AtomicBool, Ordering}};
 use std::path::{PathBuf, Path};
 
+// Renderer, sheet and solved parts for one scene actor
+type SceneLayer = (Arc<Mutex<Option<GlowRenderer>>>, Arc<SpriteSheet>, Vec<WorldTransform>);
+
 pub static STATUS_RX: Mutex<Option<mpsc::Receiver<EncoderStatus>>> = Mutex::new(None);
 
 pub fn start_export(state: &mut ExporterState) {
@@ -23,11 +29,18 @@ pub fn start_export(state: &mut ExporterState) {
     // Initialize the abort signal
     let abort_signal = Arc::new(AtomicBool::new(false));
     state.abort = Some(abort_signal.clone());
+    state.camera.follow_position = None;
 
     if state.export_mode == ExportMode::Showcase {
         state.frame_start = 0;
         let total_frames = state.showcase_walk_len + state.showcase_idle_len + state.showcase_attack_len + state.showcase_kb_len;
         state.frame_end = if total_frames > 0 { total_frames - 1 } else { 0 }; 
+    } else if state.export_mode == ExportMode::Scene {
+        state.frame_start = 0;
+        state.frame_end = (state.scene_length - 1).max(0);
+    } else if state.export_mode == ExportMode::Playlist {
+        state.frame_start = 0;
+        state.frame_end = (state.playlist_length - 1).max(0);
     }
 
     let (base_name, file_name) = if state.file_name.trim().is_empty() {
@@ -54,6 +67,12 @@ pub fn start_export(state: &mut ExporterState) {
              } else { 
                  "unit.showcase".to_string() 
              }
+        } else if state.export_mode == ExportMode::Scene {
+            "scene".to_string()
+        } else if state.export_mode == ExportMode::Playlist {
+            let unit = clean_prefix.split('.').next().unwrap_or("unit");
+            let playlist_name = state.playlist.as_ref().map_or("", |playlist| playlist.name.trim());
+            if playlist_name.is_empty() { format!("{}.playlist", unit) } else { format!("{}.{}", unit, playlist_name) }
         } else { 
             clean_prefix.clone() 
         };
@@ -114,24 +133,16 @@ pub fn start_export(state: &mut ExporterState) {
     leader::start_encoding_thread(config, receiver, status_sender, abort_signal);
 }
 
-pub fn process_frame(
-    ui: &mut egui::Ui,
-    rect: egui::Rect,
-    state: &mut ExporterState,
-    model: &Model,
-    anim: Option<&Animation>,
-    sheet: &SpriteSheet,
-    renderer_ref: Arc<Mutex<Option<GlowRenderer>>>,
-    current_time: f32, 
-) {
-    if state.tx.is_none() { return; }
+// Handles aborts and the end of the range, true when another frame should be rendered
+fn begin_frame(state: &mut ExporterState) -> bool {
+    if state.tx.is_none() { return false; }
 
     // CHECK ABORT SIGNAL BEFORE PROCESSING
     if let Some(abort) = &state.abort {
         if abort.load(Ordering::Relaxed) {
             state.tx = None;
             state.abort = None;
-            return;
+            return false;
         }
     }
 
@@ -140,12 +151,37 @@ pub fn process_frame(
         if let Some(sender) = state.tx.take() { 
             let _ = sender.send(EncoderMessage::Finish); 
         }
-        return;
+        return false;
     }
+    true
+}
 
-    let frame_delay_ms = 1000.0 / state.fps as f32;
+// Static region from the export settings, moved by the camera keys and follow
+fn camera_view(state: &ExporterState) -> CameraView {
+    let base = CameraView::from_region(egui::vec2(state.region_x, state.region_y), egui::vec2(state.region_w, state.region_h), state.zoom);
+    state.camera.view(base, state.current_progress as f32)
+}
+
+fn hit_track_frame(state: &ExporterState, current_time: f32) -> f32 {
+    if state.export_mode == ExportMode::Showcase { return current_time; }
+    let step = if state.frame_start < state.frame_end { 1 } else { -1 };
+    let frame = (state.frame_start + state.current_progress * step) as f32;
+    if state.hit_track_length > 0 { frame.rem_euclid(state.hit_track_length as f32) } else { frame }
+}
+
+pub fn process_frame(
+    ui: &mut egui::Ui,
+    rect: egui::Rect,
+    state: &mut ExporterState,
+    model: &Model,
+    anim: Option<&Animation>,
+    sheet: &SpriteSheet,
+    renderer_ref: Arc<Mutex<Option<GlowRenderer>>>,
+    current_time: f32, 
+) {
+    if !begin_frame(state) { return; }
     
-    let parts = if let Some(animation) = anim {
+    let (parts, ghosts) = if let Some(animation) = anim {
         let raw_frame = if state.export_mode == ExportMode::Showcase {
             current_time
         } else {
@@ -177,23 +213,55 @@ pub fn process_frame(
             raw_frame 
         };
 
-        if state.interpolation { 
+        let ghosts = if state.include_onion {
+            onion::build_ghosts(model, animation, frame_to_render, &state.onion, state.interpolation)
+        } else {
+            Vec::new()
+        };
+
+        let animated = if state.interpolation { 
             smooth::animate(model, animation, frame_to_render) 
         } else { 
             animator::animate(model, animation, frame_to_render) 
-        }
+        };
+        (animated, ghosts)
     } else { 
-        model.parts.clone() 
+        (model.parts.clone(), Vec::new())
     };
     
-    let world_parts = transform::solve_hierarchy(&parts, model);
-    let pan = egui::vec2(-state.region_x - (state.region_w as f32 / (2.0 * state.zoom)), -state.region_y - (state.region_h as f32 / (2.0 * state.zoom)));
+    // Ghosts go first so the real frame is drawn over them, the camera only follows the real frame
+    let solved_parts = transform::solve_hierarchy(&parts, model);
+    if state.camera.is_following() {
+        state.camera.track(bounds::calculate_tight_pose_bounds(&solved_parts, sheet));
+    }
+    let mut world_parts = ghosts;
+    world_parts.extend(solved_parts);
+    submit_frame(ui, rect, state, world_parts, sheet, renderer_ref, current_time);
+}
+
+// Renders solved parts offscreen and hands the pixels to the encoder
+fn submit_frame(
+    ui: &mut egui::Ui,
+    rect: egui::Rect,
+    state: &mut ExporterState,
+    world_parts: Vec<WorldTransform>,
+    sheet: &SpriteSheet,
+    renderer_ref: Arc<Mutex<Option<GlowRenderer>>>,
+    current_time: f32,
+) {
+    let frame_delay_ms = 1000.0 / state.fps as f32;
+    let view = camera_view(state);
     let bg_color = if state.background { [80, 80, 80, 255] } else { [0, 0, 0, 0] };
 
     let renderer_arc = renderer_ref.clone();
     let sheet_arc = Arc::new(sheet.clone()); 
     let Some(sender) = state.tx.as_ref().cloned() else { return; };
-    let (w, h, z) = (state.region_w, state.region_h, state.zoom);
+    let (w, h, z, pan, supersample) = (state.region_w, state.region_h, view.zoom, -view.center, state.supersample);
+    let overlay_shapes = if state.include_overlays { state.overlay_shapes.clone() } else { Vec::new() };
+    // Playlists jump between animations, the attack's hit strip wouldn't line up
+    let hit_frames = if state.include_hit_track && state.export_mode != ExportMode::Playlist { state.hit_frames.clone() } else { Vec::new() };
+    let (hit_track_length, track_frame) = (state.hit_track_length, hit_track_frame(state, current_time));
+    let region_min = view.region_min(egui::vec2(w, h));
     
     ui.painter().add(egui::PaintCallback {
         rect, 
@@ -201,10 +269,77 @@ pub fn process_frame(
             let Ok(mut lock) = renderer_arc.lock() else { return; };
             let Some(renderer) = lock.as_mut() else { return; };
             
-            let raw_pixels = encoding::render_frame(renderer, painter.gl(), w as u32, h as u32, &world_parts, &sheet_arc, pan, z, bg_color);
+            let mut raw_pixels = encoding::render_layers(painter.gl(), w as u32, h as u32, bg_color, supersample, |gl, viewport, scale| {
+                renderer.paint(gl, viewport, &world_parts, &sheet_arc, pan, z * scale, true);
+            });
+            // Overlays are stamped at output size, their edges are already pixel aligned
+            overlay::stamp(&mut raw_pixels, w as u32, h as u32, &overlay_shapes, region_min, z);
+            if !hit_frames.is_empty() {
+                overlay::stamp_hit_track(&mut raw_pixels, w as u32, h as u32, &hit_frames, hit_track_length, track_frame);
+            }
             let _ = sender.send(EncoderMessage::Frame(raw_pixels, w as u32, h as u32, frame_delay_ms as u32));
         })),
     });
 
     state.current_progress += 1;
-}
\ No newline at end of file
+}
+
+// Playlists are sampled by the viewer, which keeps their animations loaded
+pub fn process_playlist_frame(
+    ui: &mut egui::Ui,
+    rect: egui::Rect,
+    state: &mut ExporterState,
+    model: &Model,
+    parts: &[ModelPart],
+    sheet: &SpriteSheet,
+    renderer_ref: Arc<Mutex<Option<GlowRenderer>>>,
+) {
+    if !begin_frame(state) { return; }
+
+    let world_parts = transform::solve_hierarchy(parts, model);
+    if state.camera.is_following() {
+        state.camera.track(bounds::calculate_tight_pose_bounds(&world_parts, sheet));
+    }
+    let playlist_frame = state.current_progress as f32;
+    submit_frame(ui, rect, state, world_parts, sheet, renderer_ref, playlist_frame);
+}
+
+pub fn process_scene_frame(ui: &mut egui::Ui, rect: egui::Rect, state: &mut ExporterState, scene: &Scene) {
+    if !begin_frame(state) { return; }
+
+    let frame_delay_ms = 1000.0 / state.fps as f32;
+    let scene_frame = (state.frame_start + state.current_progress) as f32;
+    let layers: Vec<SceneLayer> = scene.actors.iter()
+        .filter(|actor| actor.is_visible)
+        .map(|actor| (actor.renderer.clone(), actor.sheet.clone(), actor.world_parts(scene_frame, state.interpolation)))
+        .collect();
+
+    // Following a scene keeps every visible actor in frame
+    if state.camera.is_following() {
+        let scene_bounds = layers.iter()
+            .filter_map(|(_, sheet, parts)| bounds::calculate_tight_pose_bounds(parts, sheet))
+            .reduce(|total, rect| total.union(rect));
+        state.camera.track(scene_bounds);
+    }
+
+    let view = camera_view(state);
+    let bg_color = if state.background { [80, 80, 80, 255] } else { [0, 0, 0, 0] };
+    let Some(sender) = state.tx.as_ref().cloned() else { return; };
+    let (w, h, z, pan, supersample) = (state.region_w, state.region_h, view.zoom, -view.center, state.supersample);
+
+    ui.painter().add(egui::PaintCallback {
+        rect,
+        callback: Arc::new(eframe::egui_glow::CallbackFn::new(move |_, painter| {
+            let raw_pixels = encoding::render_layers(painter.gl(), w as u32, h as u32, bg_color, supersample, |gl, viewport, scale| {
+                for (renderer_ref, sheet, parts) in &layers {
+                    let Ok(mut lock) = renderer_ref.lock() else { continue; };
+                    let renderer = lock.get_or_insert_with(|| GlowRenderer::new(gl));
+                    renderer.paint(gl, viewport, parts, sheet, pan, z * scale, true);
+                }
+            });
+            let _ = sender.send(EncoderMessage::Frame(raw_pixels, w as u32, h as u32, frame_delay_ms as u32));
+        })),
+    });
+
+    state.current_progress += 1;
+}
@@ -1,6 +1,11 @@
 use std::sync::mpsc::{Sender, Receiver};
 use std::sync::{Arc, atomic::AtomicBool};
-use crate::features::animation::export::encoding::{ExportFormat, EncoderMessage};
+use crate::features::animation::export::encoding::{ExportFormat, EncoderMessage, MAX_SUPERSAMPLE};
+use crate::features::animation::export::findloop::LoopReport;
+use crate::features::animation::logic::overlay::OverlayShape;
+use crate::features::animation::logic::camera::CameraTrack;
+use crate::features::animation::logic::onion::OnionSkin;
+use crate::features::animation::logic::playlist::Playlist;
 use crate::global::ui::shared::DragGuard;
 use crate::features::settings::logic::state::Settings;
 
@@ -13,13 +18,15 @@ pub enum ExportMode {
     Manual,
     Loop,
     Showcase,
+    Scene,
+    Playlist,
 }
 
 #[derive(Clone, Debug)]
 #[allow(dead_code)] 
 pub enum LoopStatus {
     Searching(usize),
-    Found(i32, i32),
+    Found(i32, i32, bool),
     NotFound,
     Error(String),
 }
@@ -37,8 +44,6 @@ pub struct ExporterState {
     pub loop_supported: bool,
 
     // Loop Mode Inputs
-    pub loop_tolerance: i32,
-    pub loop_tolerance_str: String,
     pub loop_min: i32,
     pub loop_min_str: String,
     pub loop_max: Option<i32>,    
@@ -74,6 +79,7 @@ pub struct ExporterState {
     pub region_y: f32,
     pub region_w: f32,
     pub region_h: f32,
+    pub camera: CameraTrack,
     
     // Output
     pub file_name: String,
@@ -90,6 +96,10 @@ pub struct ExporterState {
     pub background: bool,
     pub user_bg_preference: bool,
     pub interpolation: bool,
+    pub include_overlays: bool,
+    pub include_hit_track: bool,
+    pub include_onion: bool,
+    pub supersample: u32,
     
     // Runtime
     pub is_processing: bool,
@@ -98,6 +108,13 @@ pub struct ExporterState {
     pub tx: Option<Sender<EncoderMessage>>,
     pub abort: Option<Arc<AtomicBool>>, 
     pub export_result_msg: Option<String>,
+    pub overlay_shapes: Vec<OverlayShape>,
+    pub hit_frames: Vec<i32>,
+    pub hit_track_length: i32,
+    pub onion: OnionSkin,
+    pub scene_length: i32,
+    pub playlist: Option<Playlist>,
+    pub playlist_length: i32,
     
     // Loop Finding Runtime
     pub is_loop_searching: bool,
@@ -106,6 +123,8 @@ pub struct ExporterState {
     pub loop_abort: Option<Arc<AtomicBool>>,
     pub loop_search_start_time: Option<f64>,
     pub loop_result_msg: Option<String>,
+    pub loop_reports: Vec<LoopReport>,
+    pub loop_report_rx: Option<Receiver<LoopReport>>,
 
     // UI Helpers
     pub drag_guard: DragGuard,
@@ -125,8 +144,6 @@ impl Default for ExporterState {
             export_mode: ExportMode::Manual,
             loop_supported: false,
 
-            loop_tolerance: 30,
-            loop_tolerance_str: String::new(),
             loop_min: 15,
             loop_min_str: String::new(),
             loop_max: None,              
@@ -157,6 +174,7 @@ impl Default for ExporterState {
             region_y: 0.0,
             region_w: 0.0,
             region_h: 0.0,
+            camera: CameraTrack::default(),
             
             file_name: String::new(),
             name_prefix: String::new(),
@@ -170,6 +188,10 @@ impl Default for ExporterState {
             background: false,
             user_bg_preference: false,
             interpolation: false,
+            include_overlays: false,
+            include_hit_track: false,
+            include_onion: false,
+            supersample: 1,
             
             is_processing: false,
             current_progress: 0,
@@ -177,6 +199,13 @@ impl Default for ExporterState {
             tx: None,
             abort: None, 
             export_result_msg: None,
+            overlay_shapes: Vec::new(),
+            hit_frames: Vec::new(),
+            hit_track_length: 0,
+            onion: OnionSkin::default(),
+            scene_length: 0,
+            playlist: None,
+            playlist_length: 0,
 
             is_loop_searching: false,
             loop_frames_searched: 0,
@@ -184,6 +213,8 @@ impl Default for ExporterState {
             loop_abort: None,
             loop_search_start_time: None,
             loop_result_msg: None,
+            loop_reports: Vec::new(),
+            loop_report_rx: None,
             
             drag_guard: DragGuard::default(),
             anim_name: String::new(),
@@ -212,6 +243,8 @@ impl ExporterState {
         
         state.compression_percent = settings.animation.last_export_compression.unwrap_or(0);
         state.compression_percent_str = settings.animation.last_export_compression.map_or_else(String::new, |v| v.to_string());
+
+        state.supersample = settings.animation.last_export_supersample.clamp(1, MAX_SUPERSAMPLE);
         
         state
     }
@@ -18,9 +18,7 @@ pub fn animate(model: &Model, animation: &Animation, global_frame: f32) -> Vec<M
             local_frame = (global_frame - keyframe_min).rem_euclid(duration) + keyframe_min;
         }
 
-        let is_discrete = matches!(curve.modification_type, 0 | 1 | 3 | 13 | 14);
-        
-        let Some(interpolated_value) = interpolate_curve(curve, local_frame, is_discrete) else {
+        let Some(interpolated_value) = sample_curve(curve, local_frame) else {
             continue;
         };
         
@@ -67,6 +65,12 @@ pub fn animate(model: &Model, animation: &Animation, global_frame: f32) -> Vec<M
     parts
 }
 
+// Value of a single track at a frame inside its keyframe range, loops are not applied
+pub fn sample_curve(curve: &AnimModification, frame: f32) -> Option<f32> {
+    let is_discrete = matches!(curve.modification_type, 0 | 1 | 3 | 13 | 14);
+    interpolate_curve(curve, frame, is_discrete)
+}
+
 fn interpolate_curve(curve: &AnimModification, frame: f32, is_discrete: bool) -> Option<f32> {
     if curve.keyframes.is_empty() { return None; }
 
@@ -4,6 +4,7 @@ use crate::global::formats::mamodel::Model;
 use crate::global::formats::maanim::Animation;
 use crate::global::formats::imgcut::SpriteSheet;
 use crate::features::animation::logic::{animator, transform};
+use crate::features::animation::logic::transform::WorldTransform;
 use crate::features::animation::ui::controls;
 
 // Scans all valid Showcase animations and mathematically unions their bounds
@@ -121,6 +122,11 @@ pub fn calculate_initial_view(
     None
 }
 
+// Same filtering as calculate_tight_bounds, for one already solved pose
+pub fn calculate_tight_pose_bounds(world_parts: &[WorldTransform], sheet: &SpriteSheet) -> Option<egui::Rect> {
+    pose_bounds(world_parts, sheet, true).or_else(|| pose_bounds(world_parts, sheet, false))
+}
+
 fn scan_bounds(
     model: &Model,
     anim: Option<&Animation>,
@@ -128,12 +134,6 @@ fn scan_bounds(
     strict_mode: bool,
     override_range: Option<(i32, i32)>
 ) -> Option<egui::Rect> {
-    let mut min_x = f32::MAX;
-    let mut min_y = f32::MAX;
-    let mut max_x = f32::MIN;
-    let mut max_y = f32::MIN;
-    let mut found_any = false;
-
     let (start, end) = if let Some(r) = override_range {
         r
     } else if let Some(a) = anim { 
@@ -144,91 +144,102 @@ fn scan_bounds(
 
     let step = 1; 
 
-    for f in (start..=end).step_by(step) {
-        let frame = f as f32;
-        let posed_parts = if let Some(a) = anim { animator::animate(model, a, frame) } else { model.parts.clone() };
-        let world_parts = transform::solve_hierarchy(&posed_parts, model);
+    (start..=end).step_by(step)
+        .filter_map(|f| {
+            let frame = f as f32;
+            let posed_parts = if let Some(a) = anim { animator::animate(model, a, frame) } else { model.parts.clone() };
+            let world_parts = transform::solve_hierarchy(&posed_parts, model);
+            pose_bounds(&world_parts, sheet, strict_mode)
+        })
+        .reduce(|total, rect| total.union(rect))
+}
 
-        for part in world_parts {
-            
-            // STRICT MODE FILTERING
-            if strict_mode {
-                // Minimum Opacity Floor
-                if part.opacity < 0.25 { continue; }
+fn pose_bounds(world_parts: &[WorldTransform], sheet: &SpriteSheet, strict_mode: bool) -> Option<egui::Rect> {
+    let mut min_x = f32::MAX;
+    let mut min_y = f32::MAX;
+    let mut max_x = f32::MIN;
+    let mut max_y = f32::MIN;
+    let mut found_any = false;
 
-                // Faint Glow Filter
-                if part.glow > 0 && part.opacity < 0.75 { continue; }
+    for part in world_parts {
+        
+        // STRICT MODE FILTERING
+        if strict_mode {
+            // Minimum Opacity Floor
+            if part.opacity < 0.25 { continue; }
 
-                // SCALE HEURISTIC
-                let scale_x = (part.matrix[0].powi(2) + part.matrix[1].powi(2)).sqrt();
-                let scale_y = (part.matrix[3].powi(2) + part.matrix[4].powi(2)).sqrt();
-                let max_scale = scale_x.max(scale_y);
+            // Faint Glow Filter
+            if part.glow > 0 && part.opacity < 0.75 { continue; }
 
-                if max_scale > 3.0 {
-                    if part.opacity < 0.95 || part.glow > 0 {
-                        continue;
-                    }
-                }
-            } else {
-                // Just check visibility
-                if part.opacity <= 0.01 || part.hidden { continue; }
-            }
+            // SCALE HEURISTIC
+            let scale_x = (part.matrix[0].powi(2) + part.matrix[1].powi(2)).sqrt();
+            let scale_y = (part.matrix[3].powi(2) + part.matrix[4].powi(2)).sqrt();
+            let max_scale = scale_x.max(scale_y);
 
-            if let Some(cut) = sheet.cuts_map.get(&part.sprite_index) {
-                let w = cut.original_size.x;
-                let h = cut.original_size.y;
-                let px = part.pivot.x;
-                let py = part.pivot.y;
-
-                // Local corners
-                let local_corners = [
-                    egui::vec2(-px, -py),
-                    egui::vec2(w - px, -py),
-                    egui::vec2(w - px, h - py),
-                    egui::vec2(-px, h - py),
-                ];
-
-                // World Bounds
-                let m = part.matrix;
-                let mut p_min_x = f32::MAX;
-                let mut p_min_y = f32::MAX;
-                let mut p_max_x = f32::MIN;
-                let mut p_max_y = f32::MIN;
-
-                for p in local_corners {
-                    let wx = p.x * m[0] + p.y * m[3] + m[6];
-                    let wy = p.x * m[1] + p.y * m[4] + m[7];
-                    
-                    p_min_x = p_min_x.min(wx);
-                    p_max_x = p_max_x.max(wx);
-                    p_min_y = p_min_y.min(wy);
-                    p_max_y = p_max_y.max(wy);
+            if max_scale > 3.0 {
+                if part.opacity < 0.95 || part.glow > 0 {
+                    continue;
                 }
+            }
+        } else {
+            // Just check visibility
+            if part.opacity <= 0.01 || part.hidden { continue; }
+        }
 
-                if strict_mode {
-                    // BEAM FILTER
-                    // If visually taller than 1000px AND narrow (H > W*2).
-                    let part_h = p_max_y - p_min_y;
-                    let part_w = p_max_x - p_min_x;
-                    
-                    if part_h > 1000.0 && part_h > part_w * 2.0 {
-                        continue; 
-                    }
+        if let Some(cut) = sheet.cuts_map.get(&part.sprite_index) {
+            let w = cut.original_size.x;
+            let h = cut.original_size.y;
+            let px = part.pivot.x;
+            let py = part.pivot.y;
+
+            // Local corners
+            let local_corners = [
+                egui::vec2(-px, -py),
+                egui::vec2(w - px, -py),
+                egui::vec2(w - px, h - py),
+                egui::vec2(-px, h - py),
+            ];
+
+            // World Bounds
+            let m = part.matrix;
+            let mut p_min_x = f32::MAX;
+            let mut p_min_y = f32::MAX;
+            let mut p_max_x = f32::MIN;
+            let mut p_max_y = f32::MIN;
+
+            for p in local_corners {
+                let wx = p.x * m[0] + p.y * m[3] + m[6];
+                let wy = p.x * m[1] + p.y * m[4] + m[7];
+                
+                p_min_x = p_min_x.min(wx);
+                p_max_x = p_max_x.max(wx);
+                p_min_y = p_min_y.min(wy);
+                p_max_y = p_max_y.max(wy);
+            }
 
-                    // SKY FILTER
-                    if p_max_y < -1200.0 {
-                        continue;
-                    }
+            if strict_mode {
+                // BEAM FILTER
+                // If visually taller than 1000px AND narrow (H > W*2).
+                let part_h = p_max_y - p_min_y;
+                let part_w = p_max_x - p_min_x;
+                
+                if part_h > 1000.0 && part_h > part_w * 2.0 {
+                    continue; 
                 }
 
-                // Accumulate
-                min_x = min_x.min(p_min_x);
-                max_x = max_x.max(p_max_x);
-                min_y = min_y.min(p_min_y);
-                max_y = max_y.max(p_max_y);
-                
-                found_any = true;
+                // SKY FILTER
+                if p_max_y < -1200.0 {
+                    continue;
+                }
             }
+
+            // Accumulate
+            min_x = min_x.min(p_min_x);
+            max_x = max_x.max(p_max_x);
+            min_y = min_y.min(p_min_y);
+            max_y = max_y.max(p_max_y);
+            
+            found_any = true;
         }
     }
 
@@ -0,0 +1,139 @@
+use eframe::egui;
+
+#[derive(Clone, Copy, PartialEq, Default)]
+pub enum CameraEase {
+    #[default]
+    Linear,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    Hold,
+}
+
+impl CameraEase {
+    pub const ALL: [Self; 5] = [Self::Linear, Self::EaseIn, Self::EaseOut, Self::EaseInOut, Self::Hold];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Linear => "Linear",
+            Self::EaseIn => "Ease In",
+            Self::EaseOut => "Ease Out",
+            Self::EaseInOut => "Ease In/Out",
+            Self::Hold => "Hold",
+        }
+    }
+
+    fn apply(self, progress: f32) -> f32 {
+        match self {
+            Self::Linear => progress,
+            Self::EaseIn => progress * progress,
+            Self::EaseOut => 1.0 - (1.0 - progress) * (1.0 - progress),
+            Self::EaseInOut => progress * progress * (3.0 - 2.0 * progress),
+            Self::Hold => 0.0,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Default)]
+pub enum FollowMode {
+    #[default]
+    Off,
+    Horizontal,
+    Both,
+}
+
+// Frames count from the start of the export, the ease shapes the move towards the next key
+#[derive(Clone, Copy, PartialEq)]
+pub struct CameraKey {
+    pub frame: i32,
+    pub center: egui::Vec2,
+    pub zoom: f32,
+    pub ease: CameraEase,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct CameraView {
+    pub center: egui::Vec2,
+    pub zoom: f32,
+}
+
+impl CameraView {
+    // The export region's X/Y is its top left corner at the given zoom
+    pub fn from_region(region_min: egui::Vec2, size: egui::Vec2, zoom: f32) -> Self {
+        Self { center: region_min + size / (2.0 * zoom), zoom }
+    }
+
+    pub fn region_min(&self, size: egui::Vec2) -> egui::Vec2 {
+        self.center - size / (2.0 * self.zoom)
+    }
+}
+
+pub struct CameraTrack {
+    pub keys: Vec<CameraKey>,
+    pub follow: FollowMode,
+    // 0 snaps to the unit every frame, higher values trail behind it
+    pub smoothing: f32,
+    pub follow_position: Option<egui::Vec2>,
+}
+
+impl Default for CameraTrack {
+    fn default() -> Self {
+        Self {
+            keys: Vec::new(),
+            follow: FollowMode::Off,
+            smoothing: 0.8,
+            follow_position: None,
+        }
+    }
+}
+
+impl CameraTrack {
+    pub fn is_following(&self) -> bool {
+        self.follow != FollowMode::Off
+    }
+
+    // Keys hold their values before the first one and after the last one
+    // They're edited in place, so they aren't assumed to be in order
+    fn sample_keys(&self, frame: f32) -> Option<(egui::Vec2, f32)> {
+        let previous = self.keys.iter().filter(|key| key.frame as f32 <= frame).max_by_key(|key| key.frame);
+        let next = self.keys.iter().filter(|key| key.frame as f32 > frame).min_by_key(|key| key.frame);
+        let (from, to) = match (previous, next) {
+            (Some(from), Some(to)) => (from, to),
+            (Some(key), None) | (None, Some(key)) => return Some((key.center, key.zoom)),
+            (None, None) => return None,
+        };
+
+        let span = (to.frame - from.frame).max(1) as f32;
+        let weight = from.ease.apply(((frame - from.frame as f32) / span).clamp(0.0, 1.0));
+        let zoom = from.zoom + (to.zoom - from.zoom) * weight;
+        Some((from.center + (to.center - from.center) * weight, zoom.max(0.01)))
+    }
+
+    // Feeds the unit's bounds for the next frame, call once per exported frame
+    pub fn track(&mut self, target: Option<egui::Rect>) {
+        if !self.is_following() { return; }
+        let Some(target) = target else { return; };
+
+        let center = target.center().to_vec2();
+        let smoothing = self.smoothing.clamp(0.0, 0.95);
+        self.follow_position = Some(match self.follow_position {
+            Some(previous) => previous + (center - previous) * (1.0 - smoothing),
+            None => center,
+        });
+    }
+
+    // Keys are offsets from the unit on the followed axes, and world positions otherwise
+    pub fn view(&self, base: CameraView, frame: f32) -> CameraView {
+        let keyed = self.sample_keys(frame);
+        let mut view = keyed.map_or(base, |(center, zoom)| CameraView { center, zoom });
+
+        if self.is_following() && let Some(followed) = self.follow_position {
+            let offset = keyed.map_or(egui::Vec2::ZERO, |(center, _)| center);
+            view.center.x = followed.x + offset.x;
+            if self.follow == FollowMode::Both {
+                view.center.y = followed.y + offset.y;
+            }
+        }
+        view
+    }
+}
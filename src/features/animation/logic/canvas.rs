@@ -28,6 +28,7 @@ precision lowp float;
 uniform sampler2D u_texture;
 uniform float u_opacity;
 uniform int u_is_glow;
+uniform vec4 u_tint;
 varying vec2 v_texcoord;
 
 void main() {
@@ -39,6 +40,8 @@ void main() {
     } else {
         gl_FragColor = tex_color * u_opacity;
     }
+
+    gl_FragColor.rgb = mix(gl_FragColor.rgb, u_tint.rgb * gl_FragColor.a, u_tint.a);
 }
 "#;
 
@@ -213,6 +216,7 @@ impl GlowRenderer {
             let u_opacity = gl_context.get_uniform_location(self.program, "u_opacity");
             let u_texture = gl_context.get_uniform_location(self.program, "u_texture");
             let u_is_glow = gl_context.get_uniform_location(self.program, "u_is_glow");
+            let u_tint = gl_context.get_uniform_location(self.program, "u_tint");
             
             gl_context.uniform_1_i32(u_texture.as_ref(), 0);
 
@@ -242,6 +246,7 @@ impl GlowRenderer {
                 
                 gl_context.uniform_matrix_3_f32_slice(u_transform.as_ref(), false, &final_matrix);
                 gl_context.uniform_1_f32(u_opacity.as_ref(), part.opacity);
+                gl_context.uniform_4_f32_slice(u_tint.as_ref(), &part.tint);
 
                 let vertices: [f32; 12] = [
                     -pivot_x,               -pivot_y,          
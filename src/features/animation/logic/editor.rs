@@ -0,0 +1,160 @@
+use std::path::{Path, PathBuf};
+use crate::global::formats::maanim::{AnimModification, Animation, Keyframe};
+use crate::global::resolver;
+use crate::features::animation::logic::animator;
+
+const HISTORY_LIMIT: usize = 100;
+
+pub const EASE_MODES: [(i32, &str); 4] = [(0, "Linear"), (1, "Instant"), (2, "Exponential"), (3, "Smooth")];
+
+pub fn modification_name(modification_type: i32) -> &'static str {
+    match modification_type {
+        0 => "Parent",
+        1 => "Unit",
+        2 => "Sprite",
+        3 => "Layer",
+        4 => "Position X",
+        5 => "Position Y",
+        6 => "Pivot X",
+        7 => "Pivot Y",
+        8 => "Scale",
+        9 => "Scale X",
+        10 => "Scale Y",
+        11 => "Angle",
+        12 => "Opacity",
+        13 => "Flip X",
+        14 => "Flip Y",
+        _ => "Unknown",
+    }
+}
+
+pub fn ease_name(ease_mode: i32) -> &'static str {
+    EASE_MODES.iter().find(|(mode, _)| *mode == ease_mode).map_or("Linear", |(_, name)| *name)
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct KeyDrag {
+    pub curve: usize,
+    pub key: usize,
+    pub in_plot: bool,
+}
+
+#[derive(Default)]
+pub struct CurveEditor {
+    pub is_open: bool,
+    pub selected_curve: Option<usize>,
+    pub selected_key: Option<usize>,
+    pub is_dirty: bool,
+    pub status: Option<String>,
+    pub dragging: Option<KeyDrag>,
+    undo_stack: Vec<Vec<AnimModification>>,
+    redo_stack: Vec<Vec<AnimModification>>,
+    // A drag keeps changing the curves every frame but only counts as one step
+    is_edit_in_progress: bool,
+}
+
+impl CurveEditor {
+    pub fn reset(&mut self) {
+        self.selected_curve = None;
+        self.selected_key = None;
+        self.is_dirty = false;
+        self.status = None;
+        self.dragging = None;
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+        self.is_edit_in_progress = false;
+    }
+
+    pub fn can_undo(&self) -> bool { !self.undo_stack.is_empty() }
+    pub fn can_redo(&self) -> bool { !self.redo_stack.is_empty() }
+
+    // Called once per frame with the curves as they were before the editor ran
+    pub fn record(&mut self, before: Vec<AnimModification>, animation: &mut Animation, is_pointer_down: bool) {
+        if animation.curves != before {
+            if !self.is_edit_in_progress {
+                self.undo_stack.push(before);
+                if self.undo_stack.len() > HISTORY_LIMIT { self.undo_stack.remove(0); }
+                self.redo_stack.clear();
+            }
+            self.is_edit_in_progress = is_pointer_down;
+            self.is_dirty = true;
+            animation.refresh_max_frame();
+        } else if !is_pointer_down {
+            self.is_edit_in_progress = false;
+        }
+    }
+
+    pub fn undo(&mut self, animation: &mut Animation) {
+        let Some(previous) = self.undo_stack.pop() else { return; };
+        self.redo_stack.push(std::mem::replace(&mut animation.curves, previous));
+        self.after_history_step(animation);
+    }
+
+    pub fn redo(&mut self, animation: &mut Animation) {
+        let Some(next) = self.redo_stack.pop() else { return; };
+        self.undo_stack.push(std::mem::replace(&mut animation.curves, next));
+        self.after_history_step(animation);
+    }
+
+    fn after_history_step(&mut self, animation: &mut Animation) {
+        animation.refresh_max_frame();
+        self.is_dirty = true;
+        self.is_edit_in_progress = false;
+        self.dragging = None;
+        self.clamp_selection(animation);
+    }
+
+    pub fn clamp_selection(&mut self, animation: &Animation) {
+        let Some(curve) = self.selected_curve.and_then(|index| animation.curves.get(index)) else {
+            self.selected_curve = None;
+            self.selected_key = None;
+            return;
+        };
+        if self.selected_key.is_some_and(|key| key >= curve.keyframes.len()) {
+            self.selected_key = curve.keyframes.len().checked_sub(1);
+        }
+    }
+}
+
+// New keys take the value the track already has at that frame so the motion doesn't jump
+pub fn add_keyframe(curve: &mut AnimModification, frame: i32) -> usize {
+    if let Some(existing) = curve.keyframes.iter().position(|keyframe| keyframe.frame == frame) {
+        return existing;
+    }
+
+    let value = animator::sample_curve(curve, frame as f32)
+        .map(|value| value as i32)
+        .or_else(|| curve.keyframes.first().map(|keyframe| keyframe.value))
+        .unwrap_or(0);
+    let ease_mode = curve.keyframes.iter().rev().find(|keyframe| keyframe.frame < frame).map_or(0, |keyframe| keyframe.ease_mode);
+
+    let index = curve.keyframes.partition_point(|keyframe| keyframe.frame < frame);
+    curve.keyframes.insert(index, Keyframe { frame, value, ease_mode, ease_power: 0 });
+    index
+}
+
+// Keys stay sorted and never share a frame, returns where the key ended up
+pub fn move_keyframe(curve: &mut AnimModification, index: usize, frame: i32, value: i32) -> usize {
+    let frame = frame.max(0);
+    let is_taken = curve.keyframes.iter().enumerate().any(|(other, keyframe)| other != index && keyframe.frame == frame);
+
+    let mut keyframe = curve.keyframes.remove(index);
+    if !is_taken { keyframe.frame = frame; }
+    keyframe.value = value;
+
+    let new_index = curve.keyframes.partition_point(|other| other.frame < keyframe.frame);
+    curve.keyframes.insert(new_index, keyframe);
+    new_index
+}
+
+// A track needs at least one key, the loader drops empty ones
+pub fn delete_keyframe(curve: &mut AnimModification, index: usize) -> bool {
+    if curve.keyframes.len() <= 1 || index >= curve.keyframes.len() { return false; }
+    curve.keyframes.remove(index);
+    true
+}
+
+pub fn mod_save_path(anim_path: &Path) -> Option<PathBuf> {
+    let active_mod = resolver::get_active_mod()?;
+    Some(Path::new("mods").join(active_mod).join(anim_path.file_name()?))
+}
@@ -0,0 +1,106 @@
+use std::collections::HashSet;
+use eframe::egui;
+use crate::global::formats::imgcut::SpriteSheet;
+use crate::global::formats::mamodel::Model;
+use crate::features::animation::logic::transform::WorldTransform;
+
+#[derive(Default)]
+pub struct PartInspector {
+    pub is_open: bool,
+    pub hidden: HashSet<usize>,
+    pub solo: HashSet<usize>,
+    pub collapsed: HashSet<usize>,
+    pub selected: Option<usize>,
+    pub hovered: Option<usize>,
+}
+
+impl PartInspector {
+    // Forgets per part state when another model is loaded, indices no longer match
+    pub fn reset(&mut self) {
+        self.hidden.clear();
+        self.solo.clear();
+        self.collapsed.clear();
+        self.selected = None;
+        self.hovered = None;
+    }
+
+    pub fn is_visible(&self, part_index: usize) -> bool {
+        !self.hidden.contains(&part_index) && (self.solo.is_empty() || self.solo.contains(&part_index))
+    }
+
+    pub fn apply(&self, parts: &mut [WorldTransform]) {
+        if self.hidden.is_empty() && self.solo.is_empty() { return; }
+        for part in parts {
+            if !self.is_visible(part.part_index) { part.hidden = true; }
+        }
+    }
+}
+
+pub struct TreeRow {
+    pub part_index: usize,
+    pub depth: usize,
+    pub has_children: bool,
+}
+
+fn parent_of(model: &Model, part_index: usize) -> Option<usize> {
+    let parent = model.parts[part_index].parent_id;
+    (parent >= 0 && (parent as usize) < model.parts.len() && parent as usize != part_index).then_some(parent as usize)
+}
+
+// Depth first walk over parent ids, collapsed parts keep their children out of the list
+pub fn tree_rows(model: &Model, collapsed: &HashSet<usize>) -> Vec<TreeRow> {
+    let count = model.parts.len();
+    let mut children: Vec<Vec<usize>> = vec![Vec::new(); count];
+    let mut roots = Vec::new();
+    for part_index in 0..count {
+        match parent_of(model, part_index) {
+            Some(parent) => children[parent].push(part_index),
+            None => roots.push(part_index),
+        }
+    }
+
+    let mut rows = Vec::with_capacity(count);
+    let mut visited = vec![false; count];
+    let mut stack: Vec<(usize, usize)> = roots.into_iter().rev().map(|root| (root, 0)).collect();
+    while let Some((part_index, depth)) = stack.pop() {
+        // Broken models can loop back on themselves
+        if visited[part_index] { continue; }
+        visited[part_index] = true;
+
+        rows.push(TreeRow { part_index, depth, has_children: !children[part_index].is_empty() });
+        if collapsed.contains(&part_index) { continue; }
+        for &child in children[part_index].iter().rev() {
+            stack.push((child, depth + 1));
+        }
+    }
+
+    // Parts caught in a cycle never hang off a root, list them at the top level
+    for (part_index, seen) in visited.iter().enumerate() {
+        if !seen { rows.push(TreeRow { part_index, depth: 0, has_children: false }); }
+    }
+    rows
+}
+
+pub fn part_corners(part: &WorldTransform, sheet: &SpriteSheet) -> Option<[egui::Pos2; 4]> {
+    let cut = sheet.cuts_map.get(&part.sprite_index)?;
+    let (width, height) = (cut.original_size.x, cut.original_size.y);
+    let (pivot_x, pivot_y) = (part.pivot.x, part.pivot.y);
+    let matrix = part.matrix;
+
+    let corner = |x: f32, y: f32| egui::pos2(x * matrix[0] + y * matrix[3] + matrix[6], x * matrix[1] + y * matrix[4] + matrix[7]);
+    Some([
+        corner(-pivot_x, -pivot_y),
+        corner(width - pivot_x, -pivot_y),
+        corner(width - pivot_x, height - pivot_y),
+        corner(-pivot_x, height - pivot_y),
+    ])
+}
+
+// Breaks the solved matrix back into readable values
+pub fn decompose(part: &WorldTransform) -> (egui::Vec2, egui::Vec2, f32) {
+    let matrix = part.matrix;
+    let position = egui::vec2(matrix[6], matrix[7]);
+    let scale = egui::vec2(matrix[0].hypot(matrix[1]), matrix[3].hypot(matrix[4]));
+    let rotation = matrix[1].atan2(matrix[0]).to_degrees();
+    (position, scale, rotation)
+}
@@ -3,4 +3,11 @@ pub mod canvas;
 pub mod transform;
 pub mod controls;
 pub mod smooth;
-pub mod bounds;
\ No newline at end of file
+pub mod bounds;
+pub mod overlay;
+pub mod inspector;
+pub mod editor;
+pub mod onion;
+pub mod scene;
+pub mod playlist;
+pub mod camera;
\ No newline at end of file
@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+use crate::global::formats::maanim::Animation;
+use crate::global::formats::mamodel::Model;
+use crate::features::animation::logic::{animator, smooth, transform};
+use crate::features::animation::logic::transform::WorldTransform;
+
+pub const MAX_GHOSTS: u32 = 10;
+
+// Earlier frames lean red, later frames lean green
+pub const PREVIOUS_TINT: [f32; 4] = [1.0, 0.25, 0.25, 0.6];
+pub const NEXT_TINT: [f32; 4] = [0.25, 1.0, 0.4, 0.6];
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct OnionSkin {
+    pub enabled: bool,
+    pub previous: u32,
+    pub next: u32,
+    pub step: u32,
+    pub opacity: f32,
+}
+
+impl Default for OnionSkin {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            previous: 2,
+            next: 2,
+            step: 1,
+            opacity: 0.35,
+        }
+    }
+}
+
+impl OnionSkin {
+    pub fn is_active(&self) -> bool {
+        self.enabled && (self.previous > 0 || self.next > 0)
+    }
+}
+
+// Ghost parts for the frames around `frame`, farthest first so nearer ghosts land on top
+pub fn build_ghosts(model: &Model, animation: &Animation, frame: f32, onion: &OnionSkin, interpolation: bool) -> Vec<WorldTransform> {
+    if !onion.is_active() { return Vec::new(); }
+
+    let step = onion.step.max(1) as f32;
+    let last_frame = animation.max_frame.max(0) as f32;
+    let previous = onion.previous.min(MAX_GHOSTS);
+    let next = onion.next.min(MAX_GHOSTS);
+
+    let mut offsets: Vec<(u32, u32, [f32; 4], f32)> = Vec::new();
+    for distance in 1..=previous { offsets.push((distance, previous, PREVIOUS_TINT, -1.0)); }
+    for distance in 1..=next { offsets.push((distance, next, NEXT_TINT, 1.0)); }
+    offsets.sort_by_key(|(distance, ..)| std::cmp::Reverse(*distance));
+
+    let mut ghosts = Vec::new();
+    for (distance, count, tint, direction) in offsets {
+        let ghost_frame = frame + direction * distance as f32 * step;
+        // Ghosts outside the animation would just repeat the first or last pose
+        if ghost_frame < 0.0 || ghost_frame > last_frame { continue; }
+
+        let parts = if interpolation {
+            smooth::animate(model, animation, ghost_frame)
+        } else {
+            animator::animate(model, animation, (ghost_frame + 0.01).floor())
+        };
+
+        let fade = onion.opacity * (1.0 - (distance - 1) as f32 / count as f32);
+        for mut part in transform::solve_hierarchy(&parts, model) {
+            part.opacity *= fade;
+            part.tint = tint;
+            ghosts.push(part);
+        }
+    }
+    ghosts
+}
@@ -0,0 +1,298 @@
+use eframe::egui;
+use serde::{Deserialize, Serialize};
+use crate::features::cat::data::unitid::CatRaw;
+use crate::features::enemy::data::t_unit::EnemyRaw;
+
+// Model pixels per battle unit, the origin of the model is where the unit stands
+pub const BATTLE_UNIT_SCALE: f32 = 1.0;
+
+// Lanes are stacked under the ground line so they never hide the sprite
+const LANE_TOP: f32 = 12.0;
+const LANE_HEIGHT: f32 = 10.0;
+const LANE_GAP: f32 = 4.0;
+const RANGE_LINE_HEIGHT: f32 = 160.0;
+const RANGE_LINE_WIDTH: f32 = 2.0;
+
+// A hit stays lit for a few frames after it lands so it can be seen at full speed
+const HIT_FLASH_FRAMES: f32 = 6.0;
+const TRACK_HEIGHT: i64 = 6;
+
+pub const RANGE_COLOR: egui::Color32 = egui::Color32::from_rgb(90, 220, 90);
+pub const HIT_COLORS: [egui::Color32; 3] = [
+    egui::Color32::from_rgb(230, 80, 80),
+    egui::Color32::from_rgb(230, 150, 60),
+    egui::Color32::from_rgb(220, 210, 70),
+];
+pub const HITBOX_COLOR: egui::Color32 = egui::Color32::from_rgb(80, 160, 240);
+pub const SURGE_COLOR: egui::Color32 = egui::Color32::from_rgb(190, 110, 240);
+pub const EXPLOSION_COLOR: egui::Color32 = egui::Color32::from_rgb(250, 120, 40);
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+#[serde(default)]
+pub struct OverlayToggles {
+    pub standing_range: bool,
+    pub hit_areas: bool,
+    pub hitbox: bool,
+    pub spawns: bool,
+    pub hit_flash: bool,
+}
+
+impl OverlayToggles {
+    pub fn any(&self) -> bool {
+        self.standing_range || self.hit_areas || self.hitbox || self.spawns
+    }
+}
+
+// Distances are in battle units measured forward from the unit, so a near/far pair
+// can describe both melee reach and LD or omni areas
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Reach {
+    pub near: i32,
+    pub far: i32,
+}
+
+impl Reach {
+    fn new(anchor: i32, span: i32) -> Self {
+        Self { near: anchor.min(anchor + span), far: anchor.max(anchor + span) }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CombatProfile {
+    // Cats walk left towards the enemy base, enemies walk right
+    pub facing: f32,
+    pub standing_range: i32,
+    pub hitbox: Reach,
+    pub hits: Vec<Reach>,
+    pub surge: Option<Reach>,
+    pub death_surge: Option<Reach>,
+    pub explosion: Option<Reach>,
+    // Frames of the attack animation where each hit lands
+    pub hit_frames: Vec<i32>,
+    pub attack_frames: i32,
+}
+
+fn hit_reach(standing_range: i32, anchor: i32, span: i32) -> Reach {
+    if span == 0 && anchor == 0 {
+        Reach { near: 0, far: standing_range }
+    } else {
+        Reach::new(anchor, span)
+    }
+}
+
+// Hits 2 and 3 reuse the first area unless their own LD flag is set
+fn collect_hits(standing_range: i32, first: Reach, extra: [(i32, i32, i32, i32); 2]) -> Vec<Reach> {
+    let mut hits = vec![first];
+    for (damage, flag, anchor, span) in extra {
+        if damage <= 0 { break; }
+        hits.push(if flag > 0 { hit_reach(standing_range, anchor, span) } else { first });
+    }
+    hits
+}
+
+// Hit 1 lands at the end of the foreswing, later hits carry their own frame
+fn collect_hit_frames(foreswing: i32, extra: [(i32, i32); 2], attack_frames: i32) -> Vec<i32> {
+    let mut frames = vec![foreswing];
+    for (damage, frame) in extra {
+        if damage <= 0 || frame <= 0 { break; }
+        frames.push(frame);
+    }
+    if attack_frames > 0 {
+        frames.retain(|&frame| frame < attack_frames);
+    }
+    frames
+}
+
+impl CombatProfile {
+    pub fn from_cat(stats: &CatRaw, attack_frames: i32) -> Self {
+        let first = hit_reach(stats.standing_range, stats.long_distance_1_anchor, stats.long_distance_1_span);
+        Self {
+            facing: -1.0,
+            standing_range: stats.standing_range,
+            hitbox: Reach::new(stats.hitbox_position, -stats.hitbox_width),
+            hits: collect_hits(stats.standing_range, first, [
+                (stats.attack_2, stats.long_distance_2_flag, stats.long_distance_2_anchor, stats.long_distance_2_span),
+                (stats.attack_3, stats.long_distance_3_flag, stats.long_distance_3_anchor, stats.long_distance_3_span),
+            ]),
+            surge: (stats.surge_chance > 0).then(|| Reach::new(stats.surge_spawn_anchor, stats.surge_spawn_span)),
+            death_surge: None,
+            explosion: (stats.explosion_chance > 0).then(|| Reach::new(stats.explosion_spawn_anchor, stats.explosion_spawn_span)),
+            hit_frames: collect_hit_frames(stats.pre_attack_animation, [
+                (stats.attack_2, stats.time_before_attack_2),
+                (stats.attack_3, stats.time_before_attack_3),
+            ], attack_frames),
+            attack_frames,
+        }
+    }
+
+    pub fn from_enemy(stats: &EnemyRaw, attack_frames: i32) -> Self {
+        let first = hit_reach(stats.standing_range, stats.long_distance_anchor_1, stats.long_distance_span_1);
+        Self {
+            facing: 1.0,
+            standing_range: stats.standing_range,
+            hitbox: Reach::new(stats.hitbox_position, -stats.hitbox_width),
+            hits: collect_hits(stats.standing_range, first, [
+                (stats.attack_2, stats.long_distance_2_flag, stats.long_distance_2_anchor, stats.long_distance_2_span),
+                (stats.attack_3, stats.long_distance_3_flag, stats.long_distance_3_anchor, stats.long_distance_3_span),
+            ]),
+            surge: (stats.surge_chance > 0).then(|| Reach::new(stats.surge_spawn_min, stats.surge_spawn_max)),
+            death_surge: (stats.death_surge_chance > 0).then(|| Reach::new(stats.death_surge_spawn_min, stats.death_surge_spawn_max)),
+            explosion: (stats.explosion_chance > 0).then(|| Reach::new(stats.explosion_anchor, stats.explosion_span)),
+            hit_frames: collect_hit_frames(stats.pre_attack_animation, [
+                (stats.attack_2, stats.time_before_attack_2),
+                (stats.attack_3, stats.time_before_attack_3),
+            ], attack_frames),
+            attack_frames,
+        }
+    }
+
+    // Wraps back to the first hit once the last one has played
+    pub fn next_hit(&self, frame: f32) -> Option<i32> {
+        self.hit_frames.iter().copied()
+            .filter(|&hit| hit as f32 > frame + 0.01)
+            .min()
+            .or_else(|| self.hit_frames.iter().copied().min())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct OverlayShape {
+    pub rect: egui::Rect,
+    pub color: egui::Color32,
+    pub label: String,
+}
+
+fn lane_rect(profile: &CombatProfile, reach: Reach, lane: usize) -> egui::Rect {
+    let top = LANE_TOP + lane as f32 * (LANE_HEIGHT + LANE_GAP);
+    let near = reach.near as f32 * BATTLE_UNIT_SCALE * profile.facing;
+    let far = reach.far as f32 * BATTLE_UNIT_SCALE * profile.facing;
+    egui::Rect::from_x_y_ranges(near.min(far)..=near.max(far), top..=top + LANE_HEIGHT)
+}
+
+// World space shapes shared by the viewport and the exporter
+pub fn build_shapes(profile: &CombatProfile, toggles: &OverlayToggles) -> Vec<OverlayShape> {
+    let mut shapes = Vec::new();
+    let mut lane = 0;
+
+    if toggles.hitbox {
+        shapes.push(OverlayShape { rect: lane_rect(profile, profile.hitbox, lane), color: HITBOX_COLOR, label: "Hitbox".to_string() });
+        lane += 1;
+    }
+
+    if toggles.hit_areas {
+        for (index, reach) in profile.hits.iter().enumerate() {
+            let label = if profile.hits.len() > 1 { format!("Hit {}", index + 1) } else { "Hit".to_string() };
+            shapes.push(OverlayShape { rect: lane_rect(profile, *reach, lane), color: HIT_COLORS[index % HIT_COLORS.len()], label });
+            lane += 1;
+        }
+    }
+
+    if toggles.spawns {
+        let spawns = [(profile.surge, "Surge", SURGE_COLOR), (profile.death_surge, "Death Surge", SURGE_COLOR), (profile.explosion, "Explosion", EXPLOSION_COLOR)];
+        for (reach, label, color) in spawns {
+            let Some(reach) = reach else { continue; };
+            shapes.push(OverlayShape { rect: lane_rect(profile, reach, lane), color, label: label.to_string() });
+            lane += 1;
+        }
+    }
+
+    if toggles.standing_range {
+        let x = profile.standing_range as f32 * BATTLE_UNIT_SCALE * profile.facing;
+        let bottom = LANE_TOP + lane as f32 * (LANE_HEIGHT + LANE_GAP);
+        shapes.push(OverlayShape {
+            rect: egui::Rect::from_x_y_ranges(x - RANGE_LINE_WIDTH / 2.0..=x + RANGE_LINE_WIDTH / 2.0, -RANGE_LINE_HEIGHT..=bottom),
+            color: RANGE_COLOR,
+            label: format!("Range {}", profile.standing_range),
+        });
+    }
+
+    shapes
+}
+
+pub fn hit_flash(profile: &CombatProfile, frame: f32) -> Option<OverlayShape> {
+    profile.hit_frames.iter().enumerate().find_map(|(index, &hit)| {
+        let elapsed = frame - hit as f32;
+        if !(0.0..HIT_FLASH_FRAMES).contains(&elapsed) { return None; }
+
+        let reach = profile.hits.get(index).or(profile.hits.first()).copied().unwrap_or_default();
+        let near = reach.near as f32 * BATTLE_UNIT_SCALE * profile.facing;
+        let far = reach.far as f32 * BATTLE_UNIT_SCALE * profile.facing;
+        let fade = 1.0 - elapsed / HIT_FLASH_FRAMES;
+        Some(OverlayShape {
+            rect: egui::Rect::from_x_y_ranges(near.min(far)..=near.max(far), -RANGE_LINE_HEIGHT..=0.0),
+            color: HIT_COLORS[index % HIT_COLORS.len()].gamma_multiply(fade),
+            label: format!("Hit {}", index + 1),
+        })
+    })
+}
+
+pub fn paint(painter: &egui::Painter, shapes: &[OverlayShape], to_screen: impl Fn(egui::Pos2) -> egui::Pos2) {
+    for shape in shapes {
+        let rect = egui::Rect::from_two_pos(to_screen(shape.rect.min), to_screen(shape.rect.max));
+        painter.rect(rect, 0.0, shape.color.gamma_multiply(0.35), egui::Stroke::new(1.0, shape.color));
+        painter.text(
+            rect.right_top() + egui::vec2(4.0, 0.0),
+            egui::Align2::LEFT_TOP,
+            &shape.label,
+            egui::FontId::proportional(11.0),
+            shape.color,
+        );
+    }
+}
+
+fn blend(pixel: &mut [u8], color: egui::Color32, alpha: f32) {
+    // Export buffers are premultiplied, so the overlay colour goes in premultiplied too
+    for channel in 0..3 {
+        let source = color[channel] as f32 * alpha;
+        pixel[channel] = (source + pixel[channel] as f32 * (1.0 - alpha)).round() as u8;
+    }
+    pixel[3] = (alpha * 255.0 + pixel[3] as f32 * (1.0 - alpha)).round() as u8;
+}
+
+// Draws onto a raw export frame, which is read back bottom row first
+pub fn stamp(pixels: &mut [u8], width: u32, height: u32, shapes: &[OverlayShape], region_min: egui::Vec2, zoom: f32) {
+    let (width, height) = (width as i64, height as i64);
+    for shape in shapes {
+        let min = (shape.rect.min.to_vec2() - region_min) * zoom;
+        let max = (shape.rect.max.to_vec2() - region_min) * zoom;
+        let (left, right) = ((min.x.floor() as i64).max(0), (max.x.ceil() as i64).min(width));
+        let (top, bottom) = ((min.y.floor() as i64).max(0), (max.y.ceil() as i64).min(height));
+
+        for y in top..bottom {
+            for x in left..right {
+                let is_edge = x == left || x == right - 1 || y == top || y == bottom - 1;
+                let alpha = if is_edge { 1.0 } else { 0.35 };
+                let index = (((height - 1 - y) * width + x) * 4) as usize;
+                blend(&mut pixels[index..index + 4], shape.color, alpha);
+            }
+        }
+    }
+}
+
+// Strip along the bottom of an export frame with a tick per hit and the current frame
+pub fn stamp_hit_track(pixels: &mut [u8], width: u32, height: u32, hit_frames: &[i32], length: i32, frame: f32) {
+    if length <= 0 || width == 0 || height == 0 { return; }
+    let (width, height) = (width as i64, height as i64);
+    let track_height = TRACK_HEIGHT.min(height);
+    let column = |frame: f32| ((frame / length as f32) * (width - 1) as f32).round() as i64;
+
+    // Row 0 of the buffer is the bottom of the image
+    let mut fill_column = |x: i64, color: egui::Color32, alpha: f32| {
+        if !(0..width).contains(&x) { return; }
+        for y in 0..track_height {
+            let index = ((y * width + x) * 4) as usize;
+            blend(&mut pixels[index..index + 4], color, alpha);
+        }
+    };
+
+    for x in 0..width {
+        fill_column(x, egui::Color32::from_gray(20), 0.6);
+    }
+    for (index, &hit) in hit_frames.iter().enumerate() {
+        let x = column(hit as f32);
+        for offset in -1..=1 {
+            fill_column(x + offset, HIT_COLORS[index % HIT_COLORS.len()], 1.0);
+        }
+    }
+    fill_column(column(frame), egui::Color32::WHITE, 1.0);
+}
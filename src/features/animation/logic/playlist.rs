@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use eframe::egui;
+use serde::{Deserialize, Serialize};
+use crate::global::formats::imgcut::SpriteSheet;
+use crate::global::formats::maanim::Animation;
+use crate::global::formats::mamodel::{Model, ModelPart};
+use crate::features::animation::logic::{animator, bounds, smooth};
+use crate::features::animation::ui::controls::IDX_WALK;
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+pub enum UnitKind {
+    #[default]
+    Cat,
+    Enemy,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+pub enum Transition {
+    #[default]
+    Cut,
+    Crossfade,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[serde(default)]
+pub struct PlaylistEntry {
+    pub anim_index: usize,
+    pub start: i32,
+    // None plays to the animation's last frame
+    pub end: Option<i32>,
+    pub repeats: u32,
+    pub speed: Option<f32>,
+    pub transition: Transition,
+    pub crossfade_frames: i32,
+}
+
+impl Default for PlaylistEntry {
+    fn default() -> Self {
+        Self {
+            anim_index: IDX_WALK,
+            start: 0,
+            end: None,
+            repeats: 1,
+            speed: None,
+            transition: Transition::Cut,
+            crossfade_frames: 5,
+        }
+    }
+}
+
+impl PlaylistEntry {
+    pub fn speed(&self) -> f32 {
+        self.speed.filter(|speed| *speed > 0.01).unwrap_or(1.0)
+    }
+
+    pub fn span(&self, animation: Option<&Animation>) -> (i32, i32) {
+        let last = self.end.unwrap_or_else(|| animation.map_or(0, |animation| animation.max_frame));
+        (self.start.min(last), last.max(self.start))
+    }
+
+    // Output frames this entry takes up, speed stretches or squeezes the range
+    pub fn duration(&self, animation: Option<&Animation>) -> i32 {
+        let (first, last) = self.span(animation);
+        let frames = (last - first + 1) * self.repeats.max(1) as i32;
+        ((frames as f32 / self.speed()).ceil() as i32).max(1)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Default)]
+#[serde(default)]
+pub struct Playlist {
+    pub name: String,
+    pub entries: Vec<PlaylistEntry>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct PlaylistPresets {
+    pub cats: Vec<Playlist>,
+    pub enemies: Vec<Playlist>,
+}
+
+impl PlaylistPresets {
+    pub fn for_kind(&mut self, kind: UnitKind) -> &mut Vec<Playlist> {
+        match kind {
+            UnitKind::Cat => &mut self.cats,
+            UnitKind::Enemy => &mut self.enemies,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct PlaylistEditor {
+    pub is_open: bool,
+    pub selected: Option<usize>,
+    pub is_previewing: bool,
+    pub time: f32,
+    pub player: PlaylistPlayer,
+}
+
+// Keeps every animation a playlist touches loaded for the current unit
+#[derive(Default)]
+pub struct PlaylistPlayer {
+    pub anims: HashMap<usize, Animation>,
+    loaded_for: String,
+}
+
+impl PlaylistPlayer {
+    pub fn sync(&mut self, unit_id: &str, available_anims: &[(usize, PathBuf)]) {
+        if self.loaded_for == unit_id { return; }
+        self.loaded_for = unit_id.to_string();
+        self.anims = available_anims.iter()
+            .filter_map(|(index, path)| Animation::load(path).map(|animation| (*index, animation)))
+            .collect();
+    }
+
+    pub fn length(&self, playlist: &Playlist) -> i32 {
+        playlist.entries.iter().map(|entry| entry.duration(self.anims.get(&entry.anim_index))).sum()
+    }
+
+    fn pose(&self, model: &Model, entry: &PlaylistEntry, elapsed: f32, interpolation: bool) -> Vec<ModelPart> {
+        let Some(animation) = self.anims.get(&entry.anim_index) else { return model.parts.clone(); };
+        let (first, last) = entry.span(Some(animation));
+        let span = (last - first + 1) as f32;
+        let frame = first as f32 + (elapsed * entry.speed()).rem_euclid(span);
+        if interpolation { smooth::animate(model, animation, frame) } else { animator::animate(model, animation, (frame + 0.01).floor()) }
+    }
+
+    pub fn sample(&self, model: &Model, playlist: &Playlist, time: f32, interpolation: bool) -> Option<Vec<ModelPart>> {
+        let mut entry_start = 0.0;
+        for (entry_index, entry) in playlist.entries.iter().enumerate() {
+            let duration = entry.duration(self.anims.get(&entry.anim_index)) as f32;
+            let elapsed = time - entry_start;
+            if elapsed >= duration && entry_index + 1 < playlist.entries.len() {
+                entry_start += duration;
+                continue;
+            }
+
+            let elapsed = elapsed.min(duration - 1.0).max(0.0);
+            let parts = self.pose(model, entry, elapsed, interpolation);
+            if entry.transition == Transition::Cut || entry_index == 0 || elapsed >= entry.crossfade_frames as f32 {
+                return Some(parts);
+            }
+
+            // Blends out of the pose the previous entry ended on
+            let previous = &playlist.entries[entry_index - 1];
+            let previous_duration = previous.duration(self.anims.get(&previous.anim_index)) as f32;
+            let from = self.pose(model, previous, previous_duration - 1.0, interpolation);
+            let weight = (elapsed + 1.0) / (entry.crossfade_frames as f32 + 1.0);
+            return Some(blend(&from, &parts, weight));
+        }
+        None
+    }
+}
+
+// Discrete values can't be mixed, they switch over halfway through
+pub fn blend(from: &[ModelPart], to: &[ModelPart], weight: f32) -> Vec<ModelPart> {
+    let mix = |a: f32, b: f32| a + (b - a) * weight;
+    to.iter().zip(from).map(|(target, source)| {
+        let mut part = if weight < 0.5 { source.clone() } else { target.clone() };
+        part.position_x = mix(source.position_x, target.position_x);
+        part.position_y = mix(source.position_y, target.position_y);
+        part.pivot_x = mix(source.pivot_x, target.pivot_x);
+        part.pivot_y = mix(source.pivot_y, target.pivot_y);
+        part.scale_x = mix(source.scale_x, target.scale_x);
+        part.scale_y = mix(source.scale_y, target.scale_y);
+        part.rotation = mix(source.rotation, target.rotation);
+        part.alpha = mix(source.alpha, target.alpha);
+        part
+    }).collect()
+}
+
+pub fn calculate_bounds(model: &Model, sheet: &SpriteSheet, available_anims: &[(usize, PathBuf)], playlist: &Playlist, use_tight_bounds: bool) -> Option<egui::Rect> {
+    let mut anim_indices: Vec<usize> = playlist.entries.iter().map(|entry| entry.anim_index).collect();
+    anim_indices.sort_unstable();
+    anim_indices.dedup();
+
+    anim_indices.into_iter()
+        .filter_map(|anim_index| available_anims.iter().find(|(index, _)| *index == anim_index))
+        .filter_map(|(_, path)| Animation::load(path))
+        .filter_map(|animation| if use_tight_bounds {
+            bounds::calculate_tight_bounds(model, Some(&animation), sheet)
+        } else {
+            bounds::calculate_loose_bounds(model, Some(&animation), sheet)
+        })
+        .reduce(|total, rect| total.union(rect))
+}
@@ -0,0 +1,164 @@
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use eframe::egui;
+use crate::global::formats::imgcut::SpriteSheet;
+use crate::global::formats::maanim::Animation;
+use crate::global::formats::mamodel::Model;
+use crate::features::animation::logic::{animator, bounds, smooth, transform};
+use crate::features::animation::logic::canvas::GlowRenderer;
+use crate::features::animation::logic::transform::WorldTransform;
+use crate::features::animation::ui::controls::{IDX_ATTACK, IDX_BURROW, IDX_IDLE, IDX_KB, IDX_SPIRIT, IDX_SURFACE, IDX_WALK};
+
+pub const DEFAULT_LENGTH: i32 = 90;
+
+// Cat and enemy tabs each own a viewer, the scene lives here so both can add to it
+pub static SCENE: Mutex<Scene> = Mutex::new(Scene::new());
+
+pub fn anim_label(index: usize) -> &'static str {
+    match index {
+        IDX_WALK => "Walk",
+        IDX_IDLE => "Idle",
+        IDX_ATTACK => "Attack",
+        IDX_KB => "Knockback",
+        IDX_BURROW => "Burrow",
+        IDX_SURFACE => "Surface",
+        IDX_SPIRIT => "Spirit",
+        _ => "Model",
+    }
+}
+
+pub struct SceneActor {
+    pub label: String,
+    pub model: Model,
+    pub sheet: Arc<SpriteSheet>,
+    pub anims: Vec<(usize, PathBuf)>,
+    pub anim_index: usize,
+    pub animation: Option<Animation>,
+    pub start_offset: i32,
+    pub flip: bool,
+    pub position: egui::Vec2,
+    pub is_visible: bool,
+    // Every actor keeps its own texture, sharing one renderer would re-upload sheets each frame
+    pub renderer: Arc<Mutex<Option<GlowRenderer>>>,
+}
+
+impl SceneActor {
+    pub fn new(label: String, model: Model, sheet: SpriteSheet, anims: Vec<(usize, PathBuf)>, anim_index: usize) -> Self {
+        let mut actor = Self {
+            label,
+            model,
+            sheet: Arc::new(sheet),
+            anims,
+            anim_index,
+            animation: None,
+            start_offset: 0,
+            flip: false,
+            position: egui::Vec2::ZERO,
+            is_visible: true,
+            renderer: Arc::new(Mutex::new(None)),
+        };
+        actor.set_anim(anim_index);
+        actor
+    }
+
+    pub fn set_anim(&mut self, anim_index: usize) {
+        self.anim_index = anim_index;
+        self.animation = self.anims.iter()
+            .find(|(index, _)| *index == anim_index)
+            .and_then(|(_, path)| Animation::load(path));
+    }
+
+    pub fn length(&self) -> i32 {
+        self.animation.as_ref().map_or(1, |animation| animation.max_frame + 1)
+    }
+
+    // Actors hold their first pose until they start, one-shot animations repeat like in showcases
+    pub fn local_frame(&self, scene_frame: f32) -> f32 {
+        let local = (scene_frame - self.start_offset as f32).max(0.0);
+        let Some(animation) = &self.animation else { return 0.0; };
+        let natively_loops = animation.curves.iter().any(|curve| curve.loop_count != 1);
+        if natively_loops || animation.max_frame <= 0 { local } else { local.rem_euclid(animation.max_frame as f32 + 1.0) }
+    }
+
+    pub fn world_parts(&self, scene_frame: f32, interpolation: bool) -> Vec<WorldTransform> {
+        let parts = match &self.animation {
+            Some(animation) => {
+                let frame = self.local_frame(scene_frame);
+                if interpolation { smooth::animate(&self.model, animation, frame) } else { animator::animate(&self.model, animation, (frame + 0.01).floor()) }
+            }
+            None => self.model.parts.clone(),
+        };
+
+        let mut world_parts = transform::solve_hierarchy(&parts, &self.model);
+        for part in &mut world_parts {
+            part.matrix = self.place(part.matrix);
+        }
+        world_parts
+    }
+
+    // Mirrors around the actor's origin, then moves it into place
+    fn place(&self, matrix: [f32; 9]) -> [f32; 9] {
+        let mirror = if self.flip { -1.0 } else { 1.0 };
+        let mut placed = matrix;
+        placed[0] *= mirror;
+        placed[3] *= mirror;
+        placed[6] = placed[6] * mirror + self.position.x;
+        placed[7] += self.position.y;
+        placed
+    }
+
+    pub fn bounds(&self, use_tight_bounds: bool) -> Option<egui::Rect> {
+        let local = if use_tight_bounds {
+            bounds::calculate_tight_bounds(&self.model, self.animation.as_ref(), &self.sheet)
+        } else {
+            bounds::calculate_loose_bounds(&self.model, self.animation.as_ref(), &self.sheet)
+        }?;
+
+        let (min_x, max_x) = if self.flip { (-local.max.x, -local.min.x) } else { (local.min.x, local.max.x) };
+        Some(egui::Rect::from_min_max(egui::pos2(min_x, local.min.y), egui::pos2(max_x, local.max.y)).translate(self.position))
+    }
+}
+
+pub struct Scene {
+    pub actors: Vec<SceneActor>,
+    pub is_open: bool,
+    pub is_visible: bool,
+    pub length: i32,
+    pub frame: f32,
+}
+
+impl Scene {
+    pub const fn new() -> Self {
+        Self {
+            actors: Vec::new(),
+            is_open: false,
+            is_visible: false,
+            length: DEFAULT_LENGTH,
+            frame: 0.0,
+        }
+    }
+
+    pub fn is_showing(&self) -> bool {
+        self.is_visible && self.actors.iter().any(|actor| actor.is_visible)
+    }
+
+    // Long enough for every actor to play its animation through once
+    pub fn fit_length(&mut self) {
+        self.length = self.actors.iter()
+            .map(|actor| actor.start_offset.max(0) + actor.length())
+            .max()
+            .unwrap_or(DEFAULT_LENGTH)
+            .max(1);
+    }
+
+    pub fn advance(&mut self, frames: f32) {
+        self.frame = (self.frame + frames).rem_euclid(self.length.max(1) as f32);
+    }
+
+    pub fn bounds(&self, use_tight_bounds: bool) -> Option<egui::Rect> {
+        self.actors.iter()
+            .filter(|actor| actor.is_visible)
+            .filter_map(|actor| actor.bounds(use_tight_bounds))
+            .reduce(|total, rect| total.union(rect))
+    }
+}
@@ -11,6 +11,8 @@ pub struct WorldTransform {
     pub hidden: bool,
     pub glow: i32,
     pub part_index: usize, 
+    // Colour mixed over the sprite, alpha is the strength, zero leaves it untouched
+    pub tint: [f32; 4],
 }
 
 // Represents the normalized local state of a part
@@ -213,6 +215,7 @@ fn solve_single_part(target_index: usize, parts: &[ModelPart], model: &Model) ->
         hidden: target_part.unit_id == -1 || target_part.sprite_index == -1 || target_global.opacity < 0.001,
         glow: target_part.glow_mode,
         part_index: target_index,
+        tint: [0.0; 4],
     }
 }
 
@@ -1,6 +1,8 @@
 use eframe::egui;
 use std::path::PathBuf;
 use crate::features::animation::ui::viewer::AnimViewer;
+use crate::features::animation::logic::overlay::{self, OverlayToggles};
+use crate::features::animation::logic::onion::{self, OnionSkin};
 use crate::features::settings::logic::state::Settings;
 
 const TILE_HEIGHT: f32 = 28.0; 
@@ -12,6 +14,8 @@ const COL2_W: f32 = 148.0;
 const NAV_W: f32 = 30.0;
 const INPUT_W: f32 = 80.0; 
 const COL3_W: f32 = 100.0;
+const TIMELINE_HEIGHT: f32 = 16.0;
+const NEXT_HIT_W: f32 = 60.0;
 
 // Animation Indices
 pub const IDX_WALK: usize = 0;
@@ -47,7 +51,7 @@ pub fn render_controls_overlay(
     let anim_id = egui::Id::new("controls_slide");
     let slide_factor = ui.ctx().animate_value_with_time(anim_id, target_slide, 0.35);
     
-    let current_offset = CONTROLS_SLIDE_DISTANCE * slide_factor;
+    let current_offset = (CONTROLS_SLIDE_DISTANCE + TIMELINE_HEIGHT + GAP) * slide_factor;
     let bottom_margin = 5.0 + OVERLAY_BOTTOM_OFFSET - current_offset;
 
     let builder = egui::UiBuilder::new()
@@ -80,6 +84,88 @@ pub fn render_controls_overlay(
     overlay_response.inner.response.rect.contains(pointer_position)
 }
 
+pub fn render_overlay_menu(
+    ui: &mut egui::Ui,
+    button_rect: egui::Rect,
+    toggles: &mut OverlayToggles,
+    has_profile: bool,
+) -> egui::Response {
+    let popup_id = egui::Id::new("anim_overlay_menu");
+    let background_fill = if toggles.any() && has_profile { egui::Color32::from_rgb(31, 106, 165) } else { egui::Color32::from_gray(60) };
+
+    let button_response = ui.put(button_rect, |ui: &mut egui::Ui| {
+        let button_widget = egui::Button::new(egui::RichText::new("📏").size(16.0).color(egui::Color32::WHITE))
+            .fill(background_fill)
+            .stroke(egui::Stroke::new(1.0, egui::Color32::from_gray(60)))
+            .rounding(4.0);
+        ui.add_enabled(has_profile, button_widget)
+    });
+
+    if button_response.clicked() {
+        ui.memory_mut(|memory| memory.toggle_popup(popup_id));
+    }
+
+    egui::popup_below_widget(ui, popup_id, &button_response, egui::PopupCloseBehavior::CloseOnClickOutside, |ui| {
+        ui.set_min_width(150.0);
+        ui.checkbox(&mut toggles.standing_range, "Standing Range");
+        ui.checkbox(&mut toggles.hit_areas, "Hit Areas");
+        ui.checkbox(&mut toggles.hitbox, "Hitbox");
+        ui.checkbox(&mut toggles.spawns, "Surge & Explosion");
+        ui.separator();
+        ui.checkbox(&mut toggles.hit_flash, "Flash Hits");
+    });
+
+    button_response.on_hover_text("Range Overlays")
+}
+
+pub fn render_onion_menu(
+    ui: &mut egui::Ui,
+    button_rect: egui::Rect,
+    onion: &mut OnionSkin,
+    has_animation: bool,
+) -> egui::Response {
+    let popup_id = egui::Id::new("anim_onion_menu");
+    let background_fill = if onion.is_active() && has_animation { egui::Color32::from_rgb(31, 106, 165) } else { egui::Color32::from_gray(60) };
+
+    let button_response = ui.put(button_rect, |ui: &mut egui::Ui| {
+        let button_widget = egui::Button::new(egui::RichText::new("👻").size(16.0).color(egui::Color32::WHITE))
+            .fill(background_fill)
+            .stroke(egui::Stroke::new(1.0, egui::Color32::from_gray(60)))
+            .rounding(4.0);
+        ui.add_enabled(has_animation, button_widget)
+    });
+
+    if button_response.clicked() {
+        ui.memory_mut(|memory| memory.toggle_popup(popup_id));
+    }
+
+    egui::popup_below_widget(ui, popup_id, &button_response, egui::PopupCloseBehavior::CloseOnClickOutside, |ui| {
+        ui.set_min_width(170.0);
+        ui.checkbox(&mut onion.enabled, "Onion Skin");
+        ui.add_enabled_ui(onion.enabled, |ui| {
+            egui::Grid::new("anim_onion_grid").num_columns(2).spacing([10.0, 4.0]).show(ui, |ui| {
+                ui.label("Previous");
+                ui.add(egui::DragValue::new(&mut onion.previous).range(0..=onion::MAX_GHOSTS));
+                ui.end_row();
+
+                ui.label("Next");
+                ui.add(egui::DragValue::new(&mut onion.next).range(0..=onion::MAX_GHOSTS));
+                ui.end_row();
+
+                ui.label("Step");
+                ui.add(egui::DragValue::new(&mut onion.step).range(1..=30)).on_hover_text("Frames between each ghost");
+                ui.end_row();
+
+                ui.label("Opacity");
+                ui.add(egui::Slider::new(&mut onion.opacity, 0.05..=1.0).show_value(false));
+                ui.end_row();
+            });
+        });
+    });
+
+    button_response.on_hover_text("Onion Skin")
+}
+
 fn render_internal_ui(
     ui: &mut egui::Ui,
     anim_viewer: &mut AnimViewer,
@@ -336,6 +422,8 @@ fn render_internal_ui(
         ui.ctx().request_repaint();
     }
 
+    render_timeline(ui, anim_viewer, max_frame_value, actual_width, base_assets_available && !is_locked && !is_model_mode);
+
     ui.add_sized(egui::vec2(actual_width, 1.0), egui::Separator::default().horizontal());
 
     let top_row_width = (button_width * 4.0) + (grid_gap * 3.0);
@@ -414,4 +502,49 @@ fn render_internal_ui(
         anim_viewer.export_state.frame_end = 0;
         anim_viewer.export_state.frame_end_str = String::new(); 
     }
-}
\ No newline at end of file
+}
+
+fn render_timeline(ui: &mut egui::Ui, anim_viewer: &mut AnimViewer, max_frame_value: i32, width: f32, is_enabled: bool) {
+    let hit_frames = anim_viewer.attack_profile().map(|profile| profile.hit_frames.clone()).unwrap_or_default();
+    let anim_frames = anim_viewer.current_anim.as_ref().map_or(0, |animation| animation.max_frame);
+    let length = max_frame_value.max(anim_frames).max(hit_frames.iter().copied().max().unwrap_or(0)).max(1) as f32;
+
+    ui.horizontal(|ui| {
+        ui.spacing_mut().item_spacing.x = GAP;
+        let bar_size = egui::vec2((width - NEXT_HIT_W - GAP).max(1.0), TIMELINE_HEIGHT);
+        let sense = if is_enabled { egui::Sense::click_and_drag() } else { egui::Sense::hover() };
+        let (bar_rect, response) = ui.allocate_exact_size(bar_size, sense);
+
+        let to_x = |frame: f32| bar_rect.left() + (frame / length).clamp(0.0, 1.0) * bar_rect.width();
+        let painter = ui.painter_at(bar_rect);
+        painter.rect_filled(bar_rect, 3.0, egui::Color32::from_gray(40));
+
+        for (index, &hit) in hit_frames.iter().enumerate() {
+            let x = to_x(hit as f32);
+            let color = overlay::HIT_COLORS[index % overlay::HIT_COLORS.len()];
+            painter.line_segment([egui::pos2(x, bar_rect.top()), egui::pos2(x, bar_rect.bottom())], egui::Stroke::new(3.0, color));
+        }
+
+        let playhead_x = to_x(anim_viewer.current_frame);
+        painter.line_segment([egui::pos2(playhead_x, bar_rect.top()), egui::pos2(playhead_x, bar_rect.bottom())], egui::Stroke::new(2.0, egui::Color32::WHITE));
+
+        if let Some(pointer) = response.interact_pointer_pos() {
+            let fraction = ((pointer.x - bar_rect.left()) / bar_rect.width()).clamp(0.0, 1.0);
+            anim_viewer.current_frame = (fraction * length).round();
+        }
+        if !hit_frames.is_empty() {
+            let hit_list = hit_frames.iter().map(|frame| frame.to_string()).collect::<Vec<_>>().join(", ");
+            response.on_hover_text(format!("Hits land on frame {}", hit_list));
+        }
+
+        let next_hit = anim_viewer.attack_profile().and_then(|profile| profile.next_hit(anim_viewer.current_frame));
+        let button = ui.add_enabled_ui(is_enabled && next_hit.is_some(), |ui| {
+            ui.add_sized(egui::vec2(NEXT_HIT_W, TIMELINE_HEIGHT), egui::Button::new(egui::RichText::new("Hit ⏭").size(11.0)))
+        }).inner.on_hover_text("Jump to the next hit frame");
+
+        if button.clicked() && let Some(frame) = next_hit {
+            anim_viewer.current_frame = frame as f32;
+            anim_viewer.is_playing = false;
+        }
+    });
+}
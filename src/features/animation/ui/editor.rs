@@ -0,0 +1,391 @@
+use std::path::Path;
+use eframe::egui;
+use crate::global::formats::maanim::Animation;
+use crate::global::formats::mamodel::Model;
+use crate::features::animation::logic::animator;
+use crate::features::animation::logic::editor::{self, CurveEditor, KeyDrag};
+
+const LABEL_WIDTH: f32 = 180.0;
+const ROW_HEIGHT: f32 = 18.0;
+const RULER_HEIGHT: f32 = 18.0;
+const FRAME_WIDTH: f32 = 6.0;
+const KEY_SIZE: f32 = 5.0;
+const PLOT_HEIGHT: f32 = 200.0;
+const PROPERTIES_WIDTH: f32 = 230.0;
+
+const ROW_COLOR: egui::Color32 = egui::Color32::from_gray(35);
+const SELECTED_ROW_COLOR: egui::Color32 = egui::Color32::from_rgb(30, 55, 80);
+const KEY_COLOR: egui::Color32 = egui::Color32::from_gray(200);
+const SELECTED_KEY_COLOR: egui::Color32 = egui::Color32::from_rgb(255, 200, 60);
+const CURVE_COLOR: egui::Color32 = egui::Color32::from_rgb(90, 170, 240);
+
+enum HistoryAction {
+    Undo,
+    Redo,
+}
+
+pub fn show(
+    ctx: &egui::Context,
+    state: &mut CurveEditor,
+    animation: &mut Animation,
+    model: Option<&Model>,
+    current_frame: &mut f32,
+    anim_path: Option<&Path>,
+) {
+    if !state.is_open { return; }
+
+    let mut is_open = state.is_open;
+    let mut history_action = shortcut_action(ctx);
+    let before = animation.curves.clone();
+    state.clamp_selection(animation);
+
+    egui::Window::new("Curve Editor")
+        .open(&mut is_open)
+        .default_size([780.0, 520.0])
+        .show(ctx, |ui| {
+            if let Some(action) = render_toolbar(ui, state, animation, anim_path) {
+                history_action = Some(action);
+            }
+            ui.separator();
+
+            egui::ScrollArea::both()
+                .id_salt("curve_editor_dopesheet")
+                .max_height(220.0)
+                .auto_shrink([false, true])
+                .show(ui, |ui| render_dopesheet(ui, state, animation, model, current_frame));
+
+            ui.separator();
+
+            let Some(curve_index) = state.selected_curve else {
+                ui.label(egui::RichText::new("Select a track to edit its curve").weak());
+                return;
+            };
+
+            ui.horizontal_top(|ui| {
+                let plot_width = (ui.available_width() - PROPERTIES_WIDTH - 10.0).max(100.0);
+                ui.allocate_ui(egui::vec2(plot_width, PLOT_HEIGHT), |ui| {
+                    render_plot(ui, state, animation, curve_index, *current_frame);
+                });
+                ui.separator();
+                ui.vertical(|ui| render_properties(ui, state, animation, curve_index, *current_frame));
+            });
+        });
+
+    let is_pointer_down = ctx.input(|input| input.pointer.any_down());
+    if !is_pointer_down { state.dragging = None; }
+    state.record(before, animation, is_pointer_down);
+
+    match history_action {
+        Some(HistoryAction::Undo) => state.undo(animation),
+        Some(HistoryAction::Redo) => state.redo(animation),
+        None => {}
+    }
+    state.is_open = is_open;
+}
+
+// Text fields keep their own undo, the editor only listens while nothing has focus
+fn shortcut_action(ctx: &egui::Context) -> Option<HistoryAction> {
+    if ctx.memory(|memory| memory.focused().is_some()) { return None; }
+
+    let redo = egui::KeyboardShortcut::new(egui::Modifiers::COMMAND | egui::Modifiers::SHIFT, egui::Key::Z);
+    let redo_alt = egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::Y);
+    let undo = egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::Z);
+
+    ctx.input_mut(|input| {
+        if input.consume_shortcut(&redo) || input.consume_shortcut(&redo_alt) {
+            Some(HistoryAction::Redo)
+        } else if input.consume_shortcut(&undo) {
+            Some(HistoryAction::Undo)
+        } else {
+            None
+        }
+    })
+}
+
+fn render_toolbar(ui: &mut egui::Ui, state: &mut CurveEditor, animation: &Animation, anim_path: Option<&Path>) -> Option<HistoryAction> {
+    let mut action = None;
+
+    ui.horizontal(|ui| {
+        if ui.add_enabled(state.can_undo(), egui::Button::new("⟲ Undo")).clicked() { action = Some(HistoryAction::Undo); }
+        if ui.add_enabled(state.can_redo(), egui::Button::new("⟳ Redo")).clicked() { action = Some(HistoryAction::Redo); }
+        ui.separator();
+
+        let mod_path = anim_path.and_then(editor::mod_save_path);
+        let save_button = ui.add_enabled(mod_path.is_some(), egui::Button::new("Save to Mod"))
+            .on_disabled_hover_text("Activate a mod to save into its folder");
+        if let Some(path) = &mod_path {
+            let save_button = save_button.on_hover_text(path.display().to_string());
+            if save_button.clicked() {
+                save(state, animation, path);
+            }
+        }
+
+        if ui.button("Save As").clicked() {
+            let file_name = anim_path.and_then(Path::file_name).map_or("animation.maanim".to_string(), |name| name.to_string_lossy().to_string());
+            if let Some(path) = rfd::FileDialog::new().add_filter("maanim", &["maanim"]).set_directory("mods").set_file_name(file_name).save_file() {
+                save(state, animation, &path);
+            }
+        }
+
+        if state.is_dirty {
+            ui.label(egui::RichText::new("Unsaved").color(SELECTED_KEY_COLOR));
+        }
+        if let Some(status) = &state.status {
+            ui.label(egui::RichText::new(status).weak());
+        }
+    });
+
+    action
+}
+
+fn save(state: &mut CurveEditor, animation: &Animation, path: &Path) {
+    state.status = Some(match animation.save(path) {
+        Ok(()) => {
+            state.is_dirty = false;
+            format!("Saved to {}", path.display())
+        }
+        Err(error) => format!("Save failed: {}", error),
+    });
+}
+
+fn track_label(model: Option<&Model>, part_id: usize, modification_type: i32) -> String {
+    let part_name = model.and_then(|model| model.parts.get(part_id)).map_or("", |part| part.name.as_str());
+    if part_name.is_empty() {
+        format!("#{} {}", part_id, editor::modification_name(modification_type))
+    } else {
+        format!("#{} {} · {}", part_id, part_name, editor::modification_name(modification_type))
+    }
+}
+
+fn frame_at(track_left: f32, x: f32) -> i32 {
+    ((x - track_left) / FRAME_WIDTH).round().max(0.0) as i32
+}
+
+fn render_dopesheet(ui: &mut egui::Ui, state: &mut CurveEditor, animation: &mut Animation, model: Option<&Model>, current_frame: &mut f32) {
+    let frame_count = animation.max_frame.max(1) + 20;
+    let size = egui::vec2(LABEL_WIDTH + frame_count as f32 * FRAME_WIDTH, RULER_HEIGHT + animation.curves.len() as f32 * ROW_HEIGHT);
+    let (canvas, _) = ui.allocate_exact_size(size, egui::Sense::hover());
+    let painter = ui.painter_at(canvas);
+    let track_left = canvas.left() + LABEL_WIDTH;
+    let frame_x = |frame: f32| track_left + frame * FRAME_WIDTH;
+
+    // Ruler, clicking it scrubs the preview
+    let ruler = egui::Rect::from_min_max(egui::pos2(track_left, canvas.top()), egui::pos2(canvas.right(), canvas.top() + RULER_HEIGHT));
+    painter.rect_filled(ruler, 0.0, egui::Color32::from_gray(25));
+    for frame in (0..frame_count).step_by(5) {
+        let x = frame_x(frame as f32);
+        let tick_height = if frame % 10 == 0 { RULER_HEIGHT * 0.6 } else { RULER_HEIGHT * 0.3 };
+        painter.line_segment([egui::pos2(x, ruler.bottom() - tick_height), egui::pos2(x, ruler.bottom())], egui::Stroke::new(1.0, egui::Color32::GRAY));
+        if frame % 10 == 0 {
+            painter.text(egui::pos2(x + 2.0, ruler.top()), egui::Align2::LEFT_TOP, frame.to_string(), egui::FontId::proportional(10.0), egui::Color32::GRAY);
+        }
+    }
+    let ruler_response = ui.interact(ruler, ui.id().with("curve_editor_ruler"), egui::Sense::click_and_drag());
+    if let Some(pointer) = ruler_response.interact_pointer_pos() {
+        *current_frame = frame_at(track_left, pointer.x) as f32;
+    }
+
+    for curve_index in 0..animation.curves.len() {
+        let row_top = canvas.top() + RULER_HEIGHT + curve_index as f32 * ROW_HEIGHT;
+        let row = egui::Rect::from_min_max(egui::pos2(canvas.left(), row_top), egui::pos2(canvas.right(), row_top + ROW_HEIGHT));
+        let label_rect = egui::Rect::from_min_max(row.min, egui::pos2(track_left, row.bottom()));
+        let track_rect = egui::Rect::from_min_max(egui::pos2(track_left, row.top()), row.max);
+        let is_selected = state.selected_curve == Some(curve_index);
+
+        painter.rect_filled(row.shrink2(egui::vec2(0.0, 1.0)), 0.0, if is_selected { SELECTED_ROW_COLOR } else { ROW_COLOR });
+
+        let curve = &animation.curves[curve_index];
+        let label = track_label(model, curve.part_id, curve.modification_type);
+        painter.with_clip_rect(label_rect).text(label_rect.left_center() + egui::vec2(4.0, 0.0), egui::Align2::LEFT_CENTER, label, egui::FontId::proportional(11.0), egui::Color32::WHITE);
+
+        let label_response = ui.interact(label_rect, ui.id().with(("curve_editor_label", curve_index)), egui::Sense::click());
+        if label_response.clicked() {
+            state.selected_curve = Some(curve_index);
+            state.selected_key = None;
+        }
+
+        // Double clicking an empty spot on a track keys it there
+        let track_response = ui.interact(track_rect, ui.id().with(("curve_editor_track", curve_index)), egui::Sense::click());
+        if track_response.double_clicked() && let Some(pointer) = track_response.interact_pointer_pos() {
+            let key = editor::add_keyframe(&mut animation.curves[curve_index], frame_at(track_left, pointer.x));
+            state.selected_curve = Some(curve_index);
+            state.selected_key = Some(key);
+        } else if track_response.clicked() {
+            state.selected_curve = Some(curve_index);
+        }
+
+        for key_index in 0..animation.curves[curve_index].keyframes.len() {
+            let keyframe = &animation.curves[curve_index].keyframes[key_index];
+            let center = egui::pos2(frame_x(keyframe.frame as f32), row.center().y);
+            let key_rect = egui::Rect::from_center_size(center, egui::vec2(KEY_SIZE * 2.0, KEY_SIZE * 2.0));
+            let is_key_selected = is_selected && state.selected_key == Some(key_index);
+
+            let diamond = vec![center + egui::vec2(0.0, -KEY_SIZE), center + egui::vec2(KEY_SIZE, 0.0), center + egui::vec2(0.0, KEY_SIZE), center + egui::vec2(-KEY_SIZE, 0.0)];
+            let color = if is_key_selected { SELECTED_KEY_COLOR } else { KEY_COLOR };
+            painter.add(egui::Shape::convex_polygon(diamond, color, egui::Stroke::new(1.0, egui::Color32::BLACK)));
+
+            let key_response = ui.interact(key_rect, ui.id().with(("curve_editor_key", curve_index, key_index)), egui::Sense::click_and_drag());
+            let key_response = key_response.on_hover_text(format!("Frame {}\nValue {}\n{}", keyframe.frame, keyframe.value, editor::ease_name(keyframe.ease_mode)));
+            if key_response.clicked() || key_response.drag_started() {
+                state.selected_curve = Some(curve_index);
+                state.selected_key = Some(key_index);
+            }
+            if key_response.drag_started() {
+                state.dragging = Some(KeyDrag { curve: curve_index, key: key_index, in_plot: false });
+            }
+        }
+    }
+
+    // Moving a key can reorder the track, the drag follows the key rather than the widget
+    if let Some(drag) = state.dragging.filter(|drag| !drag.in_plot)
+        && let Some(pointer) = ui.ctx().pointer_latest_pos()
+        && let Some(curve) = animation.curves.get_mut(drag.curve)
+        && let Some(keyframe) = curve.keyframes.get(drag.key)
+    {
+        let value = keyframe.value;
+        let new_key = editor::move_keyframe(curve, drag.key, frame_at(track_left, pointer.x), value);
+        state.dragging = Some(KeyDrag { key: new_key, ..drag });
+        state.selected_key = Some(new_key);
+    }
+
+    let playhead_x = frame_x(*current_frame);
+    painter.line_segment([egui::pos2(playhead_x, canvas.top()), egui::pos2(playhead_x, canvas.bottom())], egui::Stroke::new(1.5, egui::Color32::RED));
+}
+
+fn render_plot(ui: &mut egui::Ui, state: &mut CurveEditor, animation: &mut Animation, curve_index: usize, current_frame: f32) {
+    let (rect, _) = ui.allocate_exact_size(egui::vec2(ui.available_width(), PLOT_HEIGHT), egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(rect, 4.0, egui::Color32::from_gray(25));
+
+    let Some(curve) = animation.curves.get(curve_index) else { return; };
+    let last_frame = curve.keyframes.last().map_or(0, |keyframe| keyframe.frame).max(animation.max_frame).max(1) as f32;
+
+    let samples: Vec<(f32, f32)> = (0..=(last_frame * 2.0) as i32)
+        .filter_map(|step| {
+            let frame = step as f32 / 2.0;
+            animator::sample_curve(curve, frame).map(|value| (frame, value))
+        })
+        .collect();
+
+    let values = curve.keyframes.iter().map(|keyframe| keyframe.value as f32).chain(samples.iter().map(|(_, value)| *value));
+    let (mut low, mut high) = values.fold((f32::MAX, f32::MIN), |(low, high), value| (low.min(value), high.max(value)));
+    if low > high { (low, high) = (0.0, 1.0); }
+    if (high - low).abs() < 1.0 { low -= 10.0; high += 10.0; }
+    let padding = (high - low) * 0.1;
+    let (low, high) = (low - padding, high + padding);
+
+    let plot = rect.shrink(8.0);
+    let to_screen = |frame: f32, value: f32| egui::pos2(
+        plot.left() + frame / last_frame * plot.width(),
+        plot.bottom() - (value - low) / (high - low) * plot.height(),
+    );
+    let from_screen = |pos: egui::Pos2| (
+        ((pos.x - plot.left()) / plot.width() * last_frame).round() as i32,
+        (low + (plot.bottom() - pos.y) / plot.height() * (high - low)).round() as i32,
+    );
+
+    if low < 0.0 && high > 0.0 {
+        let zero = to_screen(0.0, 0.0).y;
+        painter.line_segment([egui::pos2(plot.left(), zero), egui::pos2(plot.right(), zero)], egui::Stroke::new(1.0, egui::Color32::from_gray(60)));
+    }
+    painter.text(rect.left_top() + egui::vec2(4.0, 2.0), egui::Align2::LEFT_TOP, format!("{:.0}", high), egui::FontId::proportional(10.0), egui::Color32::GRAY);
+    painter.text(rect.left_bottom() + egui::vec2(4.0, -2.0), egui::Align2::LEFT_BOTTOM, format!("{:.0}", low), egui::FontId::proportional(10.0), egui::Color32::GRAY);
+
+    let line: Vec<egui::Pos2> = samples.iter().map(|&(frame, value)| to_screen(frame, value)).collect();
+    if line.len() > 1 {
+        painter.add(egui::Shape::line(line, egui::Stroke::new(1.5, CURVE_COLOR)));
+    }
+
+    let playhead_x = to_screen(current_frame, 0.0).x;
+    painter.line_segment([egui::pos2(playhead_x, rect.top()), egui::pos2(playhead_x, rect.bottom())], egui::Stroke::new(1.0, egui::Color32::RED));
+
+    for (key_index, keyframe) in curve.keyframes.iter().enumerate() {
+        let center = to_screen(keyframe.frame as f32, keyframe.value as f32);
+        let is_selected = state.selected_key == Some(key_index);
+        painter.circle(center, 4.0, if is_selected { SELECTED_KEY_COLOR } else { KEY_COLOR }, egui::Stroke::new(1.0, egui::Color32::BLACK));
+
+        let response = ui.interact(egui::Rect::from_center_size(center, egui::vec2(10.0, 10.0)), ui.id().with(("curve_editor_point", key_index)), egui::Sense::click_and_drag());
+        if response.clicked() || response.drag_started() {
+            state.selected_key = Some(key_index);
+        }
+        if response.drag_started() {
+            state.dragging = Some(KeyDrag { curve: curve_index, key: key_index, in_plot: true });
+        }
+    }
+
+    if let Some(drag) = state.dragging.filter(|drag| drag.in_plot && drag.curve == curve_index)
+        && let Some(pointer) = ui.ctx().pointer_latest_pos()
+        && drag.key < animation.curves[curve_index].keyframes.len()
+    {
+        let (frame, value) = from_screen(pointer);
+        let new_key = editor::move_keyframe(&mut animation.curves[curve_index], drag.key, frame, value);
+        state.dragging = Some(KeyDrag { key: new_key, ..drag });
+        state.selected_key = Some(new_key);
+    }
+}
+
+fn render_properties(ui: &mut egui::Ui, state: &mut CurveEditor, animation: &mut Animation, curve_index: usize, current_frame: f32) {
+    ui.set_width(PROPERTIES_WIDTH);
+    let Some(curve) = animation.curves.get_mut(curve_index) else { return; };
+
+    ui.strong(format!("#{} {}", curve.part_id, editor::modification_name(curve.modification_type)));
+    ui.horizontal(|ui| {
+        ui.label("Loops");
+        ui.add(egui::DragValue::new(&mut curve.loop_count).range(-1..=9999))
+            .on_hover_text("1 plays once, -1 repeats forever");
+    });
+
+    ui.horizontal(|ui| {
+        if ui.button("Key at Playhead").clicked() {
+            state.selected_key = Some(editor::add_keyframe(curve, current_frame.round() as i32));
+        }
+        let can_delete = state.selected_key.is_some() && curve.keyframes.len() > 1;
+        if ui.add_enabled(can_delete, egui::Button::new("Delete Key")).clicked()
+            && let Some(key_index) = state.selected_key
+            && editor::delete_keyframe(curve, key_index)
+        {
+            state.selected_key = None;
+        }
+    });
+    ui.separator();
+
+    let Some(key_index) = state.selected_key.filter(|&key| key < curve.keyframes.len()) else {
+        ui.label(egui::RichText::new("Select a key to edit it").weak());
+        return;
+    };
+
+    let keyframe = &curve.keyframes[key_index];
+    let (mut frame, mut value) = (keyframe.frame, keyframe.value);
+    let (mut ease_mode, mut ease_power) = (keyframe.ease_mode, keyframe.ease_power);
+
+    egui::Grid::new("curve_editor_key").num_columns(2).spacing([10.0, 4.0]).show(ui, |ui| {
+        ui.label("Frame");
+        ui.add(egui::DragValue::new(&mut frame).range(0..=i32::MAX));
+        ui.end_row();
+
+        ui.label("Value");
+        ui.add(egui::DragValue::new(&mut value));
+        ui.end_row();
+
+        ui.label("Ease");
+        egui::ComboBox::from_id_salt("curve_editor_ease")
+            .selected_text(editor::ease_name(ease_mode))
+            .show_ui(ui, |ui| {
+                for (mode, name) in editor::EASE_MODES {
+                    ui.selectable_value(&mut ease_mode, mode, name);
+                }
+            });
+        ui.end_row();
+
+        ui.label("Power");
+        ui.add_enabled(ease_mode == 2, egui::DragValue::new(&mut ease_power));
+        ui.end_row();
+    });
+
+    let keyframe = &mut curve.keyframes[key_index];
+    keyframe.ease_mode = ease_mode;
+    keyframe.ease_power = ease_power;
+    if frame != keyframe.frame || value != keyframe.value {
+        state.selected_key = Some(editor::move_keyframe(curve, key_index, frame, value));
+    }
+}
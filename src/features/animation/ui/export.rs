@@ -2,15 +2,17 @@ use eframe::egui;
 use std::time::Duration;
 use std::path::PathBuf;
 use std::sync::{Arc, atomic::{AtomicBool, Ordering}};
+use std::sync::mpsc::TryRecvError;
 use crate::global::formats::mamodel::Model;
 use crate::global::formats::maanim::Animation;
 use crate::global::formats::imgcut::SpriteSheet;
-use crate::features::animation::export::encoding::{ExportFormat, EncoderStatus};
+use crate::features::animation::export::encoding::{ExportFormat, EncoderStatus, MAX_SUPERSAMPLE};
 use crate::features::animation::export::state::{ExporterState, ExportMode, LoopStatus};
 use crate::features::animation::export::process::{start_export, STATUS_RX};
 use crate::features::animation::export::findloop;
 use crate::features::settings::ui::toggle_ui; 
-use crate::features::animation::logic::bounds;
+use crate::features::animation::logic::{bounds, playlist, scene};
+use crate::features::animation::logic::camera::{CameraEase, CameraKey, CameraView, FollowMode};
 use crate::features::addons::toolpaths::{self, Presence};
 use crate::features::settings::logic::state::Settings;
 
@@ -74,11 +76,16 @@ pub fn show_popup(
             while let Ok(loop_message) = loop_receiver.try_recv() {
                 match loop_message {
                     LoopStatus::Searching(searched_frames) => { state.loop_frames_searched = searched_frames; },
-                    LoopStatus::Found(loop_start, loop_end) => {
+                    LoopStatus::Found(loop_start, loop_end, confirmed) => {
                         state.frame_start = loop_start;
                         state.frame_end = loop_end;
                         state.frame_start_str = loop_start.to_string();
                         state.frame_end_str = loop_end.to_string();
+
+                        let start_ms = findloop::frames_to_ms(loop_start as i64);
+                        let length_ms = findloop::frames_to_ms((loop_end - loop_start + 1) as i64);
+                        let note = if confirmed { "" } else { " (unconfirmed)" };
+                        state.loop_result_msg = Some(format!("Loop at {}ms, {}ms long{}", start_ms, length_ms, note));
                         
                         state.completion_time = Some(ui.input(|input| input.time));
                         is_loop_finished = true;
@@ -87,11 +94,7 @@ pub fn show_popup(
                         is_loop_finished = true;
                     },
                     LoopStatus::Error(error_message) => {
-                        if error_message.contains("Timed out") {
-                            state.loop_result_msg = Some("Loop Search Timeout (180s)".to_string());
-                        } else {
-                             state.loop_result_msg = Some(error_message);
-                        }
+                        state.loop_result_msg = Some(error_message);
                         
                         state.completion_time = Some(ui.input(|input| input.time));
                         ui.ctx().data_mut(|data_store| data_store.insert_temp(egui::Id::new("export_done_seen"), false));
@@ -109,6 +112,20 @@ pub fn show_popup(
         state.loop_abort = None;
     }
 
+    if let Some(report_receiver) = &state.loop_report_rx {
+        ui.ctx().request_repaint_after(Duration::from_millis(50));
+        loop {
+            match report_receiver.try_recv() {
+                Ok(report) => state.loop_reports.push(report),
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    state.loop_report_rx = None;
+                    break;
+                }
+            }
+        }
+    }
+
     // UI RENDERING
     if !settings.animation.export_popup_open { return; }
 
@@ -184,6 +201,8 @@ fn render_content(
                      ExportMode::Manual => "Manual",
                      ExportMode::Loop => "Loop",
                      ExportMode::Showcase => "Showcase",
+                     ExportMode::Scene => "Scene",
+                     ExportMode::Playlist => "Playlist",
                  }).show_ui(ui, |ui| {
                      ui.selectable_value(&mut selected_mode, ExportMode::Manual, "Manual");
                      
@@ -195,6 +214,20 @@ fn render_content(
                      }
                      
                      ui.selectable_value(&mut selected_mode, ExportMode::Showcase, "Showcase");
+
+                     if state.playlist_length > 0 {
+                         ui.selectable_value(&mut selected_mode, ExportMode::Playlist, "Playlist");
+                     } else {
+                         let disabled_label = ui.add_enabled(false, egui::SelectableLabel::new(false, "Playlist"));
+                         disabled_label.on_disabled_hover_text("Pick a playlist with entries in the Playlist editor");
+                     }
+
+                     if state.scene_length > 0 {
+                         ui.selectable_value(&mut selected_mode, ExportMode::Scene, "Scene");
+                     } else {
+                         let disabled_label = ui.add_enabled(false, egui::SelectableLabel::new(false, "Scene"));
+                         disabled_label.on_disabled_hover_text("Add units in the Scene Composer first");
+                     }
                  });
                  if selected_mode != state.export_mode {
                      // Mode Switch Logic
@@ -263,20 +296,7 @@ fn render_content(
                 // Locked when locked
                 ui.add_enabled_ui(!is_ui_locked, |ui| {
                     egui::Grid::new("loop_settings_grid").num_columns(2).spacing([10.0, 8.0]).show(ui, |ui| {
-                        // Row 1: Tolerance
-                        ui.label("Loop Tolerance");
-                        ui.horizontal(|ui| {
-                            ui.spacing_mut().item_spacing.x = EXPORT_MODE_SPACING;
-                            let hint = egui::RichText::new("30").color(egui::Color32::GRAY);
-                            if ui.add(egui::TextEdit::singleline(&mut state.loop_tolerance_str).hint_text(hint).desired_width(40.0)).changed() {
-                                if state.loop_tolerance_str.trim().is_empty() { state.loop_tolerance = 30; }
-                                else if let Ok(parsed_tolerance) = state.loop_tolerance_str.parse::<i32>() { state.loop_tolerance = parsed_tolerance; }
-                            }
-                            ui.label("%");
-                        });
-                        ui.end_row();
-                        
-                        // Row 2: Minimum
+                        // Row 1: Minimum
                         ui.label("Loop Minimum");
                         ui.horizontal(|ui| {
                             ui.spacing_mut().item_spacing.x = EXPORT_MODE_SPACING;
@@ -289,7 +309,7 @@ fn render_content(
                         });
                         ui.end_row();
 
-                        // Row 3: Maximum
+                        // Row 2: Maximum
                         ui.label("Loop Maximum");
                         ui.horizontal(|ui| {
                             ui.spacing_mut().item_spacing.x = EXPORT_MODE_SPACING;
@@ -353,13 +373,6 @@ fn render_content(
                             let Some(model_data) = model else { return; };
                             let Some(animation_data) = anim else { return; };
                             
-                            let mut parsed_tolerance = 30;
-                            if !state.loop_tolerance_str.is_empty() {
-                                if let Ok(val) = state.loop_tolerance_str.parse() {
-                                    parsed_tolerance = val;
-                                }
-                            }
-                            
                             let mut parsed_minimum = 15;
                             if !state.loop_min_str.is_empty() {
                                 if let Ok(val) = state.loop_min_str.parse() {
@@ -367,7 +380,6 @@ fn render_content(
                                 }
                             }
 
-                            state.loop_tolerance = parsed_tolerance;
                             state.loop_min = parsed_minimum;
 
                             let (search_sender, search_receiver) = std::sync::mpsc::channel();
@@ -380,7 +392,7 @@ fn render_content(
                             let abort_signal = Arc::new(AtomicBool::new(false));
                             state.loop_abort = Some(abort_signal.clone());
                             
-                            findloop::start_search(model_data.clone(), animation_data.clone(), parsed_tolerance, parsed_minimum, state.loop_max, search_sender, abort_signal);
+                            findloop::start_search(model_data.clone(), animation_data.clone(), parsed_minimum, state.loop_max, search_sender, abort_signal);
                             
                             // Reset termination msg
                             state.loop_result_msg = None;
@@ -399,6 +411,9 @@ fn render_content(
                         }
                     });
                 }
+
+                ui.add_space(5.0);
+                render_loop_report(ui, state, model, available_anims);
             },
             ExportMode::Showcase => {
                 ui.add_enabled_ui(!is_ui_locked, |ui| {
@@ -482,6 +497,41 @@ fn render_content(
                         ui.end_row();
                     });
                 });
+            },
+            ExportMode::Scene => {
+                ui.add_enabled_ui(!is_ui_locked, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.spacing_mut().item_spacing.x = EXPORT_MODE_SPACING;
+                        let mut display_start = "0".to_string();
+                        let mut display_end = (state.scene_length - 1).max(0).to_string();
+                        ui.add_enabled(false, egui::TextEdit::singleline(&mut display_start).desired_width(40.0));
+                        ui.add_enabled(false, egui::Label::new("f"));
+                        ui.add_space(5.0);
+                        ui.add_enabled(false, egui::Label::new("~"));
+                        ui.add_space(5.0);
+                        ui.add_enabled(false, egui::TextEdit::singleline(&mut display_end).desired_width(40.0));
+                        ui.add_enabled(false, egui::Label::new("f"));
+                    });
+                    ui.label(egui::RichText::new("Length and units are set in the Scene Composer").weak());
+                });
+            },
+            ExportMode::Playlist => {
+                ui.add_enabled_ui(!is_ui_locked, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.spacing_mut().item_spacing.x = EXPORT_MODE_SPACING;
+                        let mut display_start = "0".to_string();
+                        let mut display_end = (state.playlist_length - 1).max(0).to_string();
+                        ui.add_enabled(false, egui::TextEdit::singleline(&mut display_start).desired_width(40.0));
+                        ui.add_enabled(false, egui::Label::new("f"));
+                        ui.add_space(5.0);
+                        ui.add_enabled(false, egui::Label::new("~"));
+                        ui.add_space(5.0);
+                        ui.add_enabled(false, egui::TextEdit::singleline(&mut display_end).desired_width(40.0));
+                        ui.add_enabled(false, egui::Label::new("f"));
+                    });
+                    let playlist_name = state.playlist.as_ref().map_or("", |playlist| playlist.name.as_str());
+                    ui.label(egui::RichText::new(format!("Plays \"{}\" from the Playlist editor", playlist_name)).weak());
+                });
             }
         }
 
@@ -497,9 +547,21 @@ fn render_content(
                 }                
                 if ui.button("Use Bounds").on_hover_text("Auto-calculate camera from unit size").clicked() { 
                     let mut was_calculated = false;
-                    if let (Some(model_data), Some(sheet_data)) = (model, sheet) {
+                    if state.export_mode == ExportMode::Scene {
+                        let scene_bounds = scene::SCENE.lock().ok().and_then(|scene| scene.bounds(settings.animation.use_tight_bounds));
+                        if let Some(calculated_bounds) = scene_bounds {
+                            state.region_x = calculated_bounds.min.x;
+                            state.region_y = calculated_bounds.min.y;
+                            state.region_w = calculated_bounds.width();
+                            state.region_h = calculated_bounds.height();
+                            state.zoom = 1.0;
+                            was_calculated = true;
+                        }
+                    } else if let (Some(model_data), Some(sheet_data)) = (model, sheet) {
                         
-                        let master_bounds = if state.export_mode == ExportMode::Showcase {
+                        let master_bounds = if state.export_mode == ExportMode::Playlist && let Some(active_playlist) = &state.playlist {
+                            playlist::calculate_bounds(model_data, sheet_data, available_anims, active_playlist, settings.animation.use_tight_bounds)
+                        } else if state.export_mode == ExportMode::Showcase {
                             bounds::calculate_showcase_bounds(
                                 model_data, sheet_data, available_anims, 
                                 state.showcase_walk_len, state.showcase_idle_len, 
@@ -549,6 +611,9 @@ fn render_content(
                     ui.label("H"); ui.add(egui::DragValue::new(&mut state.region_h).range(0.0..=10000.0).speed(1.0));
                     ui.end_row();
                 });
+
+            ui.add_space(5.0);
+            render_camera_keys(ui, state);
         });
 
         ui.add_space(20.0);
@@ -719,6 +784,24 @@ fn render_content(
                         }
                     });
                     ui.end_row();
+
+                    // ANTI-ALIASING
+                    ui.label("Anti-Aliasing").on_hover_text("Renders every frame larger and scales it down, smoothing the edges of rotated sprites");
+                    let supersample_label = |factor: u32| if factor <= 1 { "Off".to_string() } else { format!("{}×", factor) };
+                    let mut selected_supersample = state.supersample;
+                    egui::ComboBox::from_id_salt("supersample_combo")
+                        .width(60.0)
+                        .selected_text(supersample_label(selected_supersample))
+                        .show_ui(ui, |ui| {
+                            for factor in 1..=MAX_SUPERSAMPLE {
+                                ui.selectable_value(&mut selected_supersample, factor, supersample_label(factor));
+                            }
+                        });
+                    if selected_supersample != state.supersample {
+                        state.supersample = selected_supersample;
+                        settings.animation.last_export_supersample = selected_supersample;
+                    }
+                    ui.end_row();
             });
             
             // BACKGROUND LOGIC
@@ -742,6 +825,33 @@ fn render_content(
                 
                 ui.label("Background").on_hover_text("Adds a gray background to the image"); 
             });
+
+            ui.horizontal(|ui| {
+                let tooltip = "Draws the enabled range and hitbox overlays into every frame";
+                let has_overlays = settings.animation.overlays.any();
+                ui.add_enabled_ui(has_overlays, |ui| {
+                    toggle_ui(ui, &mut state.include_overlays);
+                }).response.on_disabled_hover_text("Enable an overlay in the viewer first");
+                ui.label("Overlays").on_hover_text(tooltip);
+            });
+
+            ui.horizontal(|ui| {
+                let tooltip = "Adds a strip along the bottom marking the frames where attacks land";
+                let has_hits = !state.hit_frames.is_empty();
+                ui.add_enabled_ui(has_hits, |ui| {
+                    toggle_ui(ui, &mut state.include_hit_track);
+                }).response.on_disabled_hover_text("Only available for attack animations");
+                ui.label("Hit Markers").on_hover_text(tooltip);
+            });
+
+            ui.horizontal(|ui| {
+                let tooltip = "Draws the onion skin ghosts from the viewer behind every frame";
+                let has_onion = settings.animation.onion.is_active();
+                ui.add_enabled_ui(has_onion, |ui| {
+                    toggle_ui(ui, &mut state.include_onion);
+                }).response.on_disabled_hover_text("Enable onion skinning in the viewer first");
+                ui.label("Onion Skin").on_hover_text(tooltip);
+            });
             
         });
 
@@ -923,4 +1033,115 @@ fn render_content(
         
         ui.add_space(5.0); ui.separator(); 
     });
-}
\ No newline at end of file
+}
+
+// Keys move the camera over the export, follow keeps the unit's bounds centered each frame
+fn render_camera_keys(ui: &mut egui::Ui, state: &mut ExporterState) {
+    ui.horizontal(|ui| {
+        ui.label("Follow");
+        egui::ComboBox::from_id_salt("camera_follow")
+            .width(90.0)
+            .selected_text(match state.camera.follow { FollowMode::Off => "Off", FollowMode::Horizontal => "Horizontal", FollowMode::Both => "Both" })
+            .show_ui(ui, |ui| {
+                ui.selectable_value(&mut state.camera.follow, FollowMode::Off, "Off");
+                ui.selectable_value(&mut state.camera.follow, FollowMode::Horizontal, "Horizontal");
+                ui.selectable_value(&mut state.camera.follow, FollowMode::Both, "Both");
+            });
+        if state.camera.is_following() {
+            ui.add(egui::Slider::new(&mut state.camera.smoothing, 0.0..=0.95).text("Smoothing"));
+        }
+    });
+
+    let mut removed_key = None;
+    egui::CollapsingHeader::new(format!("Camera Keys ({})", state.camera.keys.len())).id_salt("camera_keys").show(ui, |ui| {
+        if state.camera.keys.is_empty() {
+            ui.label(egui::RichText::new("No keys, the camera stays where it's set").weak());
+        } else {
+            let offset_label = if state.camera.is_following() { "Offset" } else { "Center" };
+            egui::Grid::new("camera_keys_grid").num_columns(6).spacing([6.0, 4.0]).show(ui, |ui| {
+                for header in ["Frame", offset_label, "", "Zoom", "Ease", ""] {
+                    ui.strong(header);
+                }
+                ui.end_row();
+
+                for (key_index, key) in state.camera.keys.iter_mut().enumerate() {
+                    ui.add(egui::DragValue::new(&mut key.frame).range(0..=i32::MAX).suffix("f"));
+                    ui.add(egui::DragValue::new(&mut key.center.x).speed(1.0).prefix("X "));
+                    ui.add(egui::DragValue::new(&mut key.center.y).speed(1.0).prefix("Y "));
+                    ui.add(egui::DragValue::new(&mut key.zoom).range(0.05..=20.0).speed(0.01).suffix("×"));
+                    egui::ComboBox::from_id_salt(("camera_key_ease", key_index))
+                        .width(80.0)
+                        .selected_text(key.ease.label())
+                        .show_ui(ui, |ui| {
+                            for ease in CameraEase::ALL {
+                                ui.selectable_value(&mut key.ease, ease, ease.label());
+                            }
+                        });
+                    if ui.small_button("✖").clicked() { removed_key = Some(key_index); }
+                    ui.end_row();
+                }
+            });
+        }
+
+        // New keys start from the current region, or right on the unit when following
+        if ui.button("➕ Add Key").clicked() {
+            let base = CameraView::from_region(egui::vec2(state.region_x, state.region_y), egui::vec2(state.region_w, state.region_h), state.zoom);
+            let frame = state.camera.keys.iter().map(|key| key.frame + 30).max().unwrap_or(0);
+            let center = match state.camera.follow {
+                FollowMode::Off => base.center,
+                FollowMode::Horizontal => egui::vec2(0.0, base.center.y),
+                FollowMode::Both => egui::Vec2::ZERO,
+            };
+            state.camera.keys.push(CameraKey { frame, center, zoom: base.zoom, ease: CameraEase::default() });
+        }
+    });
+
+    if let Some(index) = removed_key {
+        state.camera.keys.remove(index);
+    }
+}
+
+// Loop start and length of every animation the unit has, in milliseconds of game time
+fn render_loop_report(ui: &mut egui::Ui, state: &mut ExporterState, model: Option<&Model>, available_anims: &[(usize, PathBuf)]) {
+    let is_reporting = state.loop_report_rx.is_some();
+    let can_report = model.is_some() && !available_anims.is_empty() && !is_reporting && !state.is_processing;
+
+    let label = if is_reporting { "Finding Loops..." } else { "Find All Loops" };
+    if ui.add_enabled(can_report, egui::Button::new(label).min_size(egui::vec2(ui.available_width(), 24.0))).clicked()
+        && let Some(model_data) = model
+    {
+        let (report_sender, report_receiver) = std::sync::mpsc::channel();
+        state.loop_reports.clear();
+        state.loop_report_rx = Some(report_receiver);
+        findloop::start_report(model_data.clone(), available_anims.to_vec(), report_sender);
+    }
+
+    if state.loop_reports.is_empty() { return; }
+
+    egui::Grid::new("loop_report_grid").num_columns(3).spacing([10.0, 4.0]).striped(true).show(ui, |ui| {
+        ui.strong("Animation");
+        ui.strong("Start");
+        ui.strong("Length");
+        ui.end_row();
+
+        for report in &state.loop_reports {
+            ui.label(scene::anim_label(report.anim_index));
+            match &report.result {
+                Ok(found) => {
+                    ui.label(format!("{}ms", findloop::frames_to_ms(found.start as i64)));
+                    let length = format!("{}ms", findloop::frames_to_ms(found.length));
+                    if found.confirmed {
+                        ui.label(length);
+                    } else {
+                        ui.label(format!("{} (unconfirmed)", length)).on_hover_text("Too long to check frame by frame, taken from the animation curves");
+                    }
+                }
+                Err(message) => {
+                    ui.label(egui::RichText::new(message).weak());
+                    ui.label("");
+                }
+            }
+            ui.end_row();
+        }
+    });
+}
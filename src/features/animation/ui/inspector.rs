@@ -0,0 +1,162 @@
+use eframe::egui;
+use crate::global::formats::mamodel::{Model, ModelPart};
+use crate::features::animation::logic::inspector::{self, PartInspector};
+use crate::features::animation::logic::transform::WorldTransform;
+
+const PANEL_WIDTH: f32 = 290.0;
+const PANEL_MARGIN: f32 = 8.0;
+const INDENT: f32 = 12.0;
+
+const HIDDEN_COLOR: egui::Color32 = egui::Color32::from_gray(110);
+const SOLO_COLOR: egui::Color32 = egui::Color32::from_rgb(230, 180, 60);
+
+// Returns true while the pointer is over the panel so the viewport doesn't pan underneath
+pub fn show(
+    ui: &mut egui::Ui,
+    viewport: egui::Rect,
+    state: &mut PartInspector,
+    model: &Model,
+    animated_parts: &[ModelPart],
+    world_parts: &[WorldTransform],
+) -> bool {
+    if !state.is_open { return false; }
+    state.hovered = None;
+
+    let panel_rect = egui::Rect::from_min_max(
+        egui::pos2(viewport.right() - PANEL_WIDTH - PANEL_MARGIN, viewport.top() + PANEL_MARGIN),
+        viewport.right_bottom() - egui::vec2(PANEL_MARGIN, PANEL_MARGIN),
+    );
+
+    let response = ui.put(panel_rect, |ui: &mut egui::Ui| {
+        egui::Frame::window(ui.style())
+            .fill(egui::Color32::from_black_alpha(200))
+            .stroke(egui::Stroke::new(1.0, egui::Color32::from_gray(60)))
+            .rounding(8.0)
+            .show(ui, |ui| {
+                ui.set_min_size(ui.available_size());
+                ui.horizontal(|ui| {
+                    ui.strong("Parts");
+                    ui.label(egui::RichText::new(format!("{}", model.parts.len())).weak());
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.small_button("Reset").on_hover_text("Show every part again").clicked() {
+                            state.hidden.clear();
+                            state.solo.clear();
+                        }
+                    });
+                });
+                ui.separator();
+
+                let tree_height = (ui.available_height() * 0.55).max(80.0);
+                egui::ScrollArea::vertical()
+                    .id_salt("part_inspector_tree")
+                    .max_height(tree_height)
+                    .auto_shrink([false, false])
+                    .show(ui, |ui| render_tree(ui, state, model));
+
+                ui.separator();
+
+                egui::ScrollArea::vertical()
+                    .id_salt("part_inspector_values")
+                    .auto_shrink([false, false])
+                    .show(ui, |ui| {
+                        let Some(part_index) = state.selected.or(state.hovered) else {
+                            ui.label(egui::RichText::new("Select a part to see its values").weak());
+                            return;
+                        };
+                        render_values(ui, model, part_index, animated_parts, world_parts);
+                    });
+            })
+            .response
+    });
+
+    ui.ctx().pointer_latest_pos().is_some_and(|pointer| response.rect.contains(pointer))
+}
+
+fn render_tree(ui: &mut egui::Ui, state: &mut PartInspector, model: &Model) {
+    ui.spacing_mut().item_spacing.y = 2.0;
+
+    for row in inspector::tree_rows(model, &state.collapsed) {
+        let part_index = row.part_index;
+        let part = &model.parts[part_index];
+
+        let row_response = ui.horizontal(|ui| {
+            ui.add_space(row.depth as f32 * INDENT);
+
+            if row.has_children {
+                let is_collapsed = state.collapsed.contains(&part_index);
+                let arrow = if is_collapsed { "▶" } else { "▼" };
+                if ui.add(egui::Button::new(egui::RichText::new(arrow).size(10.0)).frame(false)).clicked() {
+                    if is_collapsed { state.collapsed.remove(&part_index); } else { state.collapsed.insert(part_index); }
+                }
+            } else {
+                ui.add_space(14.0);
+            }
+
+            let label = if part.name.is_empty() { format!("#{}", part_index) } else { format!("#{} {}", part_index, part.name) };
+            let text_color = if state.is_visible(part_index) { egui::Color32::WHITE } else { HIDDEN_COLOR };
+            let is_selected = state.selected == Some(part_index);
+            if ui.selectable_label(is_selected, egui::RichText::new(label).color(text_color)).clicked() {
+                state.selected = if is_selected { None } else { Some(part_index) };
+            }
+
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                let is_solo = state.solo.contains(&part_index);
+                let solo_text = egui::RichText::new("S").color(if is_solo { SOLO_COLOR } else { HIDDEN_COLOR });
+                if ui.add(egui::Button::new(solo_text).frame(false)).on_hover_text("Solo").clicked() {
+                    if is_solo { state.solo.remove(&part_index); } else { state.solo.insert(part_index); }
+                }
+
+                let is_hidden = state.hidden.contains(&part_index);
+                let eye_text = egui::RichText::new("👁").color(if is_hidden { HIDDEN_COLOR } else { egui::Color32::WHITE });
+                if ui.add(egui::Button::new(eye_text).frame(false)).on_hover_text("Hide").clicked() {
+                    if is_hidden { state.hidden.remove(&part_index); } else { state.hidden.insert(part_index); }
+                }
+            });
+        }).response;
+
+        if row_response.hovered() {
+            state.hovered = Some(part_index);
+        }
+    }
+}
+
+fn value_row(ui: &mut egui::Ui, label: &str, value: String) {
+    ui.label(egui::RichText::new(label).strong());
+    ui.label(value);
+    ui.end_row();
+}
+
+fn render_values(ui: &mut egui::Ui, model: &Model, part_index: usize, animated_parts: &[ModelPart], world_parts: &[WorldTransform]) {
+    let Some(part) = animated_parts.get(part_index) else { return; };
+    let scale_unit = if model.scale_unit == 0.0 { 1000.0 } else { model.scale_unit };
+    let angle_unit = if model.angle_unit == 0.0 { 1000.0 } else { model.angle_unit };
+    let alpha_unit = if model.alpha_unit == 0.0 { 1000.0 } else { model.alpha_unit };
+
+    ui.label(egui::RichText::new(format!("Part #{} (Animated)", part_index)).strong().color(SOLO_COLOR));
+    egui::Grid::new("part_inspector_local").num_columns(2).spacing([12.0, 2.0]).show(ui, |ui| {
+        value_row(ui, "Parent", part.parent_id.to_string());
+        value_row(ui, "Sprite", part.sprite_index.to_string());
+        value_row(ui, "Layer", part.drawing_layer.to_string());
+        value_row(ui, "Position", format!("{:.1}, {:.1}", part.position_x, part.position_y));
+        value_row(ui, "Pivot", format!("{:.1}, {:.1}", part.pivot_x, part.pivot_y));
+        value_row(ui, "Scale", format!("{:.3}, {:.3}", part.scale_x / scale_unit, part.scale_y / scale_unit));
+        value_row(ui, "Rotation", format!("{:.2}°", part.rotation * 360.0 / angle_unit));
+        value_row(ui, "Opacity", format!("{:.3}", part.alpha / alpha_unit));
+        value_row(ui, "Flip", format!("{}, {}", part.flip_x, part.flip_y));
+        value_row(ui, "Glow", part.glow_mode.to_string());
+    });
+
+    ui.add_space(6.0);
+    let Some(world) = world_parts.iter().find(|world| world.part_index == part_index) else { return; };
+    let (position, scale, rotation) = inspector::decompose(world);
+
+    ui.label(egui::RichText::new("World Transform").strong().color(SOLO_COLOR));
+    egui::Grid::new("part_inspector_world").num_columns(2).spacing([12.0, 2.0]).show(ui, |ui| {
+        value_row(ui, "Position", format!("{:.1}, {:.1}", position.x, position.y));
+        value_row(ui, "Scale", format!("{:.3}, {:.3}", scale.x, scale.y));
+        value_row(ui, "Rotation", format!("{:.2}°", rotation));
+        value_row(ui, "Opacity", format!("{:.3}", world.opacity));
+        value_row(ui, "Z Order", world.z_order.to_string());
+        value_row(ui, "Drawn", if world.hidden { "No".to_string() } else { "Yes".to_string() });
+    });
+}
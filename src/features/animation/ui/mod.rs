@@ -1,3 +1,7 @@
 pub mod viewer;
 pub mod controls;
-pub mod export;
\ No newline at end of file
+pub mod export;
+pub mod inspector;
+pub mod editor;
+pub mod scene;
+pub mod playlist;
\ No newline at end of file
@@ -0,0 +1,172 @@
+use std::path::PathBuf;
+use eframe::egui;
+use crate::features::animation::logic::playlist::{Playlist, PlaylistEditor, PlaylistEntry, Transition};
+use crate::features::animation::logic::scene;
+
+enum EntryAction {
+    MoveUp(usize),
+    MoveDown(usize),
+    Remove(usize),
+}
+
+// Presets are the saved playlists for the unit type the viewer is showing
+pub fn show(ctx: &egui::Context, editor: &mut PlaylistEditor, presets: &mut Vec<Playlist>, available_anims: &[(usize, PathBuf)]) {
+    if !editor.is_open { return; }
+
+    let mut is_open = editor.is_open;
+    egui::Window::new("Playlist")
+        .open(&mut is_open)
+        .default_size([560.0, 360.0])
+        .show(ctx, |ui| {
+            render_presets(ui, editor, presets);
+            ui.separator();
+
+            let Some(playlist) = editor.selected.and_then(|index| presets.get_mut(index)) else {
+                ui.label(egui::RichText::new("Create a playlist to get started").weak());
+                return;
+            };
+
+            let length = editor.player.length(playlist);
+            ui.horizontal(|ui| {
+                ui.label("Name");
+                ui.add(egui::TextEdit::singleline(&mut playlist.name).desired_width(160.0));
+                ui.checkbox(&mut editor.is_previewing, "Preview in Viewer");
+                ui.label(egui::RichText::new(format!("{}f", length)).weak());
+            });
+
+            if length > 0 {
+                let last_frame = (length - 1) as f32;
+                editor.time = editor.time.min(last_frame);
+                ui.add(egui::Slider::new(&mut editor.time, 0.0..=last_frame).step_by(1.0).text("Frame"));
+            }
+            ui.separator();
+
+            egui::ScrollArea::vertical().auto_shrink([false, true]).show(ui, |ui| {
+                render_entries(ui, playlist, available_anims);
+            });
+
+            if ui.button("➕ Add Entry").clicked() {
+                let anim_index = available_anims.first().map_or(PlaylistEntry::default().anim_index, |(index, _)| *index);
+                playlist.entries.push(PlaylistEntry { anim_index, ..Default::default() });
+            }
+        });
+    editor.is_open = is_open;
+}
+
+fn render_presets(ui: &mut egui::Ui, editor: &mut PlaylistEditor, presets: &mut Vec<Playlist>) {
+    editor.selected = editor.selected.filter(|index| *index < presets.len());
+
+    ui.horizontal(|ui| {
+        let selected_name = editor.selected.map_or("None".to_string(), |index| preset_name(&presets[index], index));
+        egui::ComboBox::from_id_salt("playlist_preset")
+            .selected_text(selected_name)
+            .width(180.0)
+            .show_ui(ui, |ui| {
+                ui.selectable_value(&mut editor.selected, None, "None");
+                for (index, playlist) in presets.iter().enumerate() {
+                    ui.selectable_value(&mut editor.selected, Some(index), preset_name(playlist, index));
+                }
+            });
+
+        if ui.button("New").clicked() {
+            presets.push(Playlist { name: format!("Playlist {}", presets.len() + 1), entries: Vec::new() });
+            editor.selected = Some(presets.len() - 1);
+            editor.time = 0.0;
+        }
+
+        if let Some(index) = editor.selected {
+            if ui.button("Duplicate").clicked() {
+                let mut copy = presets[index].clone();
+                copy.name = format!("{} Copy", copy.name);
+                presets.push(copy);
+                editor.selected = Some(presets.len() - 1);
+            }
+            if ui.button("Delete").clicked() {
+                presets.remove(index);
+                editor.selected = None;
+                editor.is_previewing = false;
+            }
+        }
+    });
+}
+
+fn preset_name(playlist: &Playlist, index: usize) -> String {
+    if playlist.name.trim().is_empty() { format!("Playlist {}", index + 1) } else { playlist.name.clone() }
+}
+
+fn render_entries(ui: &mut egui::Ui, playlist: &mut Playlist, available_anims: &[(usize, PathBuf)]) {
+    let mut action = None;
+    let entry_count = playlist.entries.len();
+
+    egui::Grid::new("playlist_entries").num_columns(7).spacing([8.0, 4.0]).striped(true).show(ui, |ui| {
+        for header in ["Anim", "Start", "End", "Repeats", "Speed", "Transition", ""] {
+            ui.strong(header);
+        }
+        ui.end_row();
+
+        for (entry_index, entry) in playlist.entries.iter_mut().enumerate() {
+            egui::ComboBox::from_id_salt(("playlist_anim", entry_index))
+                .selected_text(scene::anim_label(entry.anim_index))
+                .width(90.0)
+                .show_ui(ui, |ui| {
+                    for (index, _) in available_anims {
+                        ui.selectable_value(&mut entry.anim_index, *index, scene::anim_label(*index));
+                    }
+                });
+
+            ui.add(egui::DragValue::new(&mut entry.start).range(0..=i32::MAX).suffix("f"));
+
+            ui.horizontal(|ui| {
+                let mut plays_to_end = entry.end.is_none();
+                if ui.checkbox(&mut plays_to_end, "Last").on_hover_text("Play to the animation's last frame").changed() {
+                    entry.end = if plays_to_end { None } else { Some(entry.start) };
+                }
+                if let Some(end) = &mut entry.end {
+                    ui.add(egui::DragValue::new(end).range(0..=i32::MAX).suffix("f"));
+                }
+            });
+
+            ui.add(egui::DragValue::new(&mut entry.repeats).range(1..=999).suffix("×"));
+
+            ui.horizontal(|ui| {
+                let mut has_speed = entry.speed.is_some();
+                if ui.checkbox(&mut has_speed, "").on_hover_text("Play this entry at its own speed").changed() {
+                    entry.speed = has_speed.then_some(1.0);
+                }
+                if let Some(speed) = &mut entry.speed {
+                    ui.add(egui::DragValue::new(speed).range(0.1..=10.0).speed(0.05).suffix("×"));
+                }
+            });
+
+            ui.horizontal(|ui| {
+                let is_first = entry_index == 0;
+                ui.add_enabled_ui(!is_first, |ui| {
+                    egui::ComboBox::from_id_salt(("playlist_transition", entry_index))
+                        .selected_text(match entry.transition { Transition::Cut => "Cut", Transition::Crossfade => "Crossfade" })
+                        .width(80.0)
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(&mut entry.transition, Transition::Cut, "Cut");
+                            ui.selectable_value(&mut entry.transition, Transition::Crossfade, "Crossfade");
+                        });
+                }).response.on_disabled_hover_text("The first entry has nothing to blend from");
+                if entry.transition == Transition::Crossfade && !is_first {
+                    ui.add(egui::DragValue::new(&mut entry.crossfade_frames).range(1..=120).suffix("f"));
+                }
+            });
+
+            ui.horizontal(|ui| {
+                if ui.add_enabled(entry_index > 0, egui::Button::new("▲").small()).clicked() { action = Some(EntryAction::MoveUp(entry_index)); }
+                if ui.add_enabled(entry_index + 1 < entry_count, egui::Button::new("▼").small()).clicked() { action = Some(EntryAction::MoveDown(entry_index)); }
+                if ui.small_button("✖").clicked() { action = Some(EntryAction::Remove(entry_index)); }
+            });
+            ui.end_row();
+        }
+    });
+
+    match action {
+        Some(EntryAction::MoveUp(index)) => playlist.entries.swap(index, index - 1),
+        Some(EntryAction::MoveDown(index)) => playlist.entries.swap(index, index + 1),
+        Some(EntryAction::Remove(index)) => { playlist.entries.remove(index); }
+        None => {}
+    }
+}
@@ -0,0 +1,107 @@
+use eframe::egui;
+use crate::features::animation::logic::scene::{self, Scene};
+
+enum ActorAction {
+    MoveUp(usize),
+    MoveDown(usize),
+    Remove(usize),
+}
+
+// Returns true when the unit open in the viewer should be added to the scene
+pub fn show(ctx: &egui::Context, scene: &mut Scene, can_add: bool) -> bool {
+    if !scene.is_open { return false; }
+
+    let mut is_open = scene.is_open;
+    let mut add_requested = false;
+    let mut action = None;
+
+    egui::Window::new("Scene Composer")
+        .open(&mut is_open)
+        .default_size([360.0, 420.0])
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                if ui.add_enabled(can_add, egui::Button::new("➕ Add Current Unit")).on_disabled_hover_text("Wait for the unit to finish loading").clicked() {
+                    add_requested = true;
+                }
+                ui.checkbox(&mut scene.is_visible, "Show in Viewer");
+            });
+
+            ui.horizontal(|ui| {
+                ui.label("Length");
+                ui.add(egui::DragValue::new(&mut scene.length).range(1..=9999).suffix("f"));
+                if ui.button("Fit").on_hover_text("Long enough for every unit to play through once").clicked() {
+                    scene.fit_length();
+                }
+            });
+
+            let last_frame = (scene.length - 1).max(0) as f32;
+            scene.frame = scene.frame.min(last_frame);
+            ui.add(egui::Slider::new(&mut scene.frame, 0.0..=last_frame).step_by(1.0).text("Frame"));
+            ui.label(egui::RichText::new("Set the camera in the viewer, then export in Scene mode").weak());
+            ui.separator();
+
+            if scene.actors.is_empty() {
+                ui.label(egui::RichText::new("Open a cat or enemy and add it here").weak());
+                return;
+            }
+
+            egui::ScrollArea::vertical().auto_shrink([false, false]).show(ui, |ui| {
+                let actor_count = scene.actors.len();
+                for (actor_index, actor) in scene.actors.iter_mut().enumerate() {
+                    egui::Frame::group(ui.style()).show(ui, |ui| {
+                        ui.set_width(ui.available_width());
+                        ui.horizontal(|ui| {
+                            ui.checkbox(&mut actor.is_visible, "");
+                            ui.strong(&actor.label);
+                            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                if ui.small_button("✖").on_hover_text("Remove").clicked() { action = Some(ActorAction::Remove(actor_index)); }
+                                if ui.add_enabled(actor_index + 1 < actor_count, egui::Button::new("▼").small()).on_hover_text("Move in front").clicked() {
+                                    action = Some(ActorAction::MoveDown(actor_index));
+                                }
+                                if ui.add_enabled(actor_index > 0, egui::Button::new("▲").small()).on_hover_text("Move behind").clicked() {
+                                    action = Some(ActorAction::MoveUp(actor_index));
+                                }
+                            });
+                        });
+
+                        egui::Grid::new(("scene_actor", actor_index)).num_columns(4).spacing([8.0, 4.0]).show(ui, |ui| {
+                            ui.label("Anim");
+                            let mut anim_index = actor.anim_index;
+                            egui::ComboBox::from_id_salt(("scene_actor_anim", actor_index))
+                                .selected_text(scene::anim_label(anim_index))
+                                .show_ui(ui, |ui| {
+                                    for (index, _) in &actor.anims {
+                                        ui.selectable_value(&mut anim_index, *index, scene::anim_label(*index));
+                                    }
+                                });
+                            if anim_index != actor.anim_index { actor.set_anim(anim_index); }
+
+                            ui.label("Start");
+                            ui.add(egui::DragValue::new(&mut actor.start_offset).suffix("f")).on_hover_text("Scene frame this unit starts playing on");
+                            ui.end_row();
+
+                            ui.label("X");
+                            ui.add(egui::DragValue::new(&mut actor.position.x).speed(1.0));
+                            ui.label("Y");
+                            ui.add(egui::DragValue::new(&mut actor.position.y).speed(1.0));
+                            ui.end_row();
+
+                            ui.label("Flip");
+                            ui.checkbox(&mut actor.flip, "");
+                            ui.end_row();
+                        });
+                    });
+                }
+            });
+        });
+
+    // Actors further down the list draw on top
+    match action {
+        Some(ActorAction::MoveUp(index)) => scene.actors.swap(index, index - 1),
+        Some(ActorAction::MoveDown(index)) => scene.actors.swap(index, index + 1),
+        Some(ActorAction::Remove(index)) => { scene.actors.remove(index); }
+        None => {}
+    }
+    scene.is_open = is_open;
+    add_requested
+}
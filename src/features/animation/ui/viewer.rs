@@ -4,12 +4,16 @@ use std::sync::{Arc, Mutex};
 use crate::global::formats::imgcut::SpriteSheet;
 use crate::global::formats::mamodel::Model;
 use crate::global::formats::maanim::Animation;
-use crate::features::animation::logic::{animator, smooth, canvas, transform, controls, bounds}; 
+use crate::features::animation::logic::{animator, smooth, canvas, transform, controls, bounds, overlay, inspector, editor, onion, scene, playlist, camera}; 
 use crate::features::animation::ui::controls::{self as anim_controls, IDX_NONE, IDX_MODEL, IDX_SPIRIT, IDX_WALK, IDX_IDLE, IDX_ATTACK, IDX_KB, IDX_BURROW, IDX_SURFACE};
 use crate::features::animation::export::state::{ExporterState, ExportMode};
 use crate::features::animation::export::encoding::ExportFormat;
 use crate::features::animation::export::process;
 use crate::features::animation::ui::export;
+use crate::features::animation::ui::inspector as inspector_panel;
+use crate::features::animation::ui::editor as editor_window;
+use crate::features::animation::ui::scene as scene_panel;
+use crate::features::animation::ui::playlist as playlist_panel;
 use crate::features::settings::logic::state::Settings;
 
 pub struct AnimViewer {
@@ -17,6 +21,7 @@ pub struct AnimViewer {
     pub target_zoom_level: f32,
     pub pan_offset: egui::Vec2,
     pub current_anim: Option<Animation>,
+    pub current_anim_path: Option<PathBuf>,
     pub current_frame: f32,
     pub is_playing: bool,
     pub playback_speed: f32,
@@ -41,12 +46,18 @@ pub struct AnimViewer {
     pub is_expanded: bool,          
     pub texture_version: u64,
     pub is_pointer_over_controls: bool,
+    pub is_pointer_over_inspector: bool,
     pub is_viewport_dragging: bool,
     pub is_selecting_export_region: bool,
     pub export_selection_start: Option<egui::Pos2>,
     pub export_state: ExporterState,
     pub has_scanned_showcase: bool,
     pub was_export_popup_open: bool, 
+    pub combat_profile: Option<overlay::CombatProfile>,
+    pub inspector: inspector::PartInspector,
+    pub curve_editor: editor::CurveEditor,
+    pub unit_kind: playlist::UnitKind,
+    pub playlist_editor: playlist::PlaylistEditor,
 }
 
 impl Default for AnimViewer {
@@ -56,6 +67,7 @@ impl Default for AnimViewer {
             target_zoom_level: 1.0,
             pan_offset: egui::vec2(0.0, 0.0),
             current_anim: None,
+            current_anim_path: None,
             current_frame: 0.0,
             is_playing: true,
             playback_speed: 1.0,
@@ -80,12 +92,18 @@ impl Default for AnimViewer {
             is_expanded: false,
             texture_version: 0,
             is_pointer_over_controls: false,
+            is_pointer_over_inspector: false,
             is_viewport_dragging: false,
             is_selecting_export_region: false,
             export_selection_start: None,
             export_state: ExporterState::default(),
             has_scanned_showcase: false,
             was_export_popup_open: false,
+            combat_profile: None,
+            inspector: inspector::PartInspector::default(),
+            curve_editor: editor::CurveEditor::default(),
+            unit_kind: playlist::UnitKind::default(),
+            playlist_editor: playlist::PlaylistEditor::default(),
         }
     }
 }
@@ -147,7 +165,43 @@ impl AnimViewer {
         self.export_state.name_prefix = format!("{}.{}", clean_id, type_string);
     }
 
+    // Hit frames only line up with the unit's own attack animation
+    pub fn attack_profile(&self) -> Option<&overlay::CombatProfile> {
+        if self.loaded_anim_index != IDX_ATTACK { return None; }
+        self.combat_profile.as_ref().filter(|profile| !profile.hit_frames.is_empty())
+    }
+
+    fn sync_hit_track(&mut self) {
+        let hit_frames = self.attack_profile().map(|profile| profile.hit_frames.clone()).unwrap_or_default();
+        let attack_frames = self.attack_profile().map_or(0, |profile| profile.attack_frames);
+        let anim_frames = self.current_anim.as_ref().map_or(0, |animation| animation.max_frame + 1);
+
+        self.export_state.hit_frames = hit_frames;
+        self.export_state.hit_track_length = attack_frames.max(anim_frames);
+    }
+
+    fn show_scene_composer(&mut self, ctx: &egui::Context, available_anims: &[(usize, PathBuf)]) {
+        let Ok(mut scene) = scene::SCENE.lock() else { return; };
+        let can_add = self.held_sheet.as_ref().is_some_and(|sheet| sheet.image_data.is_some() && !sheet.is_loading_active);
+        if !scene_panel::show(ctx, &mut scene, can_add) { return; }
+        let (Some(model), Some(sheet)) = (&self.held_model, &self.held_sheet) else { return; };
+
+        // Spirits come from a separate pack, they only bring the one animation
+        let anims = if self.loaded_anim_index == IDX_SPIRIT {
+            self.current_anim_path.iter().map(|path| (IDX_SPIRIT, path.clone())).collect()
+        } else {
+            available_anims.to_vec()
+        };
+        let label = self.export_state.name_prefix.split('.').next().filter(|id| !id.is_empty()).unwrap_or("Unit").to_string();
+
+        scene.actors.push(scene::SceneActor::new(label, model.clone(), sheet.clone(), anims, self.loaded_anim_index));
+        scene.is_visible = true;
+        if scene.actors.len() == 1 { scene.fit_length(); }
+    }
+
     pub fn load_anim(&mut self, path: &Path, settings: &Settings) {
+        self.curve_editor.reset();
+        self.current_anim_path = Some(path.to_path_buf());
         if let Some(anim) = Animation::load(path) {
             self.current_frame = 0.0;
             self.loop_range = (None, None);
@@ -412,6 +466,7 @@ impl AnimViewer {
         if self.loaded_id != self.last_loaded_id {
             self.last_loaded_id = self.loaded_id.clone();
             self.pending_initial_center = true;
+            self.inspector.reset();
 
             let mut preserved_loop_msg: Option<String> = None;
             let mut preserved_export_msg: Option<String> = None;
@@ -521,6 +576,38 @@ impl AnimViewer {
             }
         }
 
+        // The scene runs on its own clock since every actor plays a different animation
+        if let Ok(mut scene) = scene::SCENE.lock() {
+            self.export_state.scene_length = if scene.actors.is_empty() { 0 } else { scene.length };
+            if self.is_playing && scene.is_showing() && !self.export_state.is_processing {
+                scene.advance(delta_time * 30.0 * self.playback_speed);
+                ui.ctx().request_repaint();
+            }
+        }
+        if self.export_state.export_mode == ExportMode::Scene && self.export_state.scene_length == 0 && !self.export_state.is_processing {
+            self.export_state.export_mode = ExportMode::Manual;
+        }
+
+        // Exports play whichever playlist is picked in the editor
+        self.playlist_editor.player.sync(&self.loaded_id, available_anims);
+        let active_playlist = self.playlist_editor.selected
+            .and_then(|index| settings.animation.playlists.for_kind(self.unit_kind).get(index))
+            .filter(|active| !active.entries.is_empty())
+            .cloned();
+        self.export_state.playlist_length = active_playlist.as_ref().map_or(0, |active| self.playlist_editor.player.length(active));
+        self.export_state.playlist = active_playlist;
+        if self.export_state.export_mode == ExportMode::Playlist && self.export_state.playlist_length == 0 && !self.export_state.is_processing {
+            self.export_state.export_mode = ExportMode::Manual;
+        }
+
+        // Spirits come from another pack, the unit's playlist doesn't fit them
+        let is_previewing_playlist = self.playlist_editor.is_previewing && self.export_state.playlist_length > 0 && self.loaded_anim_index != IDX_SPIRIT;
+        if is_previewing_playlist && self.is_playing && !self.export_state.is_processing {
+            let length = self.export_state.playlist_length as f32;
+            self.playlist_editor.time = (self.playlist_editor.time + delta_time * 30.0 * self.playback_speed).rem_euclid(length);
+            ui.ctx().request_repaint();
+        }
+
         let (rect_alloc, viewport_response) = ui.allocate_exact_size(rect.size(), egui::Sense::drag());
         let (hover_position, right_mouse_down, left_mouse_down) = ui.input(|input_state| (input_state.pointer.hover_pos(), input_state.pointer.secondary_down(), input_state.pointer.primary_down()));
         
@@ -599,7 +686,11 @@ impl AnimViewer {
                  if settings.animation.auto_set_camera_region {
                      if let (Some(model_data), Some(sheet_data)) = (&self.held_model, &self.held_sheet) {
                          
-                        let master_bounds = if self.export_state.export_mode == ExportMode::Showcase {
+                        let master_bounds = if self.export_state.export_mode == ExportMode::Scene {
+                            scene::SCENE.lock().ok().and_then(|scene| scene.bounds(settings.animation.use_tight_bounds))
+                        } else if self.export_state.export_mode == ExportMode::Playlist && let Some(active_playlist) = &self.export_state.playlist {
+                            playlist::calculate_bounds(model_data, sheet_data, available_anims, active_playlist, settings.animation.use_tight_bounds)
+                        } else if self.export_state.export_mode == ExportMode::Showcase {
                             bounds::calculate_showcase_bounds(
                                 model_data, sheet_data, available_anims, 
                                 self.export_state.showcase_walk_len, self.export_state.showcase_idle_len, 
@@ -693,6 +784,20 @@ impl AnimViewer {
              self.has_scanned_showcase = true;
         }
 
+        // Spirits are separate units, the summoner's ranges don't apply to them
+        let mut overlay_shapes = match &self.combat_profile {
+            Some(profile) if self.loaded_anim_index != IDX_SPIRIT => overlay::build_shapes(profile, &settings.animation.overlays),
+            _ => Vec::new(),
+        };
+        self.export_state.overlay_shapes = overlay_shapes.clone();
+        self.export_state.onion = settings.animation.onion;
+
+        if self.is_playing && settings.animation.overlays.hit_flash
+            && let Some(flash) = self.attack_profile().and_then(|profile| overlay::hit_flash(profile, self.current_frame))
+        {
+            overlay_shapes.push(flash);
+        }
+
         let mut showcase_render_time = 0.0;
 
         if self.export_state.is_processing && self.export_state.export_mode == ExportMode::Showcase {
@@ -726,6 +831,9 @@ impl AnimViewer {
             }
         }
 
+        // Showcases switch animations mid export, so the markers follow along
+        self.sync_hit_track();
+
         if let (Some(model_data), Some(sheet_data)) = (&self.held_model, &self.held_sheet) {
             
             if self.export_state.is_processing {
@@ -739,19 +847,72 @@ impl AnimViewer {
                      (start + (self.export_state.current_progress * step)) as f32
                 };
 
-                process::process_frame(ui, rect_alloc, &mut self.export_state, model_data, self.current_anim.as_ref(), sheet_data, self.renderer.clone(), time_to_use);
+                if self.export_state.export_mode == ExportMode::Scene {
+                    if let Ok(scene) = scene::SCENE.lock() {
+                        process::process_scene_frame(ui, rect_alloc, &mut self.export_state, &scene);
+                    }
+                } else if self.export_state.export_mode == ExportMode::Playlist {
+                    let playlist_frame = (self.export_state.frame_start + self.export_state.current_progress) as f32;
+                    let parts = self.export_state.playlist.as_ref()
+                        .and_then(|active| self.playlist_editor.player.sample(model_data, active, playlist_frame, self.export_state.interpolation))
+                        .unwrap_or_else(|| model_data.parts.clone());
+                    process::process_playlist_frame(ui, rect_alloc, &mut self.export_state, model_data, &parts, sheet_data, self.renderer.clone());
+                } else {
+                    process::process_frame(ui, rect_alloc, &mut self.export_state, model_data, self.current_anim.as_ref(), sheet_data, self.renderer.clone(), time_to_use);
+                }
                 
                 ui.ctx().request_repaint();
             }
 
-            let parts_to_draw = if let Some(animation) = &self.current_anim {
+            let playlist_parts = self.export_state.playlist.as_ref()
+                .filter(|_| is_previewing_playlist)
+                .and_then(|active| self.playlist_editor.player.sample(model_data, active, self.playlist_editor.time, interpolation));
+            let animated_parts = if let Some(parts) = playlist_parts {
+                parts
+            } else if let Some(animation) = &self.current_anim {
                 let current_animation_frame = self.current_frame;
-                let animated_parts = if interpolation { smooth::animate(model_data, animation, current_animation_frame) } else { animator::animate(model_data, animation, (current_animation_frame + 0.01).floor()) };
-                transform::solve_hierarchy(&animated_parts, model_data)
-            } else { transform::solve_hierarchy(&model_data.parts, model_data) };
+                if interpolation { smooth::animate(model_data, animation, current_animation_frame) } else { animator::animate(model_data, animation, (current_animation_frame + 0.01).floor()) }
+            } else { model_data.parts.clone() };
+            let mut parts_to_draw = transform::solve_hierarchy(&animated_parts, model_data);
+            self.inspector.apply(&mut parts_to_draw);
+            let inspector_parts = self.inspector.is_open.then(|| parts_to_draw.clone());
+
+            // Exports bring their own ghosts, the viewer only shows them while idle
+            let parts_to_draw = match &self.current_anim {
+                Some(animation) if !self.export_state.is_processing && !is_previewing_playlist && settings.animation.onion.is_active() => {
+                    let mut ghosts = onion::build_ghosts(model_data, animation, self.current_frame, &settings.animation.onion, interpolation);
+                    ghosts.extend(parts_to_draw);
+                    ghosts
+                }
+                _ => parts_to_draw,
+            };
 
             let sheet_arc = Arc::new(SpriteSheet { texture_handle: sheet_data.texture_handle.clone(), image_data: sheet_data.image_data.clone(), cuts_map: sheet_data.cuts_map.clone(), is_loading_active: sheet_data.is_loading_active, data_receiver: None, sheet_name: sheet_data.sheet_name.clone() });
-            canvas::paint(ui, rect_alloc, self.renderer.clone(), sheet_arc, parts_to_draw, self.pan_offset, self.zoom_level, allow_update);
+            let to_screen = |world_pos: egui::Pos2| rect_alloc.center() + (world_pos.to_vec2() + self.pan_offset) * self.zoom_level;
+            let showing_scene = scene::SCENE.lock().ok().filter(|scene| scene.is_showing());
+            if let Some(scene) = &showing_scene {
+                for actor in scene.actors.iter().filter(|actor| actor.is_visible) {
+                    canvas::paint(ui, rect_alloc, actor.renderer.clone(), actor.sheet.clone(), actor.world_parts(scene.frame, interpolation), self.pan_offset, self.zoom_level, true);
+                }
+            } else {
+                canvas::paint(ui, rect_alloc, self.renderer.clone(), sheet_arc, parts_to_draw, self.pan_offset, self.zoom_level, allow_update);
+                overlay::paint(&ui.painter().with_clip_rect(rect_alloc), &overlay_shapes, to_screen);
+            }
+
+            if let Some(world_parts) = &inspector_parts && showing_scene.is_none() {
+                let highlights = [(self.inspector.selected, egui::Color32::YELLOW), (self.inspector.hovered, egui::Color32::WHITE)];
+                for (part_index, color) in highlights {
+                    let Some(part_index) = part_index else { continue; };
+                    let Some(part) = world_parts.iter().find(|part| part.part_index == part_index) else { continue; };
+                    let Some(corners) = inspector::part_corners(part, sheet_data) else { continue; };
+                    let points = corners.map(to_screen).to_vec();
+                    ui.painter().with_clip_rect(rect_alloc).add(egui::Shape::closed_line(points, egui::Stroke::new(1.5, color)));
+                }
+
+                self.is_pointer_over_inspector = inspector_panel::show(ui, rect_alloc, &mut self.inspector, model_data, &animated_parts, world_parts);
+            } else {
+                self.is_pointer_over_inspector = false;
+            }
             
             if debug_show_info {
                 let cross_center = rect_alloc.center() + self.pan_offset * self.zoom_level;
@@ -768,6 +929,18 @@ impl AnimViewer {
                      let min = to_screen(self.export_state.region_x, self.export_state.region_y);
                      let max = to_screen(self.export_state.region_x + self.export_state.region_w, self.export_state.region_y + self.export_state.region_h);
                      ui.painter().with_clip_rect(rect_alloc).rect_stroke(egui::Rect::from_min_max(min, max), 0.0, egui::Stroke::new(1.0, egui::Color32::YELLOW));
+
+                     // Where the camera keys put the shot on this frame, follow only resolves while exporting
+                     let is_frame_range = matches!(self.export_state.export_mode, ExportMode::Manual | ExportMode::Loop);
+                     if is_frame_range && !self.export_state.camera.keys.is_empty() && !self.export_state.camera.is_following() {
+                         let size = egui::vec2(self.export_state.region_w, self.export_state.region_h);
+                         let base = camera::CameraView::from_region(egui::vec2(self.export_state.region_x, self.export_state.region_y), size, self.export_state.zoom);
+                         let view = self.export_state.camera.view(base, self.current_frame - self.export_state.frame_start as f32);
+                         let view_min = view.region_min(size);
+                         let view_max = view_min + size / view.zoom;
+                         let keyed_rect = egui::Rect::from_min_max(to_screen(view_min.x, view_min.y), to_screen(view_max.x, view_max.y));
+                         ui.painter().with_clip_rect(rect_alloc).rect_stroke(keyed_rect, 0.0, egui::Stroke::new(1.0, egui::Color32::from_rgb(80, 200, 255)));
+                     }
                  }
             }
         } else { ui.painter().rect_filled(rect_alloc, 0.0, egui::Color32::from_rgb(20, 20, 20)); }
@@ -793,8 +966,78 @@ impl AnimViewer {
             response
         });
 
+        let overlay_button_rect = button_rect.translate(egui::vec2(button_size.x + 6.0, 0.0));
+        let overlay_button_response = anim_controls::render_overlay_menu(ui, overlay_button_rect, &mut settings.animation.overlays, self.combat_profile.is_some());
+
+        let inspector_button_rect = overlay_button_rect.translate(egui::vec2(button_size.x + 6.0, 0.0));
+        let inspector_fill = if self.inspector.is_open { egui::Color32::from_rgb(31, 106, 165) } else { egui::Color32::from_gray(60) };
+        let inspector_button_response = ui.put(inspector_button_rect, |ui: &mut egui::Ui| {
+            let button_widget = egui::Button::new(egui::RichText::new("🗂").size(16.0).color(egui::Color32::WHITE))
+                .fill(inspector_fill)
+                .stroke(egui::Stroke::new(1.0, egui::Color32::from_gray(60)))
+                .rounding(4.0);
+            ui.add_enabled(self.held_model.is_some(), button_widget)
+        }).on_hover_text("Part Inspector");
+        if inspector_button_response.clicked() {
+            self.inspector.is_open = !self.inspector.is_open;
+        }
+
+        let editor_button_rect = inspector_button_rect.translate(egui::vec2(button_size.x + 6.0, 0.0));
+        let editor_fill = if self.curve_editor.is_open { egui::Color32::from_rgb(31, 106, 165) } else { egui::Color32::from_gray(60) };
+        let editor_button_response = ui.put(editor_button_rect, |ui: &mut egui::Ui| {
+            let button_widget = egui::Button::new(egui::RichText::new("📈").size(16.0).color(egui::Color32::WHITE))
+                .fill(editor_fill)
+                .stroke(egui::Stroke::new(1.0, egui::Color32::from_gray(60)))
+                .rounding(4.0);
+            ui.add_enabled(self.current_anim.is_some() && !self.export_state.is_processing, button_widget)
+        }).on_hover_text("Curve Editor");
+        if editor_button_response.clicked() {
+            self.curve_editor.is_open = !self.curve_editor.is_open;
+        }
+
+        let onion_button_rect = editor_button_rect.translate(egui::vec2(button_size.x + 6.0, 0.0));
+        let onion_button_response = anim_controls::render_onion_menu(ui, onion_button_rect, &mut settings.animation.onion, self.current_anim.is_some());
+
+        let scene_button_rect = onion_button_rect.translate(egui::vec2(button_size.x + 6.0, 0.0));
+        let is_scene_open = scene::SCENE.lock().is_ok_and(|scene| scene.is_open);
+        let scene_fill = if is_scene_open { egui::Color32::from_rgb(31, 106, 165) } else { egui::Color32::from_gray(60) };
+        let scene_button_response = ui.put(scene_button_rect, |ui: &mut egui::Ui| {
+            let button_widget = egui::Button::new(egui::RichText::new("🎬").size(16.0).color(egui::Color32::WHITE))
+                .fill(scene_fill)
+                .stroke(egui::Stroke::new(1.0, egui::Color32::from_gray(60)))
+                .rounding(4.0);
+            ui.add_enabled(!self.export_state.is_processing, button_widget)
+        }).on_hover_text("Scene Composer");
+        if scene_button_response.clicked() && let Ok(mut scene) = scene::SCENE.lock() {
+            scene.is_open = !scene.is_open;
+        }
+
+        let playlist_button_rect = scene_button_rect.translate(egui::vec2(button_size.x + 6.0, 0.0));
+        let playlist_fill = if self.playlist_editor.is_open { egui::Color32::from_rgb(31, 106, 165) } else { egui::Color32::from_gray(60) };
+        let playlist_button_response = ui.put(playlist_button_rect, |ui: &mut egui::Ui| {
+            let button_widget = egui::Button::new(egui::RichText::new("📋").size(16.0).color(egui::Color32::WHITE))
+                .fill(playlist_fill)
+                .stroke(egui::Stroke::new(1.0, egui::Color32::from_gray(60)))
+                .rounding(4.0);
+            ui.add_enabled(self.held_model.is_some() && !self.export_state.is_processing, button_widget)
+        }).on_hover_text("Playlist");
+        if playlist_button_response.clicked() {
+            self.playlist_editor.is_open = !self.playlist_editor.is_open;
+        }
+
         let controls_hovered = anim_controls::render_controls_overlay(ui, rect_alloc, self, available_anims, base_assets_available, is_loading_new, secondary_id, primary_id, secondary_assets, interpolation, native_fps, settings);
-        self.is_pointer_over_controls = controls_hovered || expand_button_response.hovered();
+        self.is_pointer_over_controls = controls_hovered || expand_button_response.hovered() || overlay_button_response.hovered()
+            || inspector_button_response.hovered() || editor_button_response.hovered() || onion_button_response.hovered() || scene_button_response.hovered()
+            || playlist_button_response.hovered() || self.is_pointer_over_inspector;
+
+        if let Some(animation) = self.current_anim.as_mut() && !self.export_state.is_processing {
+            editor_window::show(ui.ctx(), &mut self.curve_editor, animation, self.held_model.as_ref(), &mut self.current_frame, self.current_anim_path.as_deref());
+        }
+
+        if !self.export_state.is_processing {
+            self.show_scene_composer(ui.ctx(), available_anims);
+            playlist_panel::show(ui.ctx(), &mut self.playlist_editor, settings.animation.playlists.for_kind(self.unit_kind), available_anims);
+        }
 
         export::show_popup(ui, &mut self.export_state, self.held_model.as_ref(), self.current_anim.as_ref(), self.held_sheet.as_ref(), &mut self.is_selecting_export_region, settings, available_anims);
     }
@@ -5,4 +5,5 @@ pub mod unitevolve;
 pub mod skillacquisition;
 pub mod skilllevel;
 pub mod skilldescriptions;
-pub mod unitexplanation;
\ No newline at end of file
+pub mod unitexplanation;
+pub mod nyancombo;
\ No newline at end of file
@@ -0,0 +1,192 @@
+#![allow(dead_code)]
+use std::fs;
+use std::path::Path;
+use std::collections::HashMap;
+use crate::global::utils;
+use crate::features::cat::paths;
+
+const FALLBACK_SIZE_NAMES: [&str; 4] = ["Sm", "M", "L", "XL"];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ComboUnit {
+    pub cat_id: u32,
+    pub form: usize,
+}
+
+impl ComboUnit {
+    // Combos accept the listed form or any later one
+    pub fn is_met_by(&self, cat_id: u32, form: usize) -> bool {
+        self.cat_id == cat_id && form >= self.form
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ComboRow {
+    pub id: usize,
+    pub name: String,
+    pub units: Vec<ComboUnit>,
+    pub effect_id: usize,
+    pub size: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ComboData {
+    pub combos: Vec<ComboRow>,
+    pub effect_names: Vec<String>,
+    pub size_names: Vec<String>,
+    pub params: HashMap<usize, Vec<i32>>,
+}
+
+impl ComboData {
+    pub fn effect_name(&self, effect_id: usize) -> String {
+        self.effect_names.get(effect_id)
+            .filter(|name| !name.is_empty())
+            .cloned()
+            .unwrap_or_else(|| format!("Effect {}", effect_id))
+    }
+
+    pub fn size_name(&self, size: usize) -> String {
+        self.size_names.get(size)
+            .filter(|name| !name.is_empty())
+            .cloned()
+            .or_else(|| FALLBACK_SIZE_NAMES.get(size).map(|name| name.to_string()))
+            .unwrap_or_else(|| size.to_string())
+    }
+
+    pub fn effect_value(&self, combo: &ComboRow) -> Option<i32> {
+        self.params.get(&combo.effect_id)?.get(combo.size).copied()
+    }
+
+    pub fn effect_text(&self, combo: &ComboRow) -> String {
+        let label = format!("{} ({})", self.effect_name(combo.effect_id), self.size_name(combo.size));
+        match self.effect_value(combo) {
+            Some(value) => format!("{} +{}", label, value),
+            None => label,
+        }
+    }
+
+    pub fn active_combos(&self, lineup: &[(u32, usize)]) -> Vec<&ComboRow> {
+        self.combos.iter()
+            .filter(|combo| combo.units.iter().all(|unit| {
+                lineup.iter().any(|&(cat_id, form)| unit.is_met_by(cat_id, form))
+            }))
+            .collect()
+    }
+
+    pub fn combos_for_cat(&self, cat_id: u32) -> Vec<&ComboRow> {
+        self.combos.iter()
+            .filter(|combo| combo.units.iter().any(|unit| unit.cat_id == cat_id))
+            .collect()
+    }
+}
+
+pub fn load(cats_directory: &Path, priority: &[String]) -> ComboData {
+    let names = load_text_column(cats_directory, paths::NYANCOMBO_NAME, priority);
+
+    ComboData {
+        combos: load_combos(cats_directory, priority, &names),
+        effect_names: load_text_column(cats_directory, paths::NYANCOMBO_EFFECT, priority),
+        size_names: load_text_column(cats_directory, paths::NYANCOMBO_SIZE, priority),
+        params: load_params(cats_directory, priority),
+    }
+}
+
+fn load_combos(cats_directory: &Path, priority: &[String], names: &[String]) -> Vec<ComboRow> {
+    let mut combos = Vec::new();
+    let Some(file_path) = crate::global::resolver::get(cats_directory, [paths::NYANCOMBO_DATA], priority).into_iter().next() else {
+        return combos;
+    };
+    let Ok(content) = fs::read_to_string(&file_path) else {
+        return combos;
+    };
+
+    let delimiter = utils::detect_csv_separator(&content);
+
+    for (line_index, line) in content.lines().enumerate() {
+        let clean_line = line.split("//").next().unwrap_or("").trim();
+        if clean_line.is_empty() { continue; }
+
+        let values: Vec<i32> = clean_line.split(delimiter)
+            .map(|part| part.trim().parse::<i32>().unwrap_or(-1))
+            .collect();
+
+        // 0: id, 1: availability, 2..12: (cat id, form) pairs, 12: effect, 13: size
+        if values.len() < 14 { continue; }
+        if values[1] == -1 { continue; }
+
+        let units: Vec<ComboUnit> = (0..5)
+            .map(|slot| (values[2 + slot * 2], values[3 + slot * 2]))
+            .take_while(|&(cat_id, _)| cat_id >= 0)
+            .map(|(cat_id, form)| ComboUnit { cat_id: cat_id as u32, form: form.max(0) as usize })
+            .collect();
+
+        if units.is_empty() { continue; }
+
+        let id = if values[0] >= 0 { values[0] as usize } else { line_index };
+        let name = names.get(id)
+            .filter(|name| !name.is_empty())
+            .cloned()
+            .unwrap_or_else(|| format!("Combo {:03}", id));
+
+        combos.push(ComboRow {
+            id,
+            name,
+            units,
+            effect_id: values[12].max(0) as usize,
+            size: values[13].max(0) as usize,
+        });
+    }
+
+    combos
+}
+
+fn load_params(cats_directory: &Path, priority: &[String]) -> HashMap<usize, Vec<i32>> {
+    let mut params = HashMap::new();
+    let Some(file_path) = crate::global::resolver::get(cats_directory, [paths::NYANCOMBO_PARAM], priority).into_iter().next() else {
+        return params;
+    };
+    let Ok(content) = fs::read_to_string(&file_path) else {
+        return params;
+    };
+
+    let mut effect_id = 0;
+    for line in content.lines() {
+        let clean_line = line.split("//").next().unwrap_or("").trim();
+        if clean_line.is_empty() { continue; }
+
+        let values: Vec<i32> = clean_line.split(['\t', ','])
+            .filter_map(|part| part.trim().parse::<i32>().ok())
+            .collect();
+
+        // Skip header rows without shifting effect ids
+        if values.is_empty() { continue; }
+
+        params.insert(effect_id, values);
+        effect_id += 1;
+    }
+
+    params
+}
+
+fn load_text_column(cats_directory: &Path, filename: &str, priority: &[String]) -> Vec<String> {
+    let mut entries = Vec::new();
+    let Some(file_path) = crate::global::resolver::get(cats_directory, [filename], priority).into_iter().next() else {
+        return entries;
+    };
+    let Ok(content) = fs::read_to_string(&file_path) else {
+        return entries;
+    };
+
+    let delimiter = utils::detect_csv_separator(&content);
+
+    for line in content.lines() {
+        let text = line.split(delimiter).next().unwrap_or("").trim();
+        if text == "@" || text == "＠" {
+            entries.push(String::new());
+        } else {
+            entries.push(text.to_string());
+        }
+    }
+
+    entries
+}
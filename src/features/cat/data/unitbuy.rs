@@ -5,6 +5,12 @@ use std::collections::HashMap;
 use crate::global::utils; 
 use crate::features::cat::paths;
 
+pub const RARITY_NAMES: [&str; 6] = ["Normal", "Special", "Rare", "Super Rare", "Uber Rare", "Legend Rare"];
+
+pub fn rarity_name(rarity: i32) -> &'static str {
+    usize::try_from(rarity).ok().and_then(|index| RARITY_NAMES.get(index)).copied().unwrap_or("Unknown")
+}
+
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
 pub struct UnitBuyRow {
     pub stage_unlock_requirement: i32,
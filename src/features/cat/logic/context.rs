@@ -2,6 +2,9 @@ use std::collections::HashMap;
 use crate::features::cat::logic::stats::{CatRaw, CatLevelCurve};
 use crate::features::cat::data::skillacquisition::TalentRaw;
 use crate::global::context::GlobalContext;
+use crate::features::cat::data::nyancombo::ComboData;
+use crate::features::gacha::data::gatyadataset::GachaData;
+use crate::features::stage::logic::index::DropIndex;
 
 #[derive(Clone, Copy)]
 pub struct CatRenderContext<'a> {
@@ -13,4 +16,12 @@ pub struct CatRenderContext<'a> {
     pub talent_data: Option<&'a TalentRaw>,
     pub talent_levels: Option<&'a HashMap<u8, u8>>,
     pub is_conjure_unit: bool,
-}
\ No newline at end of file
+}
+
+// Game-wide tables the details tab looks cats up in
+#[derive(Clone, Copy)]
+pub struct CatLookups<'a> {
+    pub combo_data: &'a ComboData,
+    pub gacha_data: &'a GachaData,
+    pub drop_index: &'a DropIndex,
+}
@@ -57,6 +57,7 @@ pub struct CatFilterState {
     pub adv_ranges: HashMap<AbilityIcon, HashMap<&'static str, RangeInput>>,
     pub level_input: String,
     pub stat_ranges: HashMap<&'static str, RangeInput>,
+    pub owned_ids: Option<HashSet<u32>>,
 }
 
 impl Default for CatFilterState {
@@ -72,6 +73,7 @@ impl Default for CatFilterState {
             adv_ranges: HashMap::new(),
             level_input: String::new(),
             stat_ranges: HashMap::new(),
+            owned_ids: None,
         }
     }
 }
@@ -84,6 +86,7 @@ impl CatFilterState {
             || self.talent_mode == TalentFilterMode::Only
             || self.ultra_talent_mode == TalentFilterMode::Only
             || self.stat_ranges.values().any(|r| !r.min.is_empty() || !r.max.is_empty())
+            || self.owned_ids.is_some()
     }
 }
 
@@ -111,6 +114,10 @@ pub fn has_trait_or_ability(s: &CatRaw, icon: &AbilityIcon) -> bool {
 }
 
 pub fn entity_passes_filter(cat: &CatEntry, filter: &CatFilterState) -> bool {
+    if filter.owned_ids.as_ref().is_some_and(|owned| !owned.contains(&cat.id)) {
+        return false;
+    }
+
     let any_rarity_selected = filter.rarities.iter().any(|&r| r);
     if any_rarity_selected {
         let r_idx = cat.unit_buy.rarity as usize;
@@ -1,10 +1,16 @@
 use std::time::Instant;
+use std::path::Path;
 use std::sync::mpsc::TryRecvError;
 
 use super::CatListState;
 use super::scanner;
 use crate::global::formats::imgcut::SpriteSheet; 
 use crate::features::settings::logic::state::ScannerConfig;
+use crate::features::cat::data::nyancombo;
+use crate::features::cat::paths;
+use crate::features::gacha::data::gatyadataset;
+use crate::features::gacha::paths as gacha_paths;
+use crate::features::schedule::paths as schedule_paths;
 
 pub fn refresh_cat(state: &mut CatListState, id: u32, config: ScannerConfig) {
     match scanner::scan_single(id, &config) {
@@ -105,5 +111,17 @@ pub fn restart_scan(state: &mut CatListState, config: ScannerConfig) {
     state.selected_form = current_form;
     state.selected_detail_tab = current_tab;
 
+    load_tables(state, &config.language_priority);
     state.scan_receiver = Some(scanner::start_scan(config));
+}
+
+// Shared tables that are not part of the cached cat entries
+pub fn load_tables(state: &mut CatListState, priority: &[String]) {
+    state.combo_data = nyancombo::load(Path::new(paths::DIR_CATS), priority);
+    state.gacha_data = gatyadataset::load(
+        Path::new(gacha_paths::DIR_GATYA),
+        Path::new(schedule_paths::DIR_EVENTS),
+        Path::new(paths::DIR_CATS),
+        priority
+    );
 }
\ No newline at end of file
@@ -48,6 +48,11 @@ impl CatEntry {
     pub fn base_id_str(&self) -> String { format!("{:03}", self.id) }
 }
 
+// Scanned cats are kept sorted by id
+pub fn find_entry(cats: &[CatEntry], id: u32) -> Option<&CatEntry> {
+    cats.binary_search_by_key(&id, |cat| cat.id).ok().map(|pos| &cats[pos])
+}
+
 fn is_valid_png(path: &Path) -> bool {
     let mut file = match fs::File::open(path) { Ok(f) => f, Err(_) => return false, };
     let mut buffer = [0u8; 25];
@@ -1,11 +1,13 @@
 use eframe::egui;
 use std::collections::HashSet;
+use std::sync::Arc;
 use std::sync::mpsc::Receiver;
 use std::collections::{HashMap, VecDeque};
 use serde::{Deserialize, Serialize};
 use std::time::Instant;
 use super::scanner::CatEntry;
 use super::loader;
+use super::context::CatLookups;
 
 use crate::features::cat::ui::list::CatList; 
 use crate::features::cat::ui as cat_detail;
@@ -17,6 +19,11 @@ use crate::features::settings::logic::state::ScannerConfig;
 use crate::features::settings::logic::Settings;
 use crate::global::ui::shared::DragGuard; 
 use crate::global::game::param::Param;
+use crate::features::cat::data::nyancombo::ComboData;
+use crate::features::gacha::data::gatyadataset::GachaData;
+use crate::features::stage::logic::index::DropIndex;
+use crate::features::save::logic::state::{self as profile_state, ProfileState};
+use crate::features::save::ui::window as profile_window;
 
 pub const TOP_PANEL_PADDING: f32 = 2.5;
 pub const SEARCH_FILTER_GAP: f32 = 5.0;
@@ -69,6 +76,10 @@ pub struct CatListState {
     #[serde(skip)] pub drag_guard: DragGuard,
     #[serde(skip)] pub saved_pre_ultra_level: Option<(i32, String)>,
     #[serde(skip)] pub is_in_ultra_state: bool,
+    #[serde(skip)] pub combo_data: ComboData,
+    #[serde(skip)] pub gacha_data: GachaData,
+    #[serde(skip)] pub drop_index: Arc<DropIndex>,
+    pub profile: ProfileState,
 }
 
 impl Default for CatListState {
@@ -105,6 +116,10 @@ impl Default for CatListState {
             drag_guard: DragGuard::default(),
             saved_pre_ultra_level: None,
             is_in_ultra_state: false,
+            combo_data: ComboData::default(),
+            gacha_data: GachaData::default(),
+            drop_index: Arc::default(),
+            profile: ProfileState::default(),
         }
     }
 }
@@ -117,6 +132,42 @@ impl CatListState {
     pub fn restart_scan(&mut self, config: ScannerConfig) {
         loader::restart_scan(self, config);
     }
+
+    pub fn sync_drops(&mut self, drop_index: &Arc<DropIndex>) {
+        if !Arc::ptr_eq(&self.drop_index, drop_index) {
+            self.drop_index = Arc::clone(drop_index);
+        }
+    }
+
+    pub fn select_cat(&mut self, id: u32, form: usize) {
+        if self.selected_cat != Some(id) {
+            self.detail_texture = None;
+            self.detail_key.clear();
+            self.sprite_sheet = SpriteSheet::default();
+            self.model_data = None;
+        }
+        self.selected_cat = Some(id);
+        self.selected_form = form;
+        self.apply_profile(id, false);
+    }
+
+    // Prefills level, talents and optionally the form from the loaded save
+    pub fn apply_profile(&mut self, cat_id: u32, use_owned_form: bool) {
+        let Some(owned) = self.profile.owned(cat_id).cloned() else { return; };
+        let Some(cat) = super::scanner::find_entry(&self.cats, cat_id) else { return; };
+
+        self.current_level = owned.total_level();
+        self.level_input = owned.level_text();
+
+        if use_owned_form && cat.forms.get(owned.form).copied().unwrap_or(false) {
+            self.selected_form = owned.form;
+        }
+
+        let levels = profile_state::talent_levels(&owned, cat.talent_data.as_ref());
+        if !levels.is_empty() {
+            self.talent_levels.insert(cat_id, levels);
+        }
+    }
 }
 
 pub fn show(ctx: &egui::Context, state: &mut CatListState, settings: &mut Settings, param: &Param) {
@@ -134,6 +185,15 @@ pub fn show(ctx: &egui::Context, state: &mut CatListState, settings: &mut Settin
         ctx.request_repaint(); 
     }
 
+    let profile_loaded = state.profile.ensure_loaded();
+    let profile_changed = profile_window::show(ctx, &mut state.profile);
+    if profile_loaded || profile_changed {
+        state.profile.sync_filter(&mut state.filter_state);
+        if let Some(selected_id) = state.selected_cat {
+            state.apply_profile(selected_id, true);
+        }
+    }
+
     egui::SidePanel::left("cat_list_panel")
         .resizable(false)
         .default_width(160.0)
@@ -156,6 +216,14 @@ pub fn show(ctx: &egui::Context, state: &mut CatListState, settings: &mut Settin
                     if ui.add_sized(btn_size, filter_btn).clicked() {
                         state.filter_state.is_open = !state.filter_state.is_open;
                     }
+                    ui.add_space(SEARCH_FILTER_GAP);
+                    let mut profile_btn = egui::Button::new("Profile");
+                    if state.profile.profile.is_some() {
+                        profile_btn = profile_btn.fill(egui::Color32::from_rgb(31, 106, 165));
+                    }
+                    if ui.add_sized(btn_size, profile_btn).clicked() {
+                        state.profile.is_open = !state.profile.is_open;
+                    }
                 });
                 ui.add_space(SPACE_BEFORE_SEPARATOR); 
                 ui.separator();
@@ -234,6 +302,8 @@ pub fn show(ctx: &egui::Context, state: &mut CatListState, settings: &mut Settin
                             state.level_input = settings.cat_data.default_level.to_string();
                         }
                     }
+
+                    state.apply_profile(new_id, true);
                 }
             }
         });
@@ -288,6 +358,11 @@ pub fn show(ctx: &egui::Context, state: &mut CatListState, settings: &mut Settin
             cat_entry.talent_costs.as_ref(),
             state.texture_cache_version,
             param,
+            CatLookups {
+                combo_data: &state.combo_data,
+                gacha_data: &state.gacha_data,
+                drop_index: &state.drop_index,
+            },
         );
 
         let mut current_ultra_state = state.selected_form == 3;
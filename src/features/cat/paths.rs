@@ -6,6 +6,11 @@ pub const UNIT_BUY: &str = "unitbuy.csv";
 pub const UNIT_LEVEL: &str = "unitlevel.csv";
 pub const SKILL_ACQUISITION: &str = "SkillAcquisition.csv";
 pub const SKILL_LEVEL: &str = "SkillLevel.csv"; 
+pub const NYANCOMBO_DATA: &str = "NyancomboData.csv";
+pub const NYANCOMBO_PARAM: &str = "NyancomboParam.tsv";
+pub const NYANCOMBO_NAME: &str = "Nyancombo.csv";
+pub const NYANCOMBO_EFFECT: &str = "Nyancombo1.csv";
+pub const NYANCOMBO_SIZE: &str = "Nyancombo2.csv";
 
 // Directory Constants
 pub const DIR_CATS: &str = "game/cats";
@@ -39,5 +39,10 @@ pub const CAT_UNIVERSAL_FILES: &[&str] = &[
     "unitexp.csv", 
     "unitlevel.csv", 
     "unitlimit.csv",
+    "NyancomboData.csv",
+    "NyancomboParam.tsv",
+    "Nyancombo.csv",
+    "Nyancombo1.csv",
+    "Nyancombo2.csv",
     "uni.png" 
 ];
\ No newline at end of file
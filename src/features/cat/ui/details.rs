@@ -3,6 +3,9 @@ use std::collections::HashMap;
 use std::path::Path;
 use image::GenericImageView; 
 use crate::features::cat::data::unitbuy::UnitBuyRow;
+use crate::features::cat::data::nyancombo::ComboData;
+use crate::features::gacha::data::gatyadataset::GachaData;
+use crate::features::stage::logic::index::DropIndex;
 use crate::global::io::paths;
 
 pub fn render(ui: &mut egui::Ui, description: &[String]) {
@@ -205,6 +208,120 @@ pub fn render_evolve(
     }
 }
 
+pub fn render_combos(ui: &mut egui::Ui, cat_id: u32, combo_data: &ComboData) {
+    let combos = combo_data.combos_for_cat(cat_id);
+    if combos.is_empty() { return; }
+
+    ui.add_space(15.0);
+    ui.separator();
+    ui.add_space(10.0);
+
+    ui.vertical_centered(|ui| {
+        ui.heading(egui::RichText::new("Combos").size(20.0).strong());
+    });
+    ui.add_space(8.0);
+
+    let form_labels = ["Normal", "Evolved", "True", "Ultra"];
+
+    ui.vertical_centered(|ui| {
+        egui::Grid::new("cat_combo_grid")
+            .striped(true)
+            .spacing([15.0, 4.0])
+            .show(ui, |ui| {
+                ui.label(egui::RichText::new("Combo").strong());
+                ui.label(egui::RichText::new("Form").strong());
+                ui.label(egui::RichText::new("Units").strong());
+                ui.label(egui::RichText::new("Effect").strong());
+                ui.end_row();
+
+                for combo in combos {
+                    let required_form = combo.units.iter()
+                        .find(|unit| unit.cat_id == cat_id)
+                        .map(|unit| form_labels.get(unit.form).copied().unwrap_or("?"))
+                        .unwrap_or("?");
+
+                    ui.label(&combo.name);
+                    ui.label(format!("{}+", required_form));
+                    ui.label(combo.units.len().to_string());
+                    ui.label(combo_data.effect_text(combo));
+                    ui.end_row();
+                }
+            });
+    });
+}
+
+pub fn render_banners(ui: &mut egui::Ui, cat_id: u32, gacha_data: &GachaData) {
+    let banners = gacha_data.banners_for_cat(cat_id);
+    if banners.is_empty() { return; }
+
+    ui.add_space(15.0);
+    ui.separator();
+    ui.add_space(10.0);
+
+    ui.vertical_centered(|ui| {
+        ui.heading(egui::RichText::new("Available in").size(20.0).strong());
+    });
+    ui.add_space(8.0);
+
+    ui.vertical_centered(|ui| {
+        egui::Grid::new("cat_banner_grid")
+            .striped(true)
+            .spacing([15.0, 4.0])
+            .show(ui, |ui| {
+                ui.label(egui::RichText::new("Banner").strong());
+                ui.label(egui::RichText::new("Pool").strong());
+                ui.label(egui::RichText::new("Rate").strong());
+                ui.end_row();
+
+                // Newest banners first
+                for banner in banners.into_iter().rev() {
+                    ui.label(banner.label());
+                    ui.label(format!("{} units", banner.units.len()));
+                    match banner.unit_rate(cat_id) {
+                        Some(rate) => ui.label(format!("{:.3}%", rate)),
+                        None => ui.label("-"),
+                    };
+                    ui.end_row();
+                }
+            });
+    });
+}
+
+pub fn render_drops(ui: &mut egui::Ui, cat_id: u32, drop_index: &DropIndex) {
+    let sources = drop_index.cat_sources(cat_id);
+    if sources.is_empty() { return; }
+
+    ui.add_space(15.0);
+    ui.separator();
+    ui.add_space(10.0);
+
+    ui.vertical_centered(|ui| {
+        ui.heading(egui::RichText::new("Stage Drops").size(20.0).strong());
+    });
+    ui.add_space(8.0);
+
+    ui.vertical_centered(|ui| {
+        egui::Grid::new("cat_drop_grid")
+            .striped(true)
+            .spacing([15.0, 4.0])
+            .show(ui, |ui| {
+                for header in ["Stage", "Map", "Form", "Chance", "Energy"] {
+                    ui.label(egui::RichText::new(header).strong());
+                }
+                ui.end_row();
+
+                for source in sources {
+                    ui.label(&source.stage_name);
+                    ui.label(format!("{} - {}", source.category_name, source.map_name));
+                    ui.label(if source.form == 2 { "True" } else { "Normal" });
+                    ui.label(&source.chance);
+                    ui.label(source.energy.to_string());
+                    ui.end_row();
+                }
+            });
+    });
+}
+
 fn load_material_icon_legacy(ctx: &egui::Context, id: i32, version: u64, priority: &[String]) -> Option<egui::TextureHandle> {
     let expected_path = paths::gatya_item_icon(Path::new(""), id)?;
     
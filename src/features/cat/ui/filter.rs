@@ -279,7 +279,7 @@ pub fn show_popup(
     state.is_open = is_open_local;
 
     if clear_filters {
-        *state = CatFilterState { is_open: state.is_open, ..Default::default() };
+        *state = CatFilterState { is_open: state.is_open, owned_ids: state.owned_ids.take(), ..Default::default() };
     }
 }
 
@@ -0,0 +1,77 @@
+use eframe::egui;
+use std::collections::HashMap;
+use std::path::Path;
+use crate::features::cat::logic::scanner::CatEntry;
+use crate::features::cat::paths;
+use crate::global::utils::autocrop;
+
+pub const DEPLOY_ICON_SIZE: egui::Vec2 = egui::vec2(110.0, 85.0);
+
+pub type DeployIconCache = HashMap<(u32, usize), Option<egui::TextureHandle>>;
+
+// Deploy icon of a specific form, loaded once per cache
+pub fn deploy_icon(
+    ctx: &egui::Context,
+    cache: &mut DeployIconCache,
+    cat: &CatEntry,
+    form: usize,
+) -> Option<egui::TextureHandle> {
+    cache.entry((cat.id, form)).or_insert_with(|| {
+        let path = cat.deploy_icon_paths.get(form).cloned().flatten();
+        load_deploy_icon(ctx, path.as_deref(), cat.id, form)
+    }).clone()
+}
+
+pub fn draw_deploy_icon(
+    ui: &mut egui::Ui,
+    texture: Option<&egui::TextureHandle>,
+    size: egui::Vec2,
+    fallback_text: &str,
+) -> egui::Response {
+    let (rect, response) = ui.allocate_exact_size(size, egui::Sense::click());
+
+    if let Some(tex) = texture {
+        ui.painter().image(
+            tex.id(),
+            rect,
+            egui::Rect::from_min_max(egui::pos2(0.0, 0.0), egui::pos2(1.0, 1.0)),
+            egui::Color32::WHITE,
+        );
+    } else {
+        ui.painter().rect_filled(rect, egui::Rounding::same(4.0), egui::Color32::from_gray(50));
+        ui.painter().text(
+            rect.center(),
+            egui::Align2::CENTER_CENTER,
+            fallback_text,
+            egui::FontId::proportional(12.0),
+            egui::Color32::WHITE,
+        );
+    }
+
+    response
+}
+
+fn load_deploy_icon(ctx: &egui::Context, path: Option<&Path>, id: u32, form: usize) -> Option<egui::TextureHandle> {
+    let fallback = Path::new(paths::FALLBACK_ICON);
+    let final_path = match path {
+        Some(icon_path) if icon_path.exists() => icon_path,
+        _ if fallback.exists() => fallback,
+        _ => return None,
+    };
+
+    let img = image::open(final_path).ok()?;
+    let mut rgba = autocrop(img.to_rgba8());
+
+    if rgba.width() != DEPLOY_ICON_SIZE.x as u32 || rgba.height() != DEPLOY_ICON_SIZE.y as u32 {
+        rgba = image::imageops::resize(&rgba, DEPLOY_ICON_SIZE.x as u32, DEPLOY_ICON_SIZE.y as u32, image::imageops::FilterType::Lanczos3);
+    }
+
+    let size = [rgba.width() as usize, rgba.height() as usize];
+    let pixels = rgba.as_flat_samples();
+
+    Some(ctx.load_texture(
+        format!("deploy_icon_{}_{}", id, form),
+        egui::ColorImage::from_rgba_unmultiplied(size, pixels.as_slice()),
+        egui::TextureOptions::LINEAR,
+    ))
+}
@@ -16,7 +16,7 @@ use super::header::ExportAction;
 use crate::features::cat::logic::statblock::build_cat_statblock;
 use crate::global::game::param::Param;
 use crate::global::context::GlobalContext;
-use crate::features::cat::logic::context::CatRenderContext;
+use crate::features::cat::logic::context::{CatLookups, CatRenderContext};
 
 pub fn show(
     ctx: &egui::Context, 
@@ -42,6 +42,7 @@ pub fn show(
     talent_costs: &HashMap<u8, TalentCost>,
     cache_version: u64,
     param: &Param,
+    lookups: CatLookups,
 ) {
     img015::ensure_loaded(ctx, img015_sheets, settings);
     img022::ensure_loaded(ctx, img022_sheets, settings);
@@ -160,21 +161,28 @@ pub fn show(
              }
         },
         DetailTab::Details => {
-             let fallback = Vec::new();
-             let desc = cat_entry.description.get(*current_form).unwrap_or(&fallback);
-             details::render(ui, desc);
-             let text_fallback = Vec::new();
-             let ev_text = cat_entry.evolve_text.get(*current_form).unwrap_or(&text_fallback);
-             details::render_evolve(
-                ui, 
-                ctx, 
-                &cat_entry.unit_buy, 
-                ev_text, 
-                *current_form, 
-                gatya_item_textures, 
-                cache_version, 
-                &settings.general.language_priority 
-            );
+             egui::ScrollArea::vertical()
+                .auto_shrink([false, false])
+                .show(ui, |ui| {
+                    let fallback = Vec::new();
+                    let desc = cat_entry.description.get(*current_form).unwrap_or(&fallback);
+                    details::render(ui, desc);
+                    let text_fallback = Vec::new();
+                    let ev_text = cat_entry.evolve_text.get(*current_form).unwrap_or(&text_fallback);
+                    details::render_evolve(
+                        ui, 
+                        ctx, 
+                        &cat_entry.unit_buy, 
+                        ev_text, 
+                        *current_form, 
+                        gatya_item_textures, 
+                        cache_version, 
+                        &settings.general.language_priority 
+                    );
+                    details::render_combos(ui, cat_entry.id, lookups.combo_data);
+                    details::render_banners(ui, cat_entry.id, lookups.gacha_data);
+                    details::render_drops(ui, cat_entry.id, lookups.drop_index);
+                });
         }
         DetailTab::Animation => {
             viewer::show(ui, ctx, cat_entry, *current_form, anim_viewer, model_data, anim_sheet, settings);
@@ -8,5 +8,6 @@ pub mod viewer;
 pub mod master;
 pub mod filter;
 pub mod conjure;
+pub mod icon;
 
 pub use master::show;
\ No newline at end of file
@@ -5,6 +5,8 @@ use crate::features::cat::logic::scanner::CatEntry;
 use crate::global::formats::imgcut::SpriteSheet;
 use crate::global::formats::mamodel::Model;
 use crate::features::animation::ui::viewer::AnimViewer;
+use crate::features::animation::logic::overlay::CombatProfile;
+use crate::features::animation::logic::playlist::UnitKind;
 use crate::features::settings::logic::Settings;
 use crate::features::cat::paths::{self, AnimType};
 use crate::features::animation::ui::controls::{
@@ -72,5 +74,9 @@ pub fn show(
     let form_char = match current_form { 0 => 'f', 1 => 'c', 2 => 's', _ => 'u' };
     let primary_id = format!("{:03}_{}_{}", cat_entry.id, form_char, anim_viewer.texture_version);
 
+    let attack_frames = cat_entry.atk_anim_frames.get(current_form).copied().unwrap_or(0);
+    anim_viewer.combat_profile = cat_entry.stats.get(current_form).and_then(Option::as_ref).map(|stats| CombatProfile::from_cat(stats, attack_frames));
+    anim_viewer.unit_kind = UnitKind::Cat;
+
     anim_viewer.show(ui, ctx, &primary_id, &secondary_id, &available_anims, primary_assets, secondary_assets, model_data, anim_sheet, settings);
 }
\ No newline at end of file
@@ -0,0 +1,9 @@
+use crate::features::cat::ui::icon::DeployIconCache;
+
+#[derive(Default, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct ComboListState {
+    pub search_query: String,
+    pub effect_filter: Option<usize>,
+    #[serde(skip)] pub icon_cache: DeployIconCache,
+}
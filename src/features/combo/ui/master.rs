@@ -0,0 +1,116 @@
+use eframe::egui;
+use std::collections::BTreeSet;
+use crate::features::combo::logic::state::ComboListState;
+use crate::features::cat::logic::scanner::{self, CatEntry};
+use crate::features::cat::data::nyancombo::{ComboData, ComboRow};
+use crate::features::cat::ui::icon;
+
+const ICON_SIZE: egui::Vec2 = egui::vec2(66.0, 51.0);
+
+// Returns the cat and form clicked in the list so the caller can open it
+pub fn show(
+    ctx: &egui::Context,
+    state: &mut ComboListState,
+    cats: &[CatEntry],
+    combo_data: &ComboData,
+) -> Option<(u32, usize)> {
+    let mut clicked_unit = None;
+
+    egui::CentralPanel::default().show(ctx, |ui| {
+        if combo_data.combos.is_empty() {
+            ui.centered_and_justified(|ui| {
+                ui.vertical_centered(|ui| {
+                    ui.add_space(ui.available_height() * 0.4);
+                    ui.heading("No Data Found");
+                    ui.label(egui::RichText::new("Could not find NyancomboData.csv in game/cats").color(ui.visuals().weak_text_color()));
+                });
+            });
+            return;
+        }
+
+        render_toolbar(ui, state, combo_data);
+        ui.separator();
+
+        let query = state.search_query.to_lowercase();
+        let visible: Vec<&ComboRow> = combo_data.combos.iter()
+            .filter(|combo| state.effect_filter.is_none_or(|effect_id| combo.effect_id == effect_id))
+            .filter(|combo| query.is_empty() || matches_query(combo, combo_data, cats, &query))
+            .collect();
+
+        if visible.is_empty() {
+            ui.centered_and_justified(|ui| { ui.label("No combos match the current search"); });
+            return;
+        }
+
+        egui::ScrollArea::vertical()
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                egui::Grid::new("combo_list_grid")
+                    .striped(true)
+                    .spacing([15.0, 4.0])
+                    .min_row_height(ICON_SIZE.y)
+                    .show(ui, |ui| {
+                        ui.label(egui::RichText::new("Combo").strong());
+                        ui.label(egui::RichText::new("Effect").strong());
+                        ui.label(egui::RichText::new("Units").strong());
+                        ui.end_row();
+
+                        for combo in visible {
+                            ui.label(&combo.name);
+                            ui.label(combo_data.effect_text(combo));
+
+                            ui.horizontal(|ui| {
+                                ui.spacing_mut().item_spacing.x = 4.0;
+                                for unit in &combo.units {
+                                    let cat = scanner::find_entry(cats, unit.cat_id);
+                                    let texture = cat.and_then(|entry| icon::deploy_icon(ctx, &mut state.icon_cache, entry, unit.form));
+                                    let fallback = format!("{:03}-{}", unit.cat_id, unit.form + 1);
+
+                                    let response = icon::draw_deploy_icon(ui, texture.as_ref(), ICON_SIZE, &fallback);
+                                    let tooltip = cat.map_or(fallback.clone(), |entry| entry.display_name(unit.form));
+
+                                    if response.on_hover_text(tooltip).clicked() && cat.is_some() {
+                                        clicked_unit = Some((unit.cat_id, unit.form));
+                                    }
+                                }
+                            });
+                            ui.end_row();
+                        }
+                    });
+            });
+    });
+
+    clicked_unit
+}
+
+fn render_toolbar(ui: &mut egui::Ui, state: &mut ComboListState, combo_data: &ComboData) {
+    ui.horizontal(|ui| {
+        ui.add(egui::TextEdit::singleline(&mut state.search_query)
+            .hint_text(egui::RichText::new("Search Combo...").color(egui::Color32::GRAY))
+            .desired_width(200.0));
+
+        let effect_ids: BTreeSet<usize> = combo_data.combos.iter().map(|combo| combo.effect_id).collect();
+        let selected_text = state.effect_filter.map_or("All Effects".to_string(), |effect_id| combo_data.effect_name(effect_id));
+
+        egui::ComboBox::from_id_salt("combo_effect_filter")
+            .selected_text(selected_text)
+            .width(200.0)
+            .show_ui(ui, |ui| {
+                ui.selectable_value(&mut state.effect_filter, None, "All Effects");
+                for effect_id in effect_ids {
+                    ui.selectable_value(&mut state.effect_filter, Some(effect_id), combo_data.effect_name(effect_id));
+                }
+            });
+    });
+}
+
+fn matches_query(combo: &ComboRow, combo_data: &ComboData, cats: &[CatEntry], query: &str) -> bool {
+    if combo.name.to_lowercase().contains(query) { return true; }
+    if combo_data.effect_text(combo).to_lowercase().contains(query) { return true; }
+
+    combo.units.iter().any(|unit| {
+        scanner::find_entry(cats, unit.cat_id).is_some_and(|cat| {
+            cat.names.iter().any(|name| name.to_lowercase().contains(query))
+        })
+    })
+}
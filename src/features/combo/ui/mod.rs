@@ -0,0 +1 @@
+pub mod master;
\ No newline at end of file
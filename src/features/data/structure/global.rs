@@ -6,6 +6,8 @@ pub struct GlobalMatcher {
     gatya_item_d: Regex,
     gatya_item_buy: Regex,
     gatya_item_name: Regex,
+    gatya_data_set: Regex,
+    event_schedule: Regex,
     img015: Regex,
     img015_cut: Regex,
     img022: Regex,
@@ -22,6 +24,8 @@ impl GlobalMatcher {
             gatya_item_d: Regex::new(patterns::GATYA_ITEM_D_PATTERN).unwrap(),
             gatya_item_buy: Regex::new(patterns::GATYA_ITEM_BUY_PATTERN).unwrap(),
             gatya_item_name: Regex::new(patterns::GATYA_ITEM_NAME_PATTERN).unwrap(),
+            gatya_data_set: Regex::new(patterns::GATYA_DATA_SET_PATTERN).unwrap(),
+            event_schedule: Regex::new(patterns::EVENT_SCHEDULE_PATTERN).unwrap(),
             img015: Regex::new(patterns::ASSET_IMG015_PATTERN).unwrap(),
             img015_cut: Regex::new(patterns::ASSET_015CUT_PATTERN).unwrap(),
             img022: Regex::new(patterns::ASSET_IMG022_PATTERN).unwrap(),
@@ -44,6 +48,12 @@ impl GlobalMatcher {
         if self.gatya_item_name.is_match(name) {
             return Some(tables_dir.join("GatyaitemName"));
         }
+        if self.gatya_data_set.is_match(name) {
+            return Some(tables_dir.join("gatya"));
+        }
+        if self.event_schedule.is_match(name) {
+            return Some(tables_dir.join("events"));
+        }
 
         // UI Directory
         if self.gatya_item_d.is_match(name) {
@@ -12,6 +12,7 @@ pub struct StageMatcher {
     stage_name: Regex,
     legacy_stage_name: Regex,
     castle: Regex,
+    cat_castle: Regex,
     bg_map: Regex,
     bg_battle: Regex,
     bg_data: Regex,
@@ -38,6 +39,7 @@ impl StageMatcher {
             stage_name: Regex::new(patterns::STAGE_NAME_PATTERN).unwrap(),
             legacy_stage_name: Regex::new(patterns::LEGACY_STAGE_NAME_PATTERN).unwrap(),
             castle: Regex::new(patterns::CASTLE_PATTERN).unwrap(),
+            cat_castle: Regex::new(patterns::CAT_CASTLE_PATTERN).unwrap(),
             bg_map: Regex::new(patterns::BG_MAP_PATTERN).unwrap(),
             bg_battle: Regex::new(patterns::BG_BATTLE_PATTERN).unwrap(),
             bg_data: Regex::new(patterns::BG_DATA_PATTERN).unwrap(),
@@ -194,6 +196,9 @@ impl StageMatcher {
             if target_file_name.starts_with("fc000") { return None; } 
             return Some(base_stages_dir.join("castles").join(&regex_captures[1]));
         }
+        if self.cat_castle.is_match(target_file_name) {
+            return Some(base_stages_dir.join("castles").join("nyanko"));
+        }
         if let Some(regex_captures) = self.bg_map.captures(target_file_name) {
             if let Ok(parsed_id) = regex_captures[1].parse::<u32>() { 
                 return Some(base_stages_dir.join("backgrounds").join("maps").join(format!("{:03}", parsed_id))); 
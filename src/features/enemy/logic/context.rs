@@ -1,5 +1,7 @@
 use crate::features::enemy::data::t_unit::EnemyRaw;
+use crate::features::enemy::logic::state::AppearanceSort;
 use crate::features::enemy::registry::Magnification;
+use crate::features::stage::logic::index::EnemyAppearance;
 use crate::global::context::GlobalContext;
 
 #[derive(Clone, Copy)]
@@ -7,4 +9,10 @@ pub struct EnemyRenderContext<'a> {
     pub global: GlobalContext<'a>,
     pub stats: &'a EnemyRaw,
     pub magnification: Magnification,
-}
\ No newline at end of file
+}
+
+// Stages the selected enemy shows up in, with the sort the appearances tab keeps between frames
+pub struct AppearanceContext<'a> {
+    pub appearances: &'a [EnemyAppearance],
+    pub sort: &'a mut AppearanceSort,
+}
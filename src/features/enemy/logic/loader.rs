@@ -38,6 +38,7 @@ pub fn refresh_enemy(state: &mut EnemyListState, id: u32, config: &ScannerConfig
             }
         }
     }
+    state.generation += 1;
 }
 
 pub fn update_data(state: &mut EnemyListState) {
@@ -88,6 +89,7 @@ pub fn update_data(state: &mut EnemyListState) {
         }
 
         state.enemy_list.force_search_rebuild();
+        state.generation += 1;
         state.scan_receiver = None;
     }
 }
\ No newline at end of file
@@ -15,7 +15,9 @@ use crate::global::ui::shared::DragGuard;
 use crate::features::enemy::ui::filter::EnemyFilterState; 
 use crate::features::enemy::registry::Magnification;
 use crate::global::game::param::Param;
+use crate::features::stage::logic::index::EnemyIndex;
 use super::loader;
+use super::context::AppearanceContext;
 
 pub const TOP_PANEL_PADDING: f32 = 2.5;
 pub const SEARCH_FILTER_GAP: f32 = 5.0;
@@ -26,6 +28,7 @@ pub const SPACE_AFTER_SEPARATOR: f32 = 2.0;
 pub enum EnemyDetailTab {
     Abilities,
     Details,
+    Appearances,
     Animation,
 }
 
@@ -33,6 +36,14 @@ impl Default for EnemyDetailTab {
     fn default() -> Self { Self::Abilities }
 }
 
+#[derive(Deserialize, Serialize, PartialEq, Clone, Copy, Default)]
+pub enum AppearanceSort {
+    #[default]
+    Stage,
+    MagnificationDesc,
+    MagnificationAsc,
+}
+
 #[derive(Deserialize, Serialize)]
 #[serde(default)]
 pub struct EnemyListState {
@@ -43,6 +54,7 @@ pub struct EnemyListState {
     pub selected_enemy: Option<u32>,
     pub search_query: String,
     pub selected_tab: EnemyDetailTab,
+    pub appearance_sort: AppearanceSort,
     pub mag_input: String,
     pub magnification: Magnification,
     #[serde(skip)] pub enemy_list: EnemyList, 
@@ -56,6 +68,8 @@ pub struct EnemyListState {
     #[serde(skip)] pub anim_viewer: AnimViewer,
     #[serde(skip)] pub custom_assets: Option<CustomAssets>,
     #[serde(skip)] pub scan_receiver: Option<Receiver<EnemyEntry>>,
+    // Bumped whenever the enemy list is complete again, other pages rebuild what they derive from it
+    #[serde(skip)] pub generation: u64,
     #[serde(skip)] pub filter_state: EnemyFilterState,
     #[serde(skip)] pub drag_guard: DragGuard,
 }
@@ -70,6 +84,7 @@ impl Default for EnemyListState {
             selected_enemy: None,
             search_query: String::new(),
             selected_tab: EnemyDetailTab::default(),
+            appearance_sort: AppearanceSort::default(),
             mag_input: "100".to_string(),
             magnification: Magnification::default(),
             enemy_list: EnemyList::default(),
@@ -83,6 +98,7 @@ impl Default for EnemyListState {
             anim_viewer: AnimViewer::default(),
             custom_assets: None, 
             scan_receiver: None,
+            generation: 0,
             filter_state: EnemyFilterState::default(),
             drag_guard: DragGuard::default(),
         }
@@ -99,7 +115,8 @@ impl EnemyListState {
     }
 }
 
-pub fn show(ctx: &egui::Context, state: &mut EnemyListState, settings: &mut Settings, param: &Param) {
+// Returns the stage key when an appearance row was clicked
+pub fn show(ctx: &egui::Context, state: &mut EnemyListState, settings: &mut Settings, param: &Param, enemy_index: &EnemyIndex) -> Option<String> {
     if state.custom_assets.is_none() {
         state.custom_assets = Some(CustomAssets::new(ctx));
     }
@@ -161,6 +178,8 @@ pub fn show(ctx: &egui::Context, state: &mut EnemyListState, settings: &mut Sett
         state.model_data = None; 
     }
 
+    let mut clicked_stage = None;
+
     egui::CentralPanel::default().show(ctx, |ui| {
         if state.enemies.is_empty() {
              ui.centered_and_justified(|ui| {
@@ -195,11 +214,14 @@ pub fn show(ctx: &egui::Context, state: &mut EnemyListState, settings: &mut Sett
             return; 
         };
 
-        master::show(
+        let appearances = enemy_index.get(&selected_id).map(Vec::as_slice).unwrap_or_default();
+
+        clicked_stage = master::show(
             ctx, ui, enemy_entry, &mut state.selected_tab, &mut state.mag_input,
             &mut state.magnification, settings, &mut state.img015_sheets,
             &mut state.anim_sheet, &mut state.model_data, &mut state.anim_viewer,
             &assets, &mut state.detail_texture, &mut state.detail_key, param,
+            AppearanceContext { appearances, sort: &mut state.appearance_sort },
         );
     });
 
@@ -207,4 +229,6 @@ pub fn show(ctx: &egui::Context, state: &mut EnemyListState, settings: &mut Sett
         ctx, &mut state.filter_state, &mut state.img015_sheets,
         &assets, settings, &mut state.drag_guard,
     );
+
+    clicked_stage
 }
\ No newline at end of file
@@ -0,0 +1,76 @@
+use eframe::egui;
+use crate::features::enemy::logic::state::AppearanceSort;
+use crate::features::stage::logic::battleground as bg_logic;
+use crate::features::stage::logic::index::EnemyAppearance;
+use crate::features::stage::ui::link::stage_link;
+
+pub fn render(ui: &mut egui::Ui, appearances: &[EnemyAppearance], sort: &mut AppearanceSort) -> Option<String> {
+    ui.add_space(5.0);
+
+    if appearances.is_empty() {
+        ui.label(egui::RichText::new("This enemy does not appear in any loaded stage").weak().italics());
+        return None;
+    }
+
+    let stage_count = appearances.iter()
+        .map(|appearance| appearance.stage_key.as_str())
+        .collect::<std::collections::HashSet<_>>()
+        .len();
+    ui.label(egui::RichText::new(format!("Appears in {} stages", stage_count)).weak());
+
+    let mut rows: Vec<&EnemyAppearance> = appearances.iter().collect();
+    match sort {
+        AppearanceSort::Stage => {},
+        AppearanceSort::MagnificationDesc => rows.sort_by(|a, b| b.magnification.cmp(&a.magnification).then(a.sort_key.cmp(&b.sort_key))),
+        AppearanceSort::MagnificationAsc => rows.sort_by(|a, b| a.magnification.cmp(&b.magnification).then(a.sort_key.cmp(&b.sort_key))),
+    }
+
+    let mut clicked_stage = None;
+
+    egui::ScrollArea::vertical()
+        .auto_shrink([false, false])
+        .show(ui, |ui| {
+            egui::Grid::new("enemy_appearance_grid")
+                .striped(true)
+                .spacing([15.0, 4.0])
+                .show(ui, |ui| {
+                    let stage_arrow = if *sort == AppearanceSort::Stage { " ▼" } else { "" };
+                    if ui.button(egui::RichText::new(format!("Stage{}", stage_arrow)).strong()).clicked() {
+                        *sort = AppearanceSort::Stage;
+                    }
+                    ui.label(egui::RichText::new("Map").strong());
+
+                    let mag_arrow = match sort {
+                        AppearanceSort::MagnificationDesc => " ▼",
+                        AppearanceSort::MagnificationAsc => " ▲",
+                        AppearanceSort::Stage => "",
+                    };
+                    if ui.button(egui::RichText::new(format!("HP %{}", mag_arrow)).strong()).clicked() {
+                        *sort = match sort {
+                            AppearanceSort::MagnificationDesc => AppearanceSort::MagnificationAsc,
+                            _ => AppearanceSort::MagnificationDesc,
+                        };
+                    }
+                    for header in ["Atk %", "Boss", "Count"] {
+                        ui.label(egui::RichText::new(header).strong());
+                    }
+                    ui.end_row();
+
+                    for appearance in rows {
+                        let stage_name = if appearance.stage_name.is_empty() { &appearance.stage_key } else { &appearance.stage_name };
+                        if stage_link(ui, stage_name) {
+                            clicked_stage = Some(appearance.stage_key.clone());
+                        }
+
+                        ui.label(format!("{} - {}", appearance.category_name, appearance.map_name));
+                        ui.label(format!("{}%", appearance.magnification));
+                        ui.label(format!("{}%", appearance.atk_magnification));
+                        ui.label(bg_logic::format_boss_type(&appearance.boss_type));
+                        ui.label(bg_logic::format_enemy_amount(&appearance.amount));
+                        ui.end_row();
+                    }
+                });
+        });
+
+    clicked_stage
+}
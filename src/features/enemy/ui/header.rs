@@ -53,6 +53,7 @@ fn render_tabs(ui: &mut egui::Ui, current_tab: &mut EnemyDetailTab) {
             let tabs = [
                 (EnemyDetailTab::Abilities, "Abilities"), 
                 (EnemyDetailTab::Details, "Details"), 
+                (EnemyDetailTab::Appearances, "Appearances"),
                 (EnemyDetailTab::Animation, "Animation")
             ];
 
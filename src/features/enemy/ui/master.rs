@@ -10,10 +10,10 @@ use crate::features::animation::ui::viewer::AnimViewer;
 use crate::global::assets::CustomAssets;
 use crate::global::game::param::Param;
 use crate::global::context::GlobalContext;
-use crate::features::enemy::logic::context::EnemyRenderContext;
+use crate::features::enemy::logic::context::{AppearanceContext, EnemyRenderContext};
 use crate::features::statblock::logic::builder::{generate_and_copy, generate_and_save};
 use crate::features::enemy::logic::statblock::build_enemy_statblock;
-use super::{header, stats, abilities, details, viewer}; 
+use super::{header, stats, abilities, details, appearances, viewer}; 
 use super::header::ExportAction;
 
 pub fn show(
@@ -32,7 +32,8 @@ pub fn show(
     detail_texture: &mut Option<egui::TextureHandle>,
     detail_key: &mut String,
     param: &Param,
-) {
+    appearance_ctx: AppearanceContext,
+) -> Option<String> {
     img015::ensure_loaded(ctx, img015_sheets, settings);
 
     let export_action = header::render(
@@ -105,8 +106,13 @@ pub fn show(
         EnemyDetailTab::Details => {
             details::render(ui, &enemy_entry.description);
         },
+        EnemyDetailTab::Appearances => {
+            return appearances::render(ui, appearance_ctx.appearances, appearance_ctx.sort);
+        },
         EnemyDetailTab::Animation => {
             viewer::show(ui, ctx, enemy_entry, anim_viewer, model_data, anim_sheet, settings);
         }
     }
+
+    None
 }
\ No newline at end of file
@@ -2,6 +2,7 @@ pub mod list;
 pub mod master;
 pub mod stats;
 pub mod details;
+pub mod appearances;
 pub mod abilities;
 pub mod header;
 pub mod viewer;
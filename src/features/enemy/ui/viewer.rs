@@ -5,6 +5,8 @@ use crate::features::enemy::logic::scanner::EnemyEntry;
 use crate::global::formats::imgcut::SpriteSheet;
 use crate::global::formats::mamodel::Model;
 use crate::features::animation::ui::viewer::AnimViewer;
+use crate::features::animation::logic::overlay::CombatProfile;
+use crate::features::animation::logic::playlist::UnitKind;
 use crate::features::settings::logic::Settings;
 use crate::features::enemy::paths::{self, AnimType};
 use crate::features::animation::ui::controls::{
@@ -53,5 +55,7 @@ pub fn show(
     })();
 
     let primary_id = format!("{}_{}", enemy_entry.id_str(), anim_viewer.texture_version);
+    anim_viewer.combat_profile = Some(CombatProfile::from_enemy(&enemy_entry.stats, enemy_entry.atk_anim_frames));
+    anim_viewer.unit_kind = UnitKind::Enemy;
     anim_viewer.show(ui, ctx, &primary_id, &String::new(), &available_anims, primary_assets, None, model_data, anim_sheet, settings);
 }
\ No newline at end of file
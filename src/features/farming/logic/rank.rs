@@ -0,0 +1,144 @@
+use crate::features::stage::data::map_option::ResetType;
+use crate::features::stage::data::mapstagedata::RewardStructure;
+use crate::features::stage::logic::index::{self, DropTables, RewardTarget};
+use crate::features::stage::registry::{Map, StageRegistry};
+use super::state::{FarmSort, FarmTarget, FarmingState};
+
+pub struct FarmOption {
+    pub stage_key: String,
+    pub stage_name: String,
+    pub map_name: String,
+    pub category_name: String,
+    pub sort_key: (u32, u32, u32),
+    pub energy: u32,
+    pub chance: f32,
+    // Map wide drops have no listed rate, they are shown but ranked after everything else
+    pub map_drop: bool,
+    pub amount: u32,
+    pub clear_seconds: u32,
+    pub is_override: bool,
+    pub limit: Option<String>,
+}
+
+impl FarmOption {
+    pub fn expected_per_clear(&self) -> f32 {
+        self.chance / 100.0 * self.amount as f32
+    }
+
+    pub fn per_energy(&self) -> Option<f32> {
+        (self.energy > 0).then(|| self.expected_per_clear() / self.energy as f32)
+    }
+
+    pub fn per_minute(&self) -> f32 {
+        self.expected_per_clear() / (self.clear_seconds.max(1) as f32 / 60.0)
+    }
+}
+
+pub fn crown_magnification(map: &Map, crown: u8) -> Option<u32> {
+    if crown > map.max_crowns.max(1) { return None; }
+    match crown {
+        2 => map.crown_2_mag,
+        3 => map.crown_3_mag,
+        4 => map.crown_4_mag,
+        _ => Some(100),
+    }
+}
+
+fn resets_rewards(reset_type: &ResetType) -> bool {
+    matches!(reset_type, ResetType::ResetRewards | ResetType::ResetRewardsAndClear)
+}
+
+fn clear_limit(map: &Map, once: bool) -> Option<String> {
+    let mut parts = Vec::new();
+    if once {
+        parts.push("Once per reset".to_string());
+    }
+    if map.max_clears > 0 {
+        let resets = matches!(map.reset_type, ResetType::ResetMaxClears | ResetType::ResetRewardsAndClear);
+        parts.push(format!("{} clears{}", map.max_clears, if resets { " per reset" } else { "" }));
+    }
+    if map.cooldown_minutes > 0 {
+        parts.push(format!("{}m cooldown", map.cooldown_minutes));
+    }
+    (!parts.is_empty()).then(|| parts.join(", "))
+}
+
+fn matches_target(target: FarmTarget, reward: Option<RewardTarget>) -> bool {
+    match (target, reward) {
+        (FarmTarget::Item(item_id), Some(RewardTarget::Item(reward_id))) => item_id == reward_id,
+        (FarmTarget::Cat(cat_id), Some(RewardTarget::Cat { cat_id: reward_id, .. })) => cat_id == reward_id,
+        _ => false,
+    }
+}
+
+// Map wide DropItem rows resolve the same way build_drop_index reads them, through drop_chara
+fn map_drops_target(map: &Map, tables: &DropTables, target: FarmTarget) -> bool {
+    let FarmTarget::Cat(cat_id) = target else { return false; };
+    let Some(drop_items) = &map.drop_items else { return false; };
+    drop_items.raw_data.iter().skip(1)
+        .filter_map(|value| value.parse::<u32>().ok())
+        .any(|drop_id| tables.drop_chara.get(&drop_id) == Some(&cat_id))
+}
+
+// Score rewards are one-off, and one-time treasures only count when the map hands its rewards out again
+pub fn rank_stages(registry: &StageRegistry, tables: &DropTables, state: &FarmingState, target: FarmTarget) -> Vec<FarmOption> {
+    let mut options = Vec::new();
+
+    for stage in registry.stages.values() {
+        let Some(map) = registry.maps.get(&stage.map_key()) else { continue; };
+        let Some(magnification) = crown_magnification(map, state.crown) else { continue; };
+
+        // Harder crowns scale the estimated clear time by their magnification
+        let override_seconds = state.clear_time_overrides.get(&stage.id).copied();
+        let estimated_seconds = (state.default_clear_seconds as u64 * magnification as u64 / 100) as u32;
+        let option = |chance: f32, map_drop: bool, amount: u32, limit: Option<String>| FarmOption {
+            stage_key: stage.id.clone(),
+            stage_name: stage.name.clone(),
+            map_name: map.name.clone(),
+            category_name: stage.category_name.clone(),
+            sort_key: index::stage_sort_key(stage),
+            energy: stage.energy,
+            chance,
+            map_drop,
+            amount,
+            clear_seconds: override_seconds.unwrap_or(estimated_seconds).max(1),
+            is_override: override_seconds.is_some(),
+            limit,
+        };
+
+        if map_drops_target(map, tables, target) {
+            let limit = clear_limit(map, false);
+            if !(state.hide_limited && limit.is_some()) {
+                options.push(option(0.0, true, 1, limit));
+            }
+        }
+
+        let RewardStructure::Treasure { drop_rule, drops } = &stage.rewards else { continue; };
+
+        for (drop_index, drop) in drops.iter().enumerate() {
+            if drop.chance == 0 || !matches_target(target, index::reward_target(drop.id, tables)) { continue; }
+
+            let once = *drop_rule == -3 || (*drop_rule == 1 && drop_index == 0);
+            if once && !resets_rewards(&map.reset_type) { continue; }
+
+            let chance = if *drop_rule == -3 || *drop_rule == -4 { 100.0 } else { drop.chance.min(100) as f32 };
+            let limit = clear_limit(map, once);
+            if state.hide_limited && limit.is_some() { continue; }
+
+            options.push(option(chance, false, drop.amount.max(1), limit));
+        }
+    }
+
+    match state.sort {
+        FarmSort::PerEnergy => options.sort_by(|a, b| {
+            let a_value = a.per_energy().unwrap_or(f32::INFINITY);
+            let b_value = b.per_energy().unwrap_or(f32::INFINITY);
+            a.map_drop.cmp(&b.map_drop).then(b_value.total_cmp(&a_value)).then(a.sort_key.cmp(&b.sort_key))
+        }),
+        FarmSort::PerMinute => options.sort_by(|a, b| {
+            a.map_drop.cmp(&b.map_drop).then(b.per_minute().total_cmp(&a.per_minute())).then(a.sort_key.cmp(&b.sort_key))
+        }),
+    }
+
+    options
+}
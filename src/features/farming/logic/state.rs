@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use serde::{Deserialize, Serialize};
+use crate::features::stage::logic::index::DropIndex;
+use crate::features::stage::logic::state::StageListState;
+use super::rank::{self, FarmOption};
+
+pub const DEFAULT_CLEAR_SECONDS: u32 = 120;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FarmTarget {
+    Item(u32),
+    Cat(u32),
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum FarmSort {
+    #[default]
+    PerEnergy,
+    PerMinute,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+pub struct FarmingState {
+    pub search_query: String,
+    pub target: Option<FarmTarget>,
+    pub crown: u8,
+    pub default_clear_seconds: u32,
+    pub clear_time_overrides: HashMap<String, u32>,
+    pub sort: FarmSort,
+    pub hide_limited: bool,
+    #[serde(skip)] pub ranking: RankingCache,
+}
+
+// Everything the ranking depends on besides the stage data
+#[derive(Clone, PartialEq)]
+struct RankingKey {
+    target: FarmTarget,
+    crown: u8,
+    default_clear_seconds: u32,
+    clear_time_overrides: HashMap<String, u32>,
+    sort: FarmSort,
+    hide_limited: bool,
+}
+
+#[derive(Default)]
+pub struct RankingCache {
+    key: Option<RankingKey>,
+    drop_index: Arc<DropIndex>,
+    options: Arc<Vec<FarmOption>>,
+}
+
+impl Default for FarmingState {
+    fn default() -> Self {
+        Self {
+            search_query: String::new(),
+            target: None,
+            crown: 1,
+            default_clear_seconds: DEFAULT_CLEAR_SECONDS,
+            clear_time_overrides: HashMap::new(),
+            sort: FarmSort::default(),
+            hide_limited: false,
+            ranking: RankingCache::default(),
+        }
+    }
+}
+
+impl FarmingState {
+    // Stages are only re-ranked when the target, the energy settings or the stage data change
+    pub fn ranked_options(&mut self, stages: &StageListState, target: FarmTarget) -> Arc<Vec<FarmOption>> {
+        let key = RankingKey {
+            target,
+            crown: self.crown,
+            default_clear_seconds: self.default_clear_seconds,
+            clear_time_overrides: self.clear_time_overrides.clone(),
+            sort: self.sort,
+            hide_limited: self.hide_limited,
+        };
+
+        // The loader swaps in a new drop index whenever the stage registry reloads
+        let is_stale = self.ranking.key.as_ref() != Some(&key) || !Arc::ptr_eq(&self.ranking.drop_index, &stages.drop_index);
+        if is_stale {
+            self.ranking.options = Arc::new(rank::rank_stages(&stages.registry, &stages.drop_tables(), self, target));
+            self.ranking.drop_index = Arc::clone(&stages.drop_index);
+            self.ranking.key = Some(key);
+        }
+        Arc::clone(&self.ranking.options)
+    }
+}
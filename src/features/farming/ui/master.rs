@@ -0,0 +1,167 @@
+use eframe::egui;
+use crate::features::farming::logic::rank;
+use crate::features::farming::logic::state::{FarmSort, FarmTarget, FarmingState};
+use crate::features::items::logic::state::item_name;
+use crate::features::cat::logic::scanner::{self, CatEntry};
+use crate::features::stage::logic::state::StageListState;
+use crate::features::stage::ui::link::stage_link;
+
+// Returns the stage key when a row was clicked
+pub fn show(ctx: &egui::Context, state: &mut FarmingState, stages: &StageListState, cats: &[CatEntry]) -> Option<String> {
+    let mut clicked_stage = None;
+
+    egui::SidePanel::left("farming_target_panel")
+        .resizable(false)
+        .default_width(240.0)
+        .show(ctx, |ui| {
+            render_target_list(ui, state, stages, cats);
+        });
+
+    egui::CentralPanel::default().show(ctx, |ui| {
+        if stages.scan_receiver.is_some() {
+            ui.centered_and_justified(|ui| { ui.spinner(); });
+            return;
+        }
+
+        let Some(target) = state.target else {
+            ui.centered_and_justified(|ui| { ui.label("Pick an item or cat to farm"); });
+            return;
+        };
+
+        ui.heading(target_name(target, stages, cats));
+        render_settings(ui, state);
+        ui.separator();
+
+        let options = state.ranked_options(stages, target);
+        if options.is_empty() {
+            ui.label(egui::RichText::new("No repeatable stage drops match these settings").weak().italics());
+            return;
+        }
+
+        egui::ScrollArea::vertical()
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                clicked_stage = render_options(ui, state, &options);
+            });
+    });
+
+    clicked_stage
+}
+
+fn target_name(target: FarmTarget, stages: &StageListState, cats: &[CatEntry]) -> String {
+    match target {
+        FarmTarget::Item(item_id) => item_name(stages, item_id),
+        FarmTarget::Cat(cat_id) => scanner::find_entry(cats, cat_id)
+            .map(|cat| cat.display_name(0))
+            .unwrap_or_else(|| format!("{:03}", cat_id)),
+    }
+}
+
+fn render_target_list(ui: &mut egui::Ui, state: &mut FarmingState, stages: &StageListState, cats: &[CatEntry]) {
+    ui.add_space(2.5);
+    ui.add(egui::TextEdit::singleline(&mut state.search_query)
+        .hint_text(egui::RichText::new("Search Item or Cat...").color(egui::Color32::GRAY))
+        .desired_width(f32::INFINITY));
+    ui.separator();
+
+    let query = state.search_query.trim().to_lowercase();
+    let mut targets: Vec<(FarmTarget, String)> = stages.drop_index.items.keys().map(|&id| FarmTarget::Item(id))
+        .chain(stages.drop_index.cats.keys().map(|&id| FarmTarget::Cat(id)))
+        .map(|target| (target, target_name(target, stages, cats)))
+        .filter(|(_, name)| query.is_empty() || name.to_lowercase().contains(&query))
+        .collect();
+    targets.sort_by_key(|(target, _)| match *target {
+        FarmTarget::Item(id) => (0, id),
+        FarmTarget::Cat(id) => (1, id),
+    });
+
+    egui::ScrollArea::vertical()
+        .auto_shrink([false, false])
+        .show(ui, |ui| {
+            for (target, name) in targets {
+                let label = match target {
+                    FarmTarget::Item(id) => format!("{:03} {}", id, name),
+                    FarmTarget::Cat(id) => format!("Cat {:03} {}", id, name),
+                };
+                if ui.selectable_label(state.target == Some(target), label).clicked() {
+                    state.target = Some(target);
+                }
+            }
+        });
+}
+
+fn render_settings(ui: &mut egui::Ui, state: &mut FarmingState) {
+    ui.horizontal(|ui| {
+        ui.label("Crown");
+        for crown in 1..=4 {
+            ui.selectable_value(&mut state.crown, crown, crown.to_string());
+        }
+        ui.separator();
+
+        ui.label("Clear time");
+        ui.add(egui::DragValue::new(&mut state.default_clear_seconds).range(1..=3600).suffix("s"));
+        ui.separator();
+
+        ui.label("Sort by");
+        ui.selectable_value(&mut state.sort, FarmSort::PerEnergy, "Per Energy");
+        ui.selectable_value(&mut state.sort, FarmSort::PerMinute, "Per Minute");
+        ui.separator();
+
+        ui.checkbox(&mut state.hide_limited, "Hide limited maps");
+    });
+    ui.label(egui::RichText::new("Clear times scale with the crown magnification unless a stage has its own").weak());
+}
+
+fn render_options(ui: &mut egui::Ui, state: &mut FarmingState, options: &[rank::FarmOption]) -> Option<String> {
+    let mut clicked_stage = None;
+    let mut reset_override = None;
+
+    egui::Grid::new("farming_option_grid")
+        .striped(true)
+        .spacing([12.0, 4.0])
+        .show(ui, |ui| {
+            for header in ["Stage", "Map", "Energy", "Chance", "Amount", "Per Clear", "Per Energy", "Per Minute", "Clear Time", "Limit"] {
+                ui.label(egui::RichText::new(header).strong());
+            }
+            ui.end_row();
+
+            for option in options {
+                let stage_name = if option.stage_name.is_empty() { &option.stage_key } else { &option.stage_name };
+                if stage_link(ui, stage_name) {
+                    clicked_stage = Some(option.stage_key.clone());
+                }
+                ui.label(format!("{} - {}", option.category_name, option.map_name));
+                ui.label(option.energy.to_string());
+                if option.map_drop {
+                    ui.label("Map drop").on_hover_text("Dropped by any stage on the map, the game files don't list a rate");
+                    ui.label(option.amount.to_string());
+                    for _ in 0..3 { ui.label("-"); }
+                } else {
+                    ui.label(format!("{}%", option.chance));
+                    ui.label(option.amount.to_string());
+                    ui.label(format!("{:.2}", option.expected_per_clear()));
+                    ui.label(option.per_energy().map_or("Free".to_string(), |value| format!("{:.4}", value)));
+                    ui.label(format!("{:.3}", option.per_minute()));
+                }
+
+                ui.horizontal(|ui| {
+                    let mut seconds = option.clear_seconds;
+                    if ui.add(egui::DragValue::new(&mut seconds).range(1..=3600).suffix("s")).changed() {
+                        state.clear_time_overrides.insert(option.stage_key.clone(), seconds);
+                    }
+                    if option.is_override && ui.small_button("Reset").clicked() {
+                        reset_override = Some(option.stage_key.clone());
+                    }
+                });
+
+                ui.label(option.limit.as_deref().unwrap_or("-"));
+                ui.end_row();
+            }
+        });
+
+    if let Some(stage_key) = reset_override {
+        state.clear_time_overrides.remove(&stage_key);
+    }
+
+    clicked_stage
+}
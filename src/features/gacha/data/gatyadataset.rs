@@ -0,0 +1,167 @@
+#![allow(dead_code)]
+use std::fs;
+use std::path::Path;
+use std::collections::{BTreeMap, HashMap};
+use serde::{Deserialize, Serialize};
+use crate::global::utils;
+use crate::features::cat::data::unitbuy;
+use crate::features::gacha::paths;
+use super::gatyaevent::{self, BannerRates};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BannerKind {
+    Normal,
+    #[default]
+    Rare,
+    Event,
+}
+
+impl BannerKind {
+    pub const ALL: [BannerKind; 3] = [BannerKind::Normal, BannerKind::Rare, BannerKind::Event];
+
+    pub fn file_prefix(self) -> &'static str {
+        match self {
+            Self::Normal => "N",
+            Self::Rare => "R",
+            Self::Event => "E",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Normal => "Normal",
+            Self::Rare => "Rare",
+            Self::Event => "Event",
+        }
+    }
+
+    pub fn from_event_type(type_id: i32) -> Option<Self> {
+        match type_id {
+            0 => Some(Self::Normal),
+            1 => Some(Self::Rare),
+            4 => Some(Self::Event),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Banner {
+    pub kind: BannerKind,
+    pub id: usize,
+    pub units: Vec<u32>,
+    pub pool: BTreeMap<i32, Vec<u32>>,
+    pub rates: Option<BannerRates>,
+    pub message: String,
+}
+
+impl Banner {
+    pub fn code(&self) -> String {
+        format!("{}{:03}", self.kind.file_prefix(), self.id)
+    }
+
+    pub fn label(&self) -> String {
+        let first_line = self.message.split("<br>").next().unwrap_or("").trim();
+        if first_line.is_empty() {
+            format!("{} Banner {:03}", self.kind.label(), self.id)
+        } else {
+            format!("{} - {}", self.code(), first_line)
+        }
+    }
+
+    pub fn rarity_of(&self, cat_id: u32) -> Option<i32> {
+        self.pool.iter()
+            .find(|(_, units)| units.contains(&cat_id))
+            .map(|(&rarity, _)| rarity)
+    }
+
+    // Chance of one specific unit, the rarity rate is split evenly across its pool
+    pub fn unit_rate(&self, cat_id: u32) -> Option<f32> {
+        let rates = self.rates.as_ref()?;
+        let rarity = self.rarity_of(cat_id)?;
+        let pool_size = self.pool.get(&rarity)?.len();
+        let rarity_rate = rates.rate_for_rarity(rarity)?;
+        if pool_size == 0 { return None; }
+
+        Some(rarity_rate as f32 / gatyaevent::RATE_SCALE * 100.0 / pool_size as f32)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct GachaData {
+    pub banners: Vec<Banner>,
+}
+
+impl GachaData {
+    pub fn banners_of_kind(&self, kind: BannerKind) -> impl DoubleEndedIterator<Item = &Banner> {
+        self.banners.iter().filter(move |banner| banner.kind == kind)
+    }
+
+    pub fn find(&self, kind: BannerKind, id: usize) -> Option<&Banner> {
+        self.banners.iter().find(|banner| banner.kind == kind && banner.id == id)
+    }
+
+    pub fn banners_for_cat(&self, cat_id: u32) -> Vec<&Banner> {
+        self.banners.iter()
+            .filter(|banner| banner.units.contains(&cat_id))
+            .collect()
+    }
+}
+
+pub fn load(gatya_directory: &Path, events_directory: &Path, cats_directory: &Path, priority: &[String]) -> GachaData {
+    let unit_buy = unitbuy::load_unitbuy(cats_directory, priority);
+    let mut rates: HashMap<(BannerKind, usize), (BannerRates, String)> = gatyaevent::load_latest(events_directory, priority)
+        .into_iter()
+        .map(|(kind, block)| ((kind, block.id), (block.rates, block.message)))
+        .collect();
+
+    let mut banners = Vec::new();
+    for kind in BannerKind::ALL {
+        for (id, units) in load_unit_lists(gatya_directory, kind, priority) {
+            let mut pool: BTreeMap<i32, Vec<u32>> = BTreeMap::new();
+            for &cat_id in &units {
+                let rarity = unit_buy.get(&cat_id).map_or(-1, |row| row.rarity);
+                pool.entry(rarity).or_default().push(cat_id);
+            }
+
+            let (banner_rates, message) = match rates.remove(&(kind, id)) {
+                Some((banner_rates, message)) => (Some(banner_rates), message),
+                None => (None, String::new()),
+            };
+
+            banners.push(Banner { kind, id, units, pool, rates: banner_rates, message });
+        }
+    }
+
+    GachaData { banners }
+}
+
+// Each row is one banner, listing unit ids until a -1 terminator
+fn load_unit_lists(gatya_directory: &Path, kind: BannerKind, priority: &[String]) -> Vec<(usize, Vec<u32>)> {
+    let mut lists = Vec::new();
+    let filename = paths::gatya_data_set(kind.file_prefix());
+    let Some(file_path) = crate::global::resolver::get(gatya_directory, [filename.as_str()], priority).into_iter().next() else {
+        return lists;
+    };
+    let Ok(content) = fs::read_to_string(&file_path) else {
+        return lists;
+    };
+
+    let delimiter = utils::detect_csv_separator(&content);
+
+    for (line_index, line) in content.lines().enumerate() {
+        let clean_line = line.split("//").next().unwrap_or("").trim();
+        if clean_line.is_empty() { continue; }
+
+        let units: Vec<u32> = clean_line.split(delimiter)
+            .map(|part| part.trim().parse::<i32>().unwrap_or(-1))
+            .take_while(|&cat_id| cat_id >= 0)
+            .map(|cat_id| cat_id as u32)
+            .collect();
+
+        if units.is_empty() { continue; }
+        lists.push((line_index, units));
+    }
+
+    lists
+}
@@ -0,0 +1,109 @@
+#![allow(dead_code)]
+use std::fs;
+use std::path::Path;
+use super::gatyadataset::BannerKind;
+use crate::features::schedule::paths;
+
+// Line layout: 0-3 start/end date and time, 4-5 version range,
+// 8 banner type, 9 banner count, then one block per banner
+pub const TYPE_INDEX: usize = 8;
+pub const COUNT_INDEX: usize = 9;
+pub const FIRST_BLOCK_INDEX: usize = 10;
+
+// Block layout: 0 banner id, 6..16 (rate, guaranteed) pairs for
+// Normal, Rare, Super Rare, Uber and Legend, 16 banner message
+pub const BLOCK_LENGTH: usize = 17;
+const RATE_OFFSET: usize = 6;
+const MESSAGE_OFFSET: usize = 16;
+
+// Rates are stored out of 10000
+pub const RATE_SCALE: f32 = 10000.0;
+
+// Unit rarity covered by each rate column
+pub const RATE_RARITIES: [i32; 5] = [0, 2, 3, 4, 5];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct BannerRates {
+    pub rates: [u32; 5],
+    pub guaranteed: [bool; 5],
+}
+
+impl BannerRates {
+    pub fn rate_for_rarity(&self, rarity: i32) -> Option<u32> {
+        let column = RATE_RARITIES.iter().position(|&listed| listed == rarity)?;
+        Some(self.rates[column])
+    }
+
+    pub fn guaranteed_rarity(&self) -> Option<i32> {
+        let column = self.guaranteed.iter().rposition(|&flag| flag)?;
+        Some(RATE_RARITIES[column])
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BannerBlock {
+    pub id: usize,
+    pub rates: BannerRates,
+    pub message: String,
+}
+
+pub fn parse_kind(fields: &[&str]) -> Option<BannerKind> {
+    let type_id = fields.get(TYPE_INDEX)?.trim().parse::<i32>().ok()?;
+    BannerKind::from_event_type(type_id)
+}
+
+pub fn parse_blocks(fields: &[&str]) -> Vec<BannerBlock> {
+    let mut blocks = Vec::new();
+    let Some(count) = fields.get(COUNT_INDEX).and_then(|value| value.trim().parse::<usize>().ok()) else {
+        return blocks;
+    };
+
+    for block_index in 0..count {
+        let start = FIRST_BLOCK_INDEX + block_index * BLOCK_LENGTH;
+        let Some(block) = fields.get(start..start + BLOCK_LENGTH) else { break; };
+
+        let Ok(id) = block[0].trim().parse::<i32>() else { continue; };
+        if id < 0 { continue; }
+
+        let mut rates = BannerRates::default();
+        for column in 0..RATE_RARITIES.len() {
+            let value_index = RATE_OFFSET + column * 2;
+            rates.rates[column] = block[value_index].trim().parse::<u32>().unwrap_or(0);
+            rates.guaranteed[column] = block[value_index + 1].trim() == "1";
+        }
+
+        blocks.push(BannerBlock {
+            id: id as usize,
+            rates,
+            message: block[MESSAGE_OFFSET].trim().to_string(),
+        });
+    }
+
+    blocks
+}
+
+// Latest schedule entry wins when a banner runs more than once
+pub fn load_latest(events_directory: &Path, priority: &[String]) -> Vec<(BannerKind, BannerBlock)> {
+    let mut entries = Vec::new();
+    let Some(file_path) = crate::global::resolver::get(events_directory, [paths::GATYA_EVENT], priority).into_iter().next() else {
+        return entries;
+    };
+    let Ok(content) = fs::read_to_string(&file_path) else {
+        return entries;
+    };
+
+    for line in content.lines() {
+        let clean_line = line.trim_end_matches(['\r', '\n']);
+        if clean_line.trim().is_empty() || clean_line.starts_with('[') { continue; }
+
+        let fields: Vec<&str> = clean_line.split('\t').collect();
+        let Some(kind) = parse_kind(&fields) else { continue; };
+
+        for block in parse_blocks(&fields) {
+            entries.retain(|(existing_kind, existing): &(BannerKind, BannerBlock)| *existing_kind != kind || existing.id != block.id);
+            entries.push((kind, block));
+        }
+    }
+
+    entries
+}
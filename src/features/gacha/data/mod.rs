@@ -0,0 +1,2 @@
+pub mod gatyadataset;
+pub mod gatyaevent;
@@ -0,0 +1,11 @@
+use crate::features::cat::ui::icon::DeployIconCache;
+use crate::features::gacha::data::gatyadataset::BannerKind;
+
+#[derive(Default, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct GachaState {
+    pub kind: BannerKind,
+    pub search_query: String,
+    pub selected_banner: Option<usize>,
+    #[serde(skip)] pub icon_cache: DeployIconCache,
+}
@@ -0,0 +1,4 @@
+pub mod paths;
+pub mod data;
+pub mod logic;
+pub mod ui;
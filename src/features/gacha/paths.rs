@@ -0,0 +1,8 @@
+#![allow(dead_code)]
+
+// Directory Constants
+pub const DIR_GATYA: &str = "game/tables/gatya";
+
+pub fn gatya_data_set(prefix: &str) -> String {
+    format!("GatyaDataSet{}1.csv", prefix)
+}
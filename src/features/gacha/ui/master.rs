@@ -0,0 +1,169 @@
+use eframe::egui;
+use crate::features::gacha::logic::state::GachaState;
+use crate::features::gacha::data::gatyadataset::{Banner, BannerKind, GachaData};
+use crate::features::gacha::data::gatyaevent::{self, BannerRates};
+use crate::features::cat::logic::scanner::{self, CatEntry};
+use crate::features::cat::data::unitbuy;
+use crate::features::cat::ui::icon;
+
+const ICON_SIZE: egui::Vec2 = egui::vec2(66.0, 51.0);
+
+// Returns the cat clicked in the pool so the caller can open it
+pub fn show(
+    ctx: &egui::Context,
+    state: &mut GachaState,
+    cats: &[CatEntry],
+    gacha_data: &GachaData,
+) -> Option<(u32, usize)> {
+    let mut clicked_unit = None;
+
+    if gacha_data.banners.is_empty() {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.centered_and_justified(|ui| {
+                ui.vertical_centered(|ui| {
+                    ui.add_space(ui.available_height() * 0.4);
+                    ui.heading("No Data Found");
+                    ui.label(egui::RichText::new("Could not find GatyaDataSet files in game/tables/gatya").color(ui.visuals().weak_text_color()));
+                });
+            });
+        });
+        return None;
+    }
+
+    egui::SidePanel::left("gacha_banner_panel")
+        .resizable(false)
+        .default_width(260.0)
+        .show(ctx, |ui| {
+            render_banner_list(ui, state, cats, gacha_data);
+        });
+
+    egui::CentralPanel::default().show(ctx, |ui| {
+        let Some(banner) = state.selected_banner.and_then(|id| gacha_data.find(state.kind, id)) else {
+            ui.centered_and_justified(|ui| { ui.label("Select a banner to view its pool"); });
+            return;
+        };
+
+        egui::ScrollArea::vertical()
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                clicked_unit = render_banner(ui, ctx, state, cats, banner);
+            });
+    });
+
+    clicked_unit
+}
+
+fn render_banner_list(ui: &mut egui::Ui, state: &mut GachaState, cats: &[CatEntry], gacha_data: &GachaData) {
+    ui.add_space(2.5);
+    ui.horizontal(|ui| {
+        for kind in BannerKind::ALL {
+            if ui.selectable_label(state.kind == kind, kind.label()).clicked() && state.kind != kind {
+                state.kind = kind;
+                state.selected_banner = None;
+            }
+        }
+    });
+
+    ui.add(egui::TextEdit::singleline(&mut state.search_query)
+        .hint_text(egui::RichText::new("Search Banner or Cat...").color(egui::Color32::GRAY))
+        .desired_width(f32::INFINITY));
+    ui.separator();
+
+    let query = state.search_query.to_lowercase();
+
+    egui::ScrollArea::vertical()
+        .auto_shrink([false, false])
+        .show(ui, |ui| {
+            for banner in gacha_data.banners_of_kind(state.kind).rev() {
+                if !query.is_empty() && !matches_query(banner, cats, &query) { continue; }
+
+                let is_selected = state.selected_banner == Some(banner.id);
+                if ui.selectable_label(is_selected, banner.label()).clicked() {
+                    state.selected_banner = Some(banner.id);
+                }
+            }
+        });
+}
+
+fn render_banner(ui: &mut egui::Ui, ctx: &egui::Context, state: &mut GachaState, cats: &[CatEntry], banner: &Banner) -> Option<(u32, usize)> {
+    let mut clicked_unit = None;
+
+    ui.heading(banner.label());
+    for line in banner.message.split("<br>").skip(1).filter(|line| !line.trim().is_empty()) {
+        ui.label(egui::RichText::new(line.trim()).weak());
+    }
+
+    ui.label(format!("{} units", banner.units.len()));
+    match &banner.rates {
+        Some(rates) => render_rates(ui, rates),
+        None => { ui.label(egui::RichText::new("No rate data found in game/tables/events").weak().italics()); },
+    }
+    ui.separator();
+
+    // Highest rarity first
+    for (&rarity, units) in banner.pool.iter().rev() {
+        let rarity_rate = banner.rates.as_ref().and_then(|rates| rates.rate_for_rarity(rarity));
+        let heading = match rarity_rate {
+            Some(rate) if !units.is_empty() => format!(
+                "{} ({} units, {}, {} each)",
+                unitbuy::rarity_name(rarity), units.len(), format_rate(rate as f32), format_percent(rate as f32 / gatyaevent::RATE_SCALE * 100.0 / units.len() as f32)
+            ),
+            _ => format!("{} ({} units)", unitbuy::rarity_name(rarity), units.len()),
+        };
+
+        ui.add_space(5.0);
+        ui.label(egui::RichText::new(heading).strong().size(16.0));
+
+        ui.horizontal_wrapped(|ui| {
+            ui.spacing_mut().item_spacing = egui::vec2(4.0, 4.0);
+            for &cat_id in units {
+                let cat = scanner::find_entry(cats, cat_id);
+                let texture = cat.and_then(|entry| icon::deploy_icon(ctx, &mut state.icon_cache, entry, 0));
+                let fallback = format!("{:03}", cat_id);
+
+                let response = icon::draw_deploy_icon(ui, texture.as_ref(), ICON_SIZE, &fallback);
+                let tooltip = cat.map_or(fallback.clone(), |entry| entry.display_name(0));
+
+                if response.on_hover_text(tooltip).clicked() && cat.is_some() {
+                    clicked_unit = Some((cat_id, 0));
+                }
+            }
+        });
+    }
+
+    clicked_unit
+}
+
+fn render_rates(ui: &mut egui::Ui, rates: &BannerRates) {
+    let guaranteed = rates.guaranteed_rarity();
+
+    ui.horizontal_wrapped(|ui| {
+        for (column, &rarity) in gatyaevent::RATE_RARITIES.iter().enumerate() {
+            let rate = rates.rates[column];
+            if rate == 0 { continue; }
+            ui.label(format!("{}: {}", unitbuy::rarity_name(rarity), format_rate(rate as f32)));
+        }
+        if let Some(rarity) = guaranteed {
+            ui.label(egui::RichText::new(format!("Guaranteed {}", unitbuy::rarity_name(rarity))).color(egui::Color32::GOLD));
+        }
+    });
+}
+
+fn format_rate(rate: f32) -> String {
+    format_percent(rate / gatyaevent::RATE_SCALE * 100.0)
+}
+
+fn format_percent(percent: f32) -> String {
+    let text = format!("{:.3}", percent);
+    format!("{}%", text.trim_end_matches('0').trim_end_matches('.'))
+}
+
+fn matches_query(banner: &Banner, cats: &[CatEntry], query: &str) -> bool {
+    if banner.label().to_lowercase().contains(query) { return true; }
+
+    banner.units.iter().any(|&cat_id| {
+        scanner::find_entry(cats, cat_id).is_some_and(|cat| {
+            cat.names.iter().any(|name| name.to_lowercase().contains(query))
+        })
+    })
+}
@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+use crate::features::items::ui::icon::ItemCache;
+use crate::features::stage::logic::state::StageListState;
+use crate::global::formats::gatyaitemname::GatyaItemName;
+
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+pub struct ItemsState {
+    pub search_query: String,
+    pub selected_item: Option<u32>,
+    pub drops_only: bool,
+    #[serde(skip)] pub icon_cache: ItemCache,
+}
+
+impl Default for ItemsState {
+    fn default() -> Self {
+        Self {
+            search_query: String::new(),
+            selected_item: None,
+            drops_only: true,
+            icon_cache: ItemCache::default(),
+        }
+    }
+}
+
+pub fn item_text(stages: &StageListState, item_id: u32) -> Option<&GatyaItemName> {
+    stages.item_buy_registry.get(&item_id).and_then(|item_buy| stages.item_name_registry.get(&item_buy.row_index))
+}
+
+pub fn item_name(stages: &StageListState, item_id: u32) -> String {
+    item_text(stages, item_id)
+        .map(|item_name| item_name.name.clone())
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| format!("Item {}", item_id))
+}
+
+fn matches_query(stages: &StageListState, item_id: u32, name: &str, query: &str) -> bool {
+    if query.is_empty() || item_id.to_string() == query || name.to_lowercase().contains(query) { return true; }
+
+    let in_description = item_text(stages, item_id)
+        .is_some_and(|text| text.description.iter().any(|line| line.to_lowercase().contains(query)));
+    let in_comment = stages.item_buy_registry.get(&item_id)
+        .is_some_and(|item_buy| item_buy.comment.to_lowercase().contains(query));
+    in_description || in_comment
+}
+
+// Items sorted by id, optionally limited to ones some stage hands out
+pub fn list_items(stages: &StageListState, drops_only: bool, query: &str) -> Vec<(u32, String)> {
+    let query = query.trim().to_lowercase();
+    let mut items: Vec<(u32, String)> = stages.item_buy_registry.keys()
+        .filter(|item_id| !drops_only || stages.drop_index.items.contains_key(item_id))
+        .map(|&item_id| (item_id, item_name(stages, item_id)))
+        .filter(|(item_id, name)| matches_query(stages, *item_id, name, &query))
+        .collect();
+    items.sort_by_key(|(item_id, _)| *item_id);
+    items
+}
@@ -0,0 +1,41 @@
+use eframe::egui;
+use std::collections::HashMap;
+use crate::features::stage::logic::state::StageListState;
+use crate::features::stage::logic::treasure;
+
+pub const ITEM_ICON_SIZE: egui::Vec2 = egui::vec2(32.0, 32.0);
+
+pub struct ItemDisplay {
+    pub name: String,
+    pub texture: Option<egui::TextureHandle>,
+}
+
+pub type ItemCache = HashMap<i32, ItemDisplay>;
+
+// Resolves names and icons through the same tables the stage drops use
+pub fn resolve<'a>(ctx: &egui::Context, cache: &'a mut ItemCache, item_id: i32, stages: &StageListState) -> &'a ItemDisplay {
+    cache.entry(item_id).or_insert_with(|| {
+        let resolved = treasure::resolve_drop(
+            item_id.max(0) as u32,
+            0,
+            &stages.item_buy_registry,
+            &stages.item_name_registry,
+            &stages.drop_chara_registry,
+            &stages.unit_buy_registry,
+            &stages.active_language_priority,
+        );
+
+        let texture = resolved.image_path
+            .and_then(|path| treasure::process_item_icon_texture(&path))
+            .map(|image| ctx.load_texture(format!("item_icon_{}", item_id), image, egui::TextureOptions::LINEAR));
+
+        ItemDisplay { name: resolved.name, texture }
+    })
+}
+
+pub fn draw_item(ui: &mut egui::Ui, item: &ItemDisplay) {
+    match &item.texture {
+        Some(texture) => { ui.add(egui::Image::new(texture).max_size(ITEM_ICON_SIZE)).on_hover_text(&item.name); },
+        None => { ui.add_sized(ITEM_ICON_SIZE, egui::Label::new("?")); },
+    }
+}
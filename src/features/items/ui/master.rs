@@ -0,0 +1,158 @@
+use eframe::egui;
+use crate::features::items::logic::state::{self, ItemsState};
+use crate::features::stage::logic::state::StageListState;
+use crate::features::stage::ui::link::stage_link;
+use super::icon;
+
+// Returns the stage key when a drop source was clicked
+pub fn show(ctx: &egui::Context, state: &mut ItemsState, stages: &StageListState) -> Option<String> {
+    let mut clicked_stage = None;
+
+    if stages.item_buy_registry.is_empty() {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.centered_and_justified(|ui| {
+                ui.vertical_centered(|ui| {
+                    ui.add_space(ui.available_height() * 0.4);
+                    ui.heading("No Data Found");
+                    ui.label(egui::RichText::new("Could not find Gatyaitembuy.csv in game/tables").color(ui.visuals().weak_text_color()));
+                });
+            });
+        });
+        return None;
+    }
+
+    egui::SidePanel::left("items_list_panel")
+        .resizable(false)
+        .default_width(240.0)
+        .show(ctx, |ui| {
+            render_item_list(ui, ctx, state, stages);
+        });
+
+    egui::CentralPanel::default().show(ctx, |ui| {
+        let Some(item_id) = state.selected_item else {
+            ui.centered_and_justified(|ui| { ui.label("Select an Item"); });
+            return;
+        };
+
+        ui.horizontal(|ui| {
+            let item = icon::resolve(ctx, &mut state.icon_cache, item_id as i32, stages);
+            icon::draw_item(ui, item);
+            ui.heading(state::item_name(stages, item_id));
+            ui.label(egui::RichText::new(format!("#{}", item_id)).weak());
+        });
+
+        egui::ScrollArea::vertical()
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                render_details(ui, stages, item_id);
+                ui.separator();
+                ui.label(egui::RichText::new("Stage Drops").strong().size(16.0));
+                clicked_stage = render_sources(ui, stages, item_id);
+            });
+    });
+
+    clicked_stage
+}
+
+fn render_item_list(ui: &mut egui::Ui, ctx: &egui::Context, state: &mut ItemsState, stages: &StageListState) {
+    ui.add_space(2.5);
+    ui.add(egui::TextEdit::singleline(&mut state.search_query)
+        .hint_text(egui::RichText::new("Search Item...").color(egui::Color32::GRAY))
+        .desired_width(f32::INFINITY));
+    ui.checkbox(&mut state.drops_only, "Stage drops only");
+    ui.separator();
+
+    let items = state::list_items(stages, state.drops_only, &state.search_query);
+    let row_height = icon::ITEM_ICON_SIZE.y + ui.spacing().item_spacing.y;
+
+    // Icons only load for rows that scroll into view
+    egui::ScrollArea::vertical()
+        .auto_shrink([false, false])
+        .show_rows(ui, row_height, items.len(), |ui, row_range| {
+            for (item_id, name) in &items[row_range] {
+                ui.horizontal(|ui| {
+                    let item = icon::resolve(ctx, &mut state.icon_cache, *item_id as i32, stages);
+                    icon::draw_item(ui, item);
+
+                    let is_selected = state.selected_item == Some(*item_id);
+                    if ui.selectable_label(is_selected, format!("{:03} {}", item_id, name)).clicked() {
+                        state.selected_item = Some(*item_id);
+                    }
+                });
+            }
+        });
+}
+
+fn render_details(ui: &mut egui::Ui, stages: &StageListState, item_id: u32) {
+    if let Some(text) = state::item_text(stages, item_id) {
+        for line in text.description.iter().filter(|line| !line.trim().is_empty()) {
+            ui.label(line);
+        }
+        ui.add_space(5.0);
+    }
+
+    let Some(item_buy) = stages.item_buy_registry.get(&item_id) else { return; };
+
+    egui::Grid::new("item_detail_grid")
+        .spacing([15.0, 4.0])
+        .show(ui, |ui| {
+            let rows = [
+                // Item rarities and categories have no known names, they don't follow the cat rarities
+                ("Rarity", item_buy.rarity.to_string()),
+                ("Category", item_buy.category.to_string()),
+                ("Price", item_buy.price.to_string()),
+                ("Server ID", item_buy.server_id.to_string()),
+            ];
+            for (label, value) in rows {
+                ui.label(egui::RichText::new(label).strong());
+                ui.label(value);
+                ui.end_row();
+            }
+
+            if !item_buy.comment.is_empty() {
+                ui.label(egui::RichText::new("Comment").strong());
+                ui.label(egui::RichText::new(&item_buy.comment).weak());
+                ui.end_row();
+            }
+        });
+}
+
+fn render_sources(ui: &mut egui::Ui, stages: &StageListState, item_id: u32) -> Option<String> {
+    let sources = stages.drop_index.item_sources(item_id);
+
+    if stages.scan_receiver.is_some() {
+        ui.spinner();
+        return None;
+    }
+    if sources.is_empty() {
+        ui.label(egui::RichText::new("No stage drops this item").weak().italics());
+        return None;
+    }
+
+    let mut clicked_stage = None;
+    ui.label(egui::RichText::new(format!("Dropped in {} stages", sources.len())).weak());
+
+    egui::Grid::new("item_source_grid")
+        .striped(true)
+        .spacing([15.0, 4.0])
+        .show(ui, |ui| {
+            for header in ["Stage", "Map", "Chance", "Amount", "Energy"] {
+                ui.label(egui::RichText::new(header).strong());
+            }
+            ui.end_row();
+
+            for source in sources {
+                let stage_name = if source.stage_name.is_empty() { &source.stage_key } else { &source.stage_name };
+                if stage_link(ui, stage_name) {
+                    clicked_stage = Some(source.stage_key.clone());
+                }
+                ui.label(format!("{} - {}", source.category_name, source.map_name));
+                ui.label(&source.chance);
+                ui.label(source.amount.to_string());
+                ui.label(source.energy.to_string());
+                ui.end_row();
+            }
+        });
+
+    clicked_stage
+}
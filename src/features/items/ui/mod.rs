@@ -0,0 +1,2 @@
+pub mod master;
+pub mod icon;
@@ -0,0 +1,39 @@
+use super::state::{Lineup, LineupSlot, SLOT_COUNT};
+
+// Share codes look like "L:25s.13c.-.-.-.0u.-.-.-.-"
+const CODE_PREFIX: &str = "L:";
+const FORM_CHARS: [char; 4] = ['f', 'c', 's', 'u'];
+
+pub fn encode(lineup: &Lineup) -> String {
+    let slots: Vec<String> = lineup.slots.iter().map(|slot| match slot {
+        Some(unit) => format!("{}{}", unit.cat_id, FORM_CHARS.get(unit.form).copied().unwrap_or('f')),
+        None => "-".to_string(),
+    }).collect();
+
+    // Drop trailing empty slots to keep codes short
+    let used_length = slots.iter().rposition(|slot| slot != "-").map_or(0, |index| index + 1);
+    format!("{}{}", CODE_PREFIX, slots[..used_length].join("."))
+}
+
+pub fn decode(code: &str) -> Option<Lineup> {
+    let body = code.trim().strip_prefix(CODE_PREFIX)?;
+    let mut lineup = Lineup::default();
+
+    if body.is_empty() { return Some(lineup); }
+
+    let parts: Vec<&str> = body.split('.').collect();
+    if parts.len() > SLOT_COUNT { return None; }
+
+    for (slot_index, part) in parts.iter().enumerate() {
+        let part = part.trim();
+        if part == "-" { continue; }
+
+        let form_char = part.chars().last()?;
+        let form = FORM_CHARS.iter().position(|&form_code| form_code == form_char)?;
+        let cat_id = part[..part.len() - 1].parse::<u32>().ok()?;
+
+        lineup.slots[slot_index] = Some(LineupSlot { cat_id, form });
+    }
+
+    Some(lineup)
+}
@@ -0,0 +1,3 @@
+pub mod state;
+pub mod validate;
+pub mod code;
\ No newline at end of file
@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+use crate::features::cat::ui::icon::DeployIconCache;
+
+pub const SLOT_COUNT: usize = 10;
+pub const ROW_SIZE: usize = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LineupSlot {
+    pub cat_id: u32,
+    pub form: usize,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Lineup {
+    pub slots: [Option<LineupSlot>; SLOT_COUNT],
+}
+
+impl Lineup {
+    pub fn units(&self) -> Vec<(u32, usize)> {
+        self.slots.iter().flatten().map(|slot| (slot.cat_id, slot.form)).collect()
+    }
+
+    pub fn contains(&self, cat_id: u32) -> bool {
+        self.slots.iter().flatten().any(|slot| slot.cat_id == cat_id)
+    }
+
+    pub fn next_empty_after(&self, index: usize) -> Option<usize> {
+        (index + 1..SLOT_COUNT).chain(0..=index).find(|&slot_index| self.slots[slot_index].is_none())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedLineup {
+    pub name: String,
+    pub lineup: Lineup,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+pub struct LineupState {
+    pub lineup: Lineup,
+    pub saved_lineups: Vec<SavedLineup>,
+    pub save_name: String,
+    pub selected_slot: usize,
+    pub treasure_chapter: u8,
+    pub selected_stage: Option<String>,
+    pub stage_query: String,
+    pub cat_query: String,
+    pub import_code: String,
+    #[serde(skip)] pub share_status: Option<String>,
+    #[serde(skip)] pub icon_cache: DeployIconCache,
+}
+
+impl Default for LineupState {
+    fn default() -> Self {
+        Self {
+            lineup: Lineup::default(),
+            saved_lineups: Vec::new(),
+            save_name: String::new(),
+            selected_slot: 0,
+            treasure_chapter: 3,
+            selected_stage: None,
+            stage_query: String::new(),
+            cat_query: String::new(),
+            import_code: String::new(),
+            share_status: None,
+            icon_cache: DeployIconCache::new(),
+        }
+    }
+}
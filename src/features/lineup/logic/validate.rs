@@ -0,0 +1,124 @@
+use crate::features::cat::logic::scanner::{find_entry, CatEntry};
+use crate::features::cat::data::unitbuy;
+use crate::features::stage::registry::Stage;
+use crate::features::stage::data::charagroup::CharaGroupType;
+use super::state::{Lineup, ROW_SIZE};
+
+pub struct RestrictionCheck {
+    pub label: &'static str,
+    pub requirement: String,
+    pub violating_slots: Vec<usize>,
+}
+
+impl RestrictionCheck {
+    pub fn passed(&self) -> bool {
+        self.violating_slots.is_empty()
+    }
+}
+
+// Unit files store Chapter 1 costs, each later chapter adds another 50% of it
+pub fn unit_cost(cat: &CatEntry, form: usize, treasure_chapter: u8) -> Option<i32> {
+    let base_cost = cat.stats.get(form)?.as_ref()?.eoc1_cost;
+    let multiplier = 1.0 + 0.5 * treasure_chapter.saturating_sub(1) as f32;
+    Some((base_cost as f32 * multiplier).round() as i32)
+}
+
+pub fn total_cost(lineup: &Lineup, cats: &[CatEntry], treasure_chapter: u8) -> i32 {
+    lineup.slots.iter().flatten()
+        .filter_map(|slot| find_entry(cats, slot.cat_id).and_then(|cat| unit_cost(cat, slot.form, treasure_chapter)))
+        .sum()
+}
+
+pub fn check_stage(lineup: &Lineup, cats: &[CatEntry], stage: &Stage, treasure_chapter: u8) -> Vec<RestrictionCheck> {
+    let mut checks = Vec::new();
+
+    let filled: Vec<(usize, u32, usize)> = lineup.slots.iter().enumerate()
+        .filter_map(|(index, slot)| slot.map(|unit| (index, unit.cat_id, unit.form)))
+        .collect();
+
+    // 1. Rarity
+    if stage.rarity_mask != 0 {
+        let allowed: Vec<&str> = (0..unitbuy::RARITY_NAMES.len())
+            .filter(|&rarity| stage.rarity_mask & (1 << rarity) != 0)
+            .map(|rarity| unitbuy::RARITY_NAMES[rarity])
+            .collect();
+
+        let violating_slots = filled.iter()
+            .filter(|&&(_, cat_id, _)| {
+                find_entry(cats, cat_id).is_some_and(|cat| {
+                    let rarity = cat.unit_buy.rarity;
+                    !(0..8).contains(&rarity) || stage.rarity_mask & (1 << rarity) == 0
+                })
+            })
+            .map(|&(index, _, _)| index)
+            .collect();
+
+        checks.push(RestrictionCheck { label: "Rarity", requirement: allowed.join(", "), violating_slots });
+    }
+
+    // 2. Deploy Limit
+    if stage.deploy_limit != 0 {
+        let violating_slots = filled.iter()
+            .skip(stage.deploy_limit as usize)
+            .map(|&(index, _, _)| index)
+            .collect();
+
+        checks.push(RestrictionCheck { label: "Max Units", requirement: stage.deploy_limit.to_string(), violating_slots });
+    }
+
+    // 3. Rows
+    if stage.allowed_rows != 0 {
+        let allowed_row = stage.allowed_rows as usize - 1;
+        let violating_slots = filled.iter()
+            .filter(|&&(index, _, _)| index / ROW_SIZE != allowed_row)
+            .map(|&(index, _, _)| index)
+            .collect();
+
+        checks.push(RestrictionCheck { label: "Rows", requirement: format!("Row {} only", stage.allowed_rows), violating_slots });
+    }
+
+    // 4. Cost Range
+    if stage.min_cost != 0 || stage.max_cost != 0 {
+        let min_cost = stage.min_cost as i32;
+        let max_cost = if stage.max_cost == 0 { i32::MAX } else { stage.max_cost as i32 };
+
+        let violating_slots = filled.iter()
+            .filter(|&&(_, cat_id, form)| {
+                find_entry(cats, cat_id)
+                    .and_then(|cat| unit_cost(cat, form, treasure_chapter))
+                    .is_some_and(|cost| cost < min_cost || cost > max_cost)
+            })
+            .map(|&(index, _, _)| index)
+            .collect();
+
+        let requirement = if stage.max_cost == 0 {
+            format!("{}¢ or more", stage.min_cost)
+        } else {
+            format!("{}¢ ~ {}¢", stage.min_cost, stage.max_cost)
+        };
+
+        checks.push(RestrictionCheck { label: "Unit Cost", requirement, violating_slots });
+    }
+
+    // 5. Chara Group
+    if let Some(group) = &stage.charagroup {
+        let (label, must_be_listed) = match group.group_type {
+            CharaGroupType::OnlyUse => ("Only Use", true),
+            CharaGroupType::CannotUse => ("Cannot Use", false),
+            CharaGroupType::Unknown(_) => ("Group", true),
+        };
+
+        let violating_slots = filled.iter()
+            .filter(|&&(_, cat_id, _)| group.units.contains(&cat_id) != must_be_listed)
+            .map(|&(index, _, _)| index)
+            .collect();
+
+        let names: Vec<String> = group.units.iter()
+            .map(|&cat_id| find_entry(cats, cat_id).map_or(format!("{:03}", cat_id), |cat| cat.display_name(0)))
+            .collect();
+
+        checks.push(RestrictionCheck { label, requirement: names.join(", "), violating_slots });
+    }
+
+    checks
+}
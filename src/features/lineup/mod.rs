@@ -0,0 +1,2 @@
+pub mod logic;
+pub mod ui;
\ No newline at end of file
@@ -0,0 +1,253 @@
+use eframe::egui;
+use crate::features::lineup::logic::state::{LineupState, LineupSlot, SavedLineup, ROW_SIZE, SLOT_COUNT};
+use crate::features::lineup::logic::{code, validate};
+use crate::features::cat::logic::scanner::{self, CatEntry};
+use crate::features::cat::data::nyancombo::ComboData;
+use crate::features::cat::ui::icon;
+use crate::features::stage::registry::StageRegistry;
+use super::stage;
+
+const SLOT_SIZE: egui::Vec2 = egui::vec2(110.0, 85.0);
+const FORM_LABELS: [&str; 4] = ["Normal", "Evolved", "True", "Ultra"];
+
+pub fn show(
+    ctx: &egui::Context,
+    state: &mut LineupState,
+    cats: &[CatEntry],
+    combo_data: &ComboData,
+    registry: &StageRegistry,
+) {
+    egui::SidePanel::left("lineup_cat_panel")
+        .resizable(false)
+        .default_width(220.0)
+        .show(ctx, |ui| {
+            render_cat_picker(ui, state, cats);
+        });
+
+    egui::CentralPanel::default().show(ctx, |ui| {
+        egui::ScrollArea::vertical()
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                render_slots(ui, ctx, state, cats);
+                ui.add_space(5.0);
+                render_cost_row(ui, state, cats);
+
+                ui.separator();
+                stage::render(ui, state, cats, registry);
+
+                ui.separator();
+                render_combos(ui, state, combo_data);
+
+                ui.separator();
+                render_storage(ui, ctx, state);
+            });
+    });
+}
+
+fn render_cat_picker(ui: &mut egui::Ui, state: &mut LineupState, cats: &[CatEntry]) {
+    ui.add_space(2.5);
+    ui.add(egui::TextEdit::singleline(&mut state.cat_query)
+        .hint_text(egui::RichText::new("Search Cat...").color(egui::Color32::GRAY))
+        .desired_width(f32::INFINITY));
+    ui.separator();
+
+    let query = state.cat_query.to_lowercase();
+
+    egui::ScrollArea::vertical()
+        .auto_shrink([false, false])
+        .show(ui, |ui| {
+            for cat in cats {
+                let max_form = cat.forms.iter().rposition(|&exists| exists).unwrap_or(0);
+                let name = cat.display_name(max_form);
+
+                if !query.is_empty() && !name.to_lowercase().contains(&query) && !cat.base_id_str().contains(&query) {
+                    continue;
+                }
+
+                let is_used = state.lineup.contains(cat.id);
+                let label = format!("{} {}", cat.base_id_str(), name);
+
+                if ui.add_enabled(!is_used, egui::SelectableLabel::new(false, label)).clicked() {
+                    let slot_index = state.selected_slot.min(SLOT_COUNT - 1);
+                    state.lineup.slots[slot_index] = Some(LineupSlot { cat_id: cat.id, form: max_form });
+                    if let Some(next_slot) = state.lineup.next_empty_after(slot_index) {
+                        state.selected_slot = next_slot;
+                    }
+                }
+            }
+        });
+}
+
+fn render_slots(ui: &mut egui::Ui, ctx: &egui::Context, state: &mut LineupState, cats: &[CatEntry]) {
+    ui.heading("Lineup");
+    ui.label(egui::RichText::new("Left click selects a slot, right click clears it").weak());
+    ui.add_space(5.0);
+
+    for row in 0..SLOT_COUNT / ROW_SIZE {
+        ui.horizontal(|ui| {
+            ui.spacing_mut().item_spacing.x = 6.0;
+            for column in 0..ROW_SIZE {
+                let slot_index = row * ROW_SIZE + column;
+                let slot = state.lineup.slots[slot_index];
+                let cat = slot.and_then(|unit| scanner::find_entry(cats, unit.cat_id));
+
+                let texture = match (slot, cat) {
+                    (Some(unit), Some(entry)) => icon::deploy_icon(ctx, &mut state.icon_cache, entry, unit.form),
+                    _ => None,
+                };
+                let fallback = slot.map_or(String::new(), |unit| format!("{:03}", unit.cat_id));
+
+                let response = icon::draw_deploy_icon(ui, texture.as_ref(), SLOT_SIZE, &fallback);
+
+                if state.selected_slot == slot_index {
+                    ui.painter().rect_stroke(response.rect, egui::Rounding::same(4.0), egui::Stroke::new(2.0, egui::Color32::from_rgb(31, 106, 165)));
+                }
+
+                let response = match (slot, cat) {
+                    (Some(unit), Some(entry)) => response.on_hover_text(entry.display_name(unit.form)),
+                    _ => response,
+                };
+
+                if response.clicked() {
+                    state.selected_slot = slot_index;
+                }
+                if response.secondary_clicked() {
+                    state.lineup.slots[slot_index] = None;
+                }
+            }
+        });
+    }
+
+    // Form selector for the selected slot
+    let selected_index = state.selected_slot.min(SLOT_COUNT - 1);
+    let Some(mut unit) = state.lineup.slots[selected_index] else { return; };
+    let Some(cat) = scanner::find_entry(cats, unit.cat_id) else { return; };
+
+    ui.add_space(5.0);
+    ui.horizontal(|ui| {
+        ui.label(format!("Slot {}:", selected_index + 1));
+        for (form_index, label) in FORM_LABELS.iter().enumerate() {
+            if !cat.forms[form_index] { continue; }
+            ui.selectable_value(&mut unit.form, form_index, *label);
+        }
+    });
+    state.lineup.slots[selected_index] = Some(unit);
+}
+
+fn render_cost_row(ui: &mut egui::Ui, state: &mut LineupState, cats: &[CatEntry]) {
+    ui.horizontal(|ui| {
+        ui.label("Treasures:");
+        egui::ComboBox::from_id_salt("lineup_treasure_chapter")
+            .selected_text(format!("Chapter {}", state.treasure_chapter))
+            .show_ui(ui, |ui| {
+                for chapter in 1..=3u8 {
+                    ui.selectable_value(&mut state.treasure_chapter, chapter, format!("Chapter {}", chapter));
+                }
+            });
+
+        let total = validate::total_cost(&state.lineup, cats, state.treasure_chapter);
+        ui.label(egui::RichText::new(format!("Total Cost: {}¢", total)).strong());
+    });
+}
+
+fn render_combos(ui: &mut egui::Ui, state: &LineupState, combo_data: &ComboData) {
+    ui.heading("Active Combos");
+
+    let combos = combo_data.active_combos(&state.lineup.units());
+    if combos.is_empty() {
+        ui.label(egui::RichText::new("No combos active").weak().italics());
+        return;
+    }
+
+    egui::Grid::new("lineup_combo_grid")
+        .striped(true)
+        .spacing([15.0, 4.0])
+        .show(ui, |ui| {
+            for combo in combos {
+                ui.label(&combo.name);
+                ui.label(combo_data.effect_text(combo));
+                ui.end_row();
+            }
+        });
+}
+
+fn render_storage(ui: &mut egui::Ui, ctx: &egui::Context, state: &mut LineupState) {
+    ui.heading("Saved Lineups");
+
+    ui.horizontal(|ui| {
+        ui.add(egui::TextEdit::singleline(&mut state.save_name)
+            .hint_text(egui::RichText::new("Lineup name...").color(egui::Color32::GRAY))
+            .desired_width(200.0));
+
+        let name = state.save_name.trim().to_string();
+        if ui.add_enabled(!name.is_empty(), egui::Button::new("Save")).clicked() {
+            let lineup = state.lineup.clone();
+            match state.saved_lineups.iter_mut().find(|saved| saved.name == name) {
+                Some(existing) => existing.lineup = lineup,
+                None => state.saved_lineups.push(SavedLineup { name, lineup }),
+            }
+            state.save_name.clear();
+        }
+
+        if ui.button("Clear").clicked() {
+            state.lineup = Default::default();
+            state.selected_slot = 0;
+        }
+    });
+
+    let mut load_index = None;
+    let mut delete_index = None;
+
+    egui::Grid::new("lineup_saved_grid")
+        .striped(true)
+        .spacing([15.0, 4.0])
+        .show(ui, |ui| {
+            for (index, saved) in state.saved_lineups.iter().enumerate() {
+                ui.label(&saved.name);
+                ui.label(egui::RichText::new(code::encode(&saved.lineup)).monospace().weak());
+                if ui.button("Load").clicked() { load_index = Some(index); }
+                if ui.button("Delete").clicked() { delete_index = Some(index); }
+                ui.end_row();
+            }
+        });
+
+    if let Some(index) = load_index {
+        state.lineup = state.saved_lineups[index].lineup.clone();
+        state.selected_slot = 0;
+    }
+    if let Some(index) = delete_index {
+        state.saved_lineups.remove(index);
+    }
+
+    ui.add_space(5.0);
+    ui.horizontal(|ui| {
+        let share_code = code::encode(&state.lineup);
+        ui.label(egui::RichText::new(&share_code).monospace());
+        if ui.button("Copy Code").clicked() {
+            ctx.copy_text(share_code);
+            state.share_status = Some("Copied to clipboard".to_string());
+        }
+    });
+
+    ui.horizontal(|ui| {
+        ui.add(egui::TextEdit::singleline(&mut state.import_code)
+            .hint_text(egui::RichText::new("Paste a lineup code...").color(egui::Color32::GRAY))
+            .desired_width(200.0));
+
+        if ui.button("Load Code").clicked() {
+            match code::decode(&state.import_code) {
+                Some(lineup) => {
+                    state.lineup = lineup;
+                    state.selected_slot = 0;
+                    state.import_code.clear();
+                    state.share_status = Some("Lineup loaded".to_string());
+                }
+                None => state.share_status = Some("Invalid lineup code".to_string()),
+            }
+        }
+
+        if let Some(status) = &state.share_status {
+            ui.label(egui::RichText::new(status).weak());
+        }
+    });
+}
@@ -0,0 +1,2 @@
+pub mod master;
+pub mod stage;
\ No newline at end of file
@@ -0,0 +1,85 @@
+use eframe::egui;
+use crate::features::lineup::logic::state::LineupState;
+use crate::features::lineup::logic::validate;
+use crate::features::cat::logic::scanner::CatEntry;
+use crate::features::stage::registry::{Stage, StageRegistry};
+
+const MAX_SEARCH_RESULTS: usize = 30;
+
+pub fn render(ui: &mut egui::Ui, state: &mut LineupState, cats: &[CatEntry], registry: &StageRegistry) {
+    ui.heading("Stage Restrictions");
+
+    ui.horizontal(|ui| {
+        ui.add(egui::TextEdit::singleline(&mut state.stage_query)
+            .hint_text(egui::RichText::new("Search Stage...").color(egui::Color32::GRAY))
+            .desired_width(200.0));
+
+        if state.selected_stage.is_some() && ui.button("Clear Stage").clicked() {
+            state.selected_stage = None;
+        }
+    });
+
+    let query = state.stage_query.trim().to_lowercase();
+    if !query.is_empty() {
+        let mut results: Vec<&Stage> = registry.stages.values()
+            .filter(|stage| stage_label(stage, registry).to_lowercase().contains(&query) || stage.id.to_lowercase().contains(&query))
+            .collect();
+        results.sort_by(|stage_a, stage_b| stage_a.id.cmp(&stage_b.id));
+
+        egui::ScrollArea::vertical()
+            .id_salt("lineup_stage_results")
+            .max_height(180.0)
+            .show(ui, |ui| {
+                for stage in results.into_iter().take(MAX_SEARCH_RESULTS) {
+                    let is_selected = state.selected_stage.as_deref() == Some(stage.id.as_str());
+                    if ui.selectable_label(is_selected, stage_label(stage, registry)).clicked() {
+                        state.selected_stage = Some(stage.id.clone());
+                        state.stage_query.clear();
+                    }
+                }
+            });
+    }
+
+    let Some(stage) = state.selected_stage.as_ref().and_then(|stage_id| registry.stages.get(stage_id)) else {
+        ui.label(egui::RichText::new("No stage selected").weak().italics());
+        return;
+    };
+
+    ui.label(egui::RichText::new(stage_label(stage, registry)).strong());
+
+    let checks = validate::check_stage(&state.lineup, cats, stage, state.treasure_chapter);
+    if checks.is_empty() {
+        ui.label(egui::RichText::new("This stage has no lineup restrictions").color(egui::Color32::LIGHT_GREEN));
+        return;
+    }
+
+    egui::Grid::new("lineup_restriction_grid")
+        .striped(true)
+        .spacing([15.0, 4.0])
+        .show(ui, |ui| {
+            for check in &checks {
+                let (mark, color) = if check.passed() {
+                    ("✔", egui::Color32::LIGHT_GREEN)
+                } else {
+                    ("✖", egui::Color32::from_rgb(230, 80, 80))
+                };
+
+                ui.label(egui::RichText::new(mark).color(color));
+                ui.label(egui::RichText::new(check.label).strong());
+                ui.add(egui::Label::new(&check.requirement).wrap());
+
+                if check.passed() {
+                    ui.label("");
+                } else {
+                    let slots: Vec<String> = check.violating_slots.iter().map(|index| (index + 1).to_string()).collect();
+                    ui.label(egui::RichText::new(format!("Slots {}", slots.join(", "))).color(color));
+                }
+                ui.end_row();
+            }
+        });
+}
+
+fn stage_label(stage: &Stage, registry: &StageRegistry) -> String {
+    let map_name = registry.maps.get(&stage.map_key()).map_or("", |map| map.name.as_str());
+    format!("{} / {} / {}", stage.category_name, map_name, stage.name)
+}
@@ -7,4 +7,12 @@ pub mod statblock;
 pub mod addons;
 pub mod home;
 pub mod mods;
-pub mod stage;
\ No newline at end of file
+pub mod stage;
+pub mod combo;
+pub mod lineup;
+pub mod gacha;
+pub mod schedule;
+pub mod save;
+pub mod planner;
+pub mod items;
+pub mod farming;
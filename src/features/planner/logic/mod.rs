@@ -0,0 +1,3 @@
+pub mod state;
+pub mod upgrade;
+pub mod talent_build;
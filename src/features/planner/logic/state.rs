@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+use super::talent_build::TalentBuild;
+use super::upgrade::{Inventory, UpgradeGoal};
+use crate::features::items::ui::icon::ItemCache;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum PlannerTab {
+    #[default]
+    Upgrades,
+    Talents,
+}
+
+impl PlannerTab {
+    pub const ALL: [PlannerTab; 2] = [PlannerTab::Upgrades, PlannerTab::Talents];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            PlannerTab::Upgrades => "Upgrades",
+            PlannerTab::Talents => "Talents",
+        }
+    }
+}
+
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PlannerState {
+    pub tab: PlannerTab,
+    pub upgrade_goals: Vec<UpgradeGoal>,
+    pub talent_builds: Vec<TalentBuild>,
+    pub inventory: Inventory,
+    pub cat_query: String,
+    pub import_code: String,
+    #[serde(skip)] pub export_status: Option<String>,
+    #[serde(skip)] pub share_status: Option<String>,
+    #[serde(skip)] pub item_cache: ItemCache,
+}
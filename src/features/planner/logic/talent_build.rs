@@ -0,0 +1,223 @@
+use std::collections::{BTreeMap, HashMap};
+use serde::{Deserialize, Serialize};
+use crate::features::cat::data::skillacquisition::TalentGroupRaw;
+use crate::features::cat::logic::scanner::{find_entry, CatEntry};
+use crate::features::cat::logic::stats::{self, CatLevelCurve, CatRaw};
+use crate::features::cat::logic::talents;
+use crate::features::cat::registry::{self, AttrUnit};
+use super::upgrade;
+
+// Share codes look like "T:25=10.10.0.1;143=5.0.10"
+const CODE_PREFIX: &str = "T:";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TalentBuild {
+    pub cat_id: u32,
+    pub levels: BTreeMap<u8, u8>,
+}
+
+impl TalentBuild {
+    pub fn new(cat_id: u32) -> Self {
+        Self { cat_id, levels: BTreeMap::new() }
+    }
+
+    pub fn level_map(&self) -> HashMap<u8, u8> {
+        self.levels.iter().map(|(&slot, &level)| (slot, level)).collect()
+    }
+}
+
+// What a talent's gain is measured in, gains are only compared within the same unit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum GainUnit {
+    StatGrowth,
+    Unlock,
+    Percent,
+    Frames,
+    Range,
+    Count,
+}
+
+impl GainUnit {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::StatGrowth => "Stat growth %",
+            Self::Unlock => "Unlocks",
+            Self::Percent => "Percent points",
+            Self::Frames => "Frames",
+            Self::Range => "Range",
+            Self::Count => "Count",
+        }
+    }
+}
+
+impl From<AttrUnit> for GainUnit {
+    fn from(unit: AttrUnit) -> Self {
+        match unit {
+            AttrUnit::Percent => Self::Percent,
+            AttrUnit::Frames => Self::Frames,
+            AttrUnit::Range => Self::Range,
+            AttrUnit::None => Self::Count,
+        }
+    }
+}
+
+pub struct TalentRank {
+    pub cat_id: u32,
+    pub slot: u8,
+    pub name: String,
+    pub effect: String,
+    pub np: i32,
+    pub unit: GainUnit,
+    pub gain: f32,
+    pub in_build: bool,
+}
+
+impl TalentRank {
+    pub fn gain_per_np(&self) -> f32 {
+        if self.np > 0 { self.gain / self.np as f32 } else { 0.0 }
+    }
+}
+
+pub fn build_np(build: &TalentBuild, cat: &CatEntry) -> i32 {
+    let Some(talent_data) = &cat.talent_data else { return 0; };
+    talents::get_total_np_cost(talent_data, &build.level_map(), &cat.talent_costs)
+}
+
+pub fn total_np(builds: &[TalentBuild], cats: &[CatEntry]) -> i32 {
+    builds.iter()
+        .filter_map(|build| find_entry(cats, build.cat_id).map(|cat| build_np(build, cat)))
+        .sum()
+}
+
+pub fn talent_name(group: &TalentGroupRaw) -> String {
+    registry::get_by_talent_id(group.ability_id)
+        .map(|definition| definition.name.to_string())
+        .unwrap_or_else(|| format!("Talent {}", group.ability_id))
+}
+
+// Ultra talents are read off the Ultra form, everything else off the True form
+fn talent_form(cat: &CatEntry, group: &TalentGroupRaw) -> usize {
+    let wanted = if group.limit == 1 { 3 } else { 2 };
+    (0..=wanted).rev().find(|&form| cat.stats.get(form).is_some_and(|stats| stats.is_some())).unwrap_or(0)
+}
+
+// Every talent is ranked at max level so it can be weighed before it goes into a build
+pub fn rank_talents(builds: &[TalentBuild], cats: &[CatEntry]) -> Vec<TalentRank> {
+    let mut ranks = Vec::new();
+
+    for build in builds {
+        let Some(cat) = find_entry(cats, build.cat_id) else { continue; };
+        let Some(talent_data) = &cat.talent_data else { continue; };
+        let unit_level = upgrade::max_base_level(&cat.unit_buy);
+
+        for (slot, group) in talent_data.groups.iter().enumerate() {
+            let max_level = group.max_level.max(1);
+            let Some(Some(stats)) = cat.stats.get(talent_form(cat, group)) else { continue; };
+
+            let effect = talents::calculate_talent_display(group, stats, max_level, cat.curve.as_ref(), unit_level).unwrap_or_default();
+            let (unit, gain) = talent_gain(group, stats, cat.curve.as_ref(), unit_level);
+            ranks.push(TalentRank {
+                cat_id: cat.id,
+                slot: slot as u8,
+                name: talent_name(group),
+                effect,
+                unit,
+                gain,
+                np: talents::get_talent_np_cost(group.cost_id, max_level, &cat.talent_costs),
+                in_build: build.levels.get(&(slot as u8)).is_some_and(|&level| level > 0),
+            });
+        }
+    }
+
+    ranks.sort_by(|a, b| a.unit.cmp(&b.unit).then(b.gain_per_np().total_cmp(&a.gain_per_np())).then(a.np.cmp(&b.np)));
+    ranks
+}
+
+// Max level gain worked out from the numbers the talent changes. Base stats count their
+// growth in percent, abilities gained from nothing count as one unlock, and the rest count
+// the change in their first changed attribute's unit
+fn talent_gain(group: &TalentGroupRaw, base_stats: &CatRaw, curve: Option<&CatLevelCurve>, unit_level: i32) -> (GainUnit, f32) {
+    let Some(def) = registry::get_by_talent_id(group.ability_id) else { return (GainUnit::Count, 0.0); };
+
+    let max_level = group.max_level.max(1);
+    let val1 = talents::calculate_talent_value(group.min_1, group.max_1, max_level, group.max_level);
+    let val2 = talents::calculate_talent_value(group.min_2, group.max_2, max_level, group.max_level);
+
+    let leveled = stats::apply_level(base_stats, curve, unit_level);
+    let mut boosted = leveled.clone();
+    if let Some(apply) = def.apply_func {
+        apply(&mut boosted, val1, val2, group);
+    }
+
+    let old_attrs = (def.get_attributes)(&leveled);
+    let new_attrs = (def.get_attributes)(&boosted);
+    if !new_attrs.is_empty() {
+        if old_attrs.is_empty() { return (GainUnit::Unlock, 1.0); }
+
+        let changes: Vec<(AttrUnit, i32)> = new_attrs.iter().filter_map(|&(key, new, unit)| {
+            let old = old_attrs.iter().find(|(old_key, _, _)| *old_key == key).map_or(0, |&(_, value, _)| value);
+            (new != old).then_some((unit, (new - old).abs()))
+        }).collect();
+        let Some(&(unit, _)) = changes.first() else { return (GainUnit::Count, 0.0); };
+        let gain = changes.iter().filter(|(other, _)| *other == unit).map(|&(_, change)| change as f32).sum();
+        return (unit.into(), gain);
+    }
+
+    if def.name.starts_with("Resist ") {
+        return (GainUnit::Percent, val1 as f32);
+    }
+
+    if let Some(stat_def) = registry::CAT_STATS_REGISTRY.iter().find(|stat| stat.linked_talent_id == Some(group.ability_id)) {
+        let old = (stat_def.get_value)(&leveled, 0);
+        let new = (stat_def.get_value)(&boosted, 0);
+        if old == 0 { return (GainUnit::StatGrowth, 0.0); }
+        return (GainUnit::StatGrowth, ((new - old) as f32 / old.abs() as f32 * 100.0).abs());
+    }
+
+    (GainUnit::Count, 0.0)
+}
+
+pub fn encode(builds: &[TalentBuild]) -> String {
+    let entries: Vec<String> = builds.iter().map(|build| {
+        let slot_count = build.levels.keys().next_back().map_or(0, |&slot| slot as usize + 1);
+        let levels: Vec<String> = (0..slot_count)
+            .map(|slot| build.levels.get(&(slot as u8)).copied().unwrap_or(0).to_string())
+            .collect();
+        format!("{}={}", build.cat_id, levels.join("."))
+    }).collect();
+
+    format!("{}{}", CODE_PREFIX, entries.join(";"))
+}
+
+pub fn decode(code: &str) -> Option<Vec<TalentBuild>> {
+    let body = code.trim().strip_prefix(CODE_PREFIX)?;
+    let mut builds = Vec::new();
+
+    for entry in body.split(';').map(str::trim).filter(|entry| !entry.is_empty()) {
+        let (cat_id, levels) = entry.split_once('=')?;
+        let mut build = TalentBuild::new(cat_id.trim().parse().ok()?);
+
+        for (slot, level) in levels.split('.').enumerate().filter(|(_, level)| !level.is_empty()) {
+            let level: u8 = level.trim().parse().ok()?;
+            if level > 0 {
+                build.levels.insert(slot as u8, level);
+            }
+        }
+        builds.push(build);
+    }
+
+    Some(builds)
+}
+
+// Clamps imported levels to what the cat actually has
+pub fn sanitize(build: &mut TalentBuild, cat: &CatEntry) {
+    let Some(talent_data) = &cat.talent_data else {
+        build.levels.clear();
+        return;
+    };
+    build.levels.retain(|&slot, level| {
+        let Some(group) = talent_data.groups.get(slot as usize) else { return false; };
+        *level = (*level).min(group.max_level.max(1));
+        *level > 0
+    });
+}
@@ -0,0 +1,193 @@
+use std::collections::{BTreeMap, HashMap};
+use serde::{Deserialize, Serialize};
+use crate::features::cat::logic::scanner::{find_entry, CatEntry};
+use crate::features::cat::logic::talents;
+use crate::features::cat::data::unitbuy::UnitBuyRow;
+
+pub const FORM_LABELS: [&str; 4] = ["Normal", "Evolved", "True", "Ultra"];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpgradeGoal {
+    pub cat_id: u32,
+    pub current_level: i32,
+    pub target_level: i32,
+    pub current_form: usize,
+    pub target_form: usize,
+    pub max_talents: bool,
+}
+
+impl UpgradeGoal {
+    pub fn new(cat: &CatEntry) -> Self {
+        let max_form = cat.forms.iter().rposition(|&exists| exists).unwrap_or(0);
+        Self {
+            cat_id: cat.id,
+            current_level: 1,
+            target_level: max_base_level(&cat.unit_buy),
+            current_form: 0,
+            target_form: max_form,
+            max_talents: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Inventory {
+    pub xp: i64,
+    pub np: i64,
+    pub items: BTreeMap<i32, i64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PlanCost {
+    pub xp: i64,
+    pub np: i64,
+    pub catseye_levels: i64,
+    pub materials: BTreeMap<i32, i64>,
+}
+
+impl PlanCost {
+    pub fn add(&mut self, other: &PlanCost) {
+        self.xp += other.xp;
+        self.np += other.np;
+        self.catseye_levels += other.catseye_levels;
+        for (&item_id, &amount) in &other.materials {
+            *self.materials.entry(item_id).or_insert(0) += amount;
+        }
+    }
+}
+
+pub fn max_base_level(unit_buy: &UnitBuyRow) -> i32 {
+    [unit_buy.level_cap_catseye, unit_buy.level_cap_standard, 1].into_iter().max().unwrap_or(1)
+}
+
+// XP only levels up to the standard cap, anything past that needs catseyes
+pub fn xp_level_cap(unit_buy: &UnitBuyRow) -> i32 {
+    if unit_buy.level_cap_standard > 0 { unit_buy.level_cap_standard } else { max_base_level(unit_buy) }
+}
+
+// upgrade_costs holds the price of each level from 1 to 10, every later ten levels repeat it at double the price
+pub fn level_xp(unit_buy: &UnitBuyRow, from_level: i32, to_level: i32) -> i64 {
+    let cap = xp_level_cap(unit_buy).min(to_level);
+    (from_level.max(1)..cap)
+        .map(|level| {
+            let cost = unit_buy.upgrade_costs.get(((level - 1) % 10) as usize).copied().unwrap_or(0).max(0) as i64;
+            if level > 10 { cost * 2 } else { cost }
+        })
+        .sum()
+}
+
+pub fn goal_cost(goal: &UpgradeGoal, cat: &CatEntry) -> PlanCost {
+    let unit_buy = &cat.unit_buy;
+    let mut cost = PlanCost {
+        xp: level_xp(unit_buy, goal.current_level, goal.target_level),
+        ..Default::default()
+    };
+
+    let catseye_start = goal.current_level.max(xp_level_cap(unit_buy));
+    cost.catseye_levels = (goal.target_level - catseye_start).max(0) as i64;
+
+    let form_steps = [
+        (2, unit_buy.true_form_xp_cost, &unit_buy.true_form_materials),
+        (3, unit_buy.ultra_form_xp_cost, &unit_buy.ultra_form_materials),
+    ];
+    for (form, xp_cost, materials) in form_steps {
+        if goal.current_form >= form || goal.target_form < form { continue; }
+        cost.xp += xp_cost.max(0) as i64;
+        for &(item_id, amount) in materials {
+            *cost.materials.entry(item_id).or_insert(0) += amount as i64;
+        }
+    }
+
+    if goal.max_talents && goal.target_form >= 2 && let Some(talent_data) = &cat.talent_data {
+        // Ultra talents only unlock on the Ultra form
+        let levels: HashMap<u8, u8> = talent_data.groups.iter().enumerate()
+            .filter(|(_, group)| group.limit != 1 || goal.target_form >= 3)
+            .map(|(index, group)| (index as u8, group.max_level.max(1)))
+            .collect();
+        cost.np = talents::get_total_np_cost(talent_data, &levels, &cat.talent_costs) as i64;
+    }
+
+    cost
+}
+
+pub fn total_cost(goals: &[UpgradeGoal], cats: &[CatEntry]) -> PlanCost {
+    let mut total = PlanCost::default();
+    for goal in goals {
+        let Some(cat) = find_entry(cats, goal.cat_id) else { continue; };
+        total.add(&goal_cost(goal, cat));
+    }
+    total
+}
+
+pub fn remaining(needed: i64, owned: i64) -> i64 {
+    (needed - owned).max(0)
+}
+
+pub fn to_csv(goals: &[UpgradeGoal], cats: &[CatEntry], inventory: &Inventory, item_names: &HashMap<i32, String>) -> String {
+    let mut lines = vec!["Cat,Name,From Level,To Level,From Form,To Form,XP,NP,Catseye Levels".to_string()];
+
+    for goal in goals {
+        let Some(cat) = find_entry(cats, goal.cat_id) else { continue; };
+        let cost = goal_cost(goal, cat);
+        lines.push(format!(
+            "{},{},{},{},{},{},{},{},{}",
+            cat.base_id_str(), csv_field(&cat.display_name(goal.target_form)),
+            goal.current_level, goal.target_level,
+            FORM_LABELS[goal.current_form.min(3)], FORM_LABELS[goal.target_form.min(3)],
+            cost.xp, cost.np, cost.catseye_levels,
+        ));
+    }
+
+    let total = total_cost(goals, cats);
+    lines.push(String::new());
+    lines.push("Resource,Needed,Owned,Remaining".to_string());
+    lines.push(format!("XP,{},{},{}", total.xp, inventory.xp, remaining(total.xp, inventory.xp)));
+    lines.push(format!("NP,{},{},{}", total.np, inventory.np, remaining(total.np, inventory.np)));
+
+    for (item_id, &needed) in &total.materials {
+        let owned = inventory.items.get(item_id).copied().unwrap_or(0);
+        let name = item_names.get(item_id).cloned().unwrap_or_else(|| format!("Item {}", item_id));
+        lines.push(format!("{},{},{},{}", csv_field(&name), needed, owned, remaining(needed, owned)));
+    }
+
+    lines.join("\n")
+}
+
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn normal_cat() -> UnitBuyRow {
+        UnitBuyRow {
+            level_cap_standard: 20,
+            upgrade_costs: vec![200, 400, 700, 1100, 1600, 2200, 2900, 3700, 4600, 5600],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn level_xp_matches_game_totals() {
+        let unit_buy = normal_cat();
+        assert_eq!(level_xp(&unit_buy, 1, 2), 200);
+        assert_eq!(level_xp(&unit_buy, 1, 10), 17_400);
+        assert_eq!(level_xp(&unit_buy, 1, 11), 23_000);
+        assert_eq!(level_xp(&unit_buy, 11, 12), 400);
+        assert_eq!(level_xp(&unit_buy, 1, 20), 57_800);
+    }
+
+    #[test]
+    fn level_xp_stops_at_standard_cap() {
+        let unit_buy = normal_cat();
+        assert_eq!(level_xp(&unit_buy, 1, 30), level_xp(&unit_buy, 1, 20));
+        assert_eq!(level_xp(&unit_buy, 20, 30), 0);
+    }
+}
@@ -0,0 +1,71 @@
+use eframe::egui;
+use crate::features::planner::logic::state::{PlannerState, PlannerTab};
+use crate::features::planner::logic::talent_build::TalentBuild;
+use crate::features::planner::logic::upgrade::UpgradeGoal;
+use crate::features::cat::logic::scanner::CatEntry;
+use crate::features::stage::logic::state::StageListState;
+use super::{talents, upgrade};
+
+pub fn show(ctx: &egui::Context, state: &mut PlannerState, cats: &[CatEntry], stages: &StageListState) {
+    egui::SidePanel::left("planner_cat_panel")
+        .resizable(false)
+        .default_width(220.0)
+        .show(ctx, |ui| {
+            render_cat_picker(ui, state, cats);
+        });
+
+    egui::CentralPanel::default().show(ctx, |ui| {
+        ui.horizontal(|ui| {
+            for tab in PlannerTab::ALL {
+                if ui.selectable_label(state.tab == tab, tab.label()).clicked() {
+                    state.tab = tab;
+                }
+            }
+        });
+        ui.separator();
+
+        egui::ScrollArea::vertical()
+            .auto_shrink([false, false])
+            .show(ui, |ui| match state.tab {
+                PlannerTab::Upgrades => upgrade::render(ui, ctx, state, cats, stages),
+                PlannerTab::Talents => talents::render(ui, ctx, state, cats),
+            });
+    });
+}
+
+fn render_cat_picker(ui: &mut egui::Ui, state: &mut PlannerState, cats: &[CatEntry]) {
+    ui.add_space(2.5);
+    ui.add(egui::TextEdit::singleline(&mut state.cat_query)
+        .hint_text(egui::RichText::new("Search Cat...").color(egui::Color32::GRAY))
+        .desired_width(f32::INFINITY));
+    ui.separator();
+
+    let query = state.cat_query.to_lowercase();
+
+    egui::ScrollArea::vertical()
+        .auto_shrink([false, false])
+        .show(ui, |ui| {
+            for cat in cats {
+                if state.tab == PlannerTab::Talents && cat.talent_data.is_none() { continue; }
+                let max_form = cat.forms.iter().rposition(|&exists| exists).unwrap_or(0);
+                let name = cat.display_name(max_form);
+
+                if !query.is_empty() && !name.to_lowercase().contains(&query) && !cat.base_id_str().contains(&query) {
+                    continue;
+                }
+
+                let is_queued = match state.tab {
+                    PlannerTab::Upgrades => state.upgrade_goals.iter().any(|goal| goal.cat_id == cat.id),
+                    PlannerTab::Talents => state.talent_builds.iter().any(|build| build.cat_id == cat.id),
+                };
+                let label = format!("{} {}", cat.base_id_str(), name);
+
+                if ui.add_enabled(!is_queued, egui::SelectableLabel::new(false, label)).clicked() {
+                    match state.tab {
+                        PlannerTab::Upgrades => state.upgrade_goals.push(UpgradeGoal::new(cat)),
+                        PlannerTab::Talents => state.talent_builds.push(TalentBuild::new(cat.id)),
+                    }
+                }
+            }
+        });
+}
@@ -0,0 +1,3 @@
+pub mod master;
+pub mod upgrade;
+pub mod talents;
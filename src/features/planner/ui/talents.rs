@@ -0,0 +1,206 @@
+use eframe::egui;
+use crate::features::planner::logic::state::PlannerState;
+use crate::features::planner::logic::talent_build::{self, TalentRank};
+use crate::features::planner::logic::upgrade;
+use crate::features::cat::logic::scanner::{self, CatEntry};
+use crate::features::cat::logic::talents;
+use crate::global::utils::format_number;
+
+const ULTRA_COLOR: egui::Color32 = egui::Color32::from_rgb(230, 110, 110);
+
+pub fn render(ui: &mut egui::Ui, ctx: &egui::Context, state: &mut PlannerState, cats: &[CatEntry]) {
+    ui.heading("Talent Builds");
+
+    if state.talent_builds.is_empty() {
+        ui.label(egui::RichText::new("Pick cats from the list to plan their talents").weak().italics());
+    } else {
+        render_builds(ui, state, cats);
+        ui.separator();
+        render_budget(ui, state, cats);
+        ui.separator();
+        render_ranking(ui, state, cats);
+    }
+
+    ui.separator();
+    render_share(ui, ctx, state, cats);
+}
+
+fn render_builds(ui: &mut egui::Ui, state: &mut PlannerState, cats: &[CatEntry]) {
+    let mut remove_index = None;
+
+    for (index, build) in state.talent_builds.iter_mut().enumerate() {
+        let Some(cat) = scanner::find_entry(cats, build.cat_id) else { continue; };
+        let Some(talent_data) = &cat.talent_data else { continue; };
+
+        let max_form = cat.forms.iter().rposition(|&exists| exists).unwrap_or(0);
+        let title = format!(
+            "{} {} ({} NP)",
+            cat.base_id_str(), cat.display_name(max_form), format_number(talent_build::build_np(build, cat) as i64),
+        );
+
+        egui::CollapsingHeader::new(title)
+            .id_salt(("planner_talent_build", build.cat_id))
+            .default_open(true)
+            .show(ui, |ui| {
+                egui::Grid::new(("planner_talent_grid", build.cat_id))
+                    .striped(true)
+                    .spacing([12.0, 4.0])
+                    .show(ui, |ui| {
+                        for header in ["Talent", "Level", "NP"] {
+                            ui.label(egui::RichText::new(header).strong());
+                        }
+                        ui.end_row();
+
+                        for (slot, group) in talent_data.groups.iter().enumerate() {
+                            let name = talent_build::talent_name(group);
+                            if group.limit == 1 {
+                                ui.label(egui::RichText::new(format!("{} (Ultra)", name)).color(ULTRA_COLOR));
+                            } else {
+                                ui.label(name);
+                            }
+
+                            let max_level = group.max_level.max(1);
+                            let mut level = build.levels.get(&(slot as u8)).copied().unwrap_or(0);
+                            ui.horizontal(|ui| {
+                                ui.add(egui::DragValue::new(&mut level).range(0..=max_level));
+                                if ui.small_button("Max").clicked() { level = max_level; }
+                                if ui.small_button("0").clicked() { level = 0; }
+                            });
+                            if level > 0 {
+                                build.levels.insert(slot as u8, level);
+                            } else {
+                                build.levels.remove(&(slot as u8));
+                            }
+
+                            let np = talents::get_talent_np_cost(group.cost_id, level, &cat.talent_costs);
+                            ui.label(format_number(np as i64));
+                            ui.end_row();
+                        }
+                    });
+
+                if ui.button("Remove").clicked() {
+                    remove_index = Some(index);
+                }
+            });
+    }
+
+    if let Some(index) = remove_index {
+        state.talent_builds.remove(index);
+    }
+}
+
+fn render_budget(ui: &mut egui::Ui, state: &mut PlannerState, cats: &[CatEntry]) {
+    let total = talent_build::total_np(&state.talent_builds, cats) as i64;
+    let remaining = upgrade::remaining(total, state.inventory.np);
+
+    egui::Grid::new("planner_talent_budget")
+        .spacing([15.0, 4.0])
+        .show(ui, |ui| {
+            ui.label(egui::RichText::new("Total NP").strong());
+            ui.label(format_number(total));
+            ui.end_row();
+
+            ui.label(egui::RichText::new("Owned NP").strong());
+            ui.add(egui::DragValue::new(&mut state.inventory.np).range(0..=i64::MAX).speed(10.0));
+            ui.end_row();
+
+            ui.label(egui::RichText::new("Remaining").strong());
+            let color = if remaining == 0 { egui::Color32::LIGHT_GREEN } else { egui::Color32::from_rgb(230, 80, 80) };
+            ui.label(egui::RichText::new(format_number(remaining)).color(color));
+            ui.end_row();
+        });
+}
+
+fn render_ranking(ui: &mut egui::Ui, state: &mut PlannerState, cats: &[CatEntry]) {
+    ui.heading("Best Value Talents");
+    ui.label(egui::RichText::new("Max level talents ordered by gain per NP within each unit, unplanned rows within the owned NP are marked").weak());
+
+    let ranks = talent_build::rank_talents(&state.talent_builds, cats);
+    let mut add_talent: Option<&TalentRank> = None;
+    let mut spent = 0;
+
+    egui::Grid::new("planner_talent_ranking")
+        .striped(true)
+        .spacing([12.0, 4.0])
+        .show(ui, |ui| {
+            for header in ["", "Cat", "Talent", "NP", "Unit", "Gain / NP", ""] {
+                ui.label(egui::RichText::new(header).strong());
+            }
+            ui.end_row();
+
+            for rank in &ranks {
+                // Talents already in a build are counted in the budget above
+                if !rank.in_build {
+                    spent += rank.np as i64;
+                }
+                let affordable = !rank.in_build && spent <= state.inventory.np;
+                ui.label(if affordable { egui::RichText::new("✔").color(egui::Color32::LIGHT_GREEN) } else { egui::RichText::new("") });
+
+                let name = scanner::find_entry(cats, rank.cat_id)
+                    .map(|cat| cat.display_name(cat.forms.iter().rposition(|&exists| exists).unwrap_or(0)))
+                    .unwrap_or_default();
+                ui.label(format!("{:03} {}", rank.cat_id, name));
+
+                let talent_label = ui.label(&rank.name);
+                if !rank.effect.is_empty() {
+                    talent_label.on_hover_text(&rank.effect);
+                }
+
+                ui.label(format_number(rank.np as i64));
+                ui.label(egui::RichText::new(rank.unit.label()).weak());
+                ui.label(format!("{:.3}", rank.gain_per_np()));
+
+                if rank.in_build {
+                    ui.label(egui::RichText::new("In build").weak());
+                } else if ui.small_button("Add").clicked() {
+                    add_talent = Some(rank);
+                }
+                ui.end_row();
+            }
+        });
+
+    if let Some(rank) = add_talent
+        && let Some(build) = state.talent_builds.iter_mut().find(|build| build.cat_id == rank.cat_id)
+        && let Some(cat) = scanner::find_entry(cats, rank.cat_id)
+        && let Some(group) = cat.talent_data.as_ref().and_then(|data| data.groups.get(rank.slot as usize))
+    {
+        build.levels.insert(rank.slot, group.max_level.max(1));
+    }
+}
+
+fn render_share(ui: &mut egui::Ui, ctx: &egui::Context, state: &mut PlannerState, cats: &[CatEntry]) {
+    ui.horizontal(|ui| {
+        let share_code = talent_build::encode(&state.talent_builds);
+        ui.label(egui::RichText::new(&share_code).monospace());
+        if ui.button("Copy Code").clicked() {
+            ctx.copy_text(share_code);
+            state.share_status = Some("Copied to clipboard".to_string());
+        }
+    });
+
+    ui.horizontal(|ui| {
+        ui.add(egui::TextEdit::singleline(&mut state.import_code)
+            .hint_text(egui::RichText::new("Paste a talent code...").color(egui::Color32::GRAY))
+            .desired_width(200.0));
+
+        if ui.button("Load Code").clicked() {
+            match talent_build::decode(&state.import_code) {
+                Some(mut builds) => {
+                    builds.retain_mut(|build| {
+                        let Some(cat) = scanner::find_entry(cats, build.cat_id) else { return false; };
+                        talent_build::sanitize(build, cat);
+                        cat.talent_data.is_some()
+                    });
+                    state.talent_builds = builds;
+                    state.import_code.clear();
+                    state.share_status = Some("Talent builds loaded".to_string());
+                }
+                None => state.share_status = Some("Invalid talent code".to_string()),
+            }
+        }
+
+        if let Some(status) = &state.share_status {
+            ui.label(egui::RichText::new(status).weak());
+        }
+    });
+}
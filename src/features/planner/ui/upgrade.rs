@@ -0,0 +1,152 @@
+use eframe::egui;
+use std::collections::HashMap;
+use crate::features::planner::logic::state::PlannerState;
+use crate::features::planner::logic::upgrade::{self, FORM_LABELS};
+use crate::features::cat::logic::scanner::{self, CatEntry};
+use crate::features::stage::logic::state::StageListState;
+use crate::features::items::ui::icon;
+use crate::global::utils::format_number;
+
+pub fn render(ui: &mut egui::Ui, ctx: &egui::Context, state: &mut PlannerState, cats: &[CatEntry], stages: &StageListState) {
+    ui.heading("Upgrade Queue");
+
+    if state.upgrade_goals.is_empty() {
+        ui.label(egui::RichText::new("Pick cats from the list to plan their upgrades").weak().italics());
+        return;
+    }
+
+    render_goals(ui, state, cats);
+    ui.separator();
+    render_totals(ui, ctx, state, cats, stages);
+}
+
+fn render_goals(ui: &mut egui::Ui, state: &mut PlannerState, cats: &[CatEntry]) {
+    let mut remove_index = None;
+
+    egui::Grid::new("planner_upgrade_grid")
+        .striped(true)
+        .spacing([12.0, 4.0])
+        .show(ui, |ui| {
+            for header in ["Cat", "Form", "Level", "Talents", "XP", "NP", ""] {
+                ui.label(egui::RichText::new(header).strong());
+            }
+            ui.end_row();
+
+            for (index, goal) in state.upgrade_goals.iter_mut().enumerate() {
+                let Some(cat) = scanner::find_entry(cats, goal.cat_id) else { continue; };
+                let max_level = upgrade::max_base_level(&cat.unit_buy);
+
+                ui.label(format!("{} {}", cat.base_id_str(), cat.display_name(goal.target_form)));
+
+                ui.horizontal(|ui| {
+                    form_selector(ui, ("planner_form_from", index), &mut goal.current_form, cat);
+                    ui.label("→");
+                    form_selector(ui, ("planner_form_to", index), &mut goal.target_form, cat);
+                });
+                goal.target_form = goal.target_form.max(goal.current_form);
+
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut goal.current_level).range(1..=max_level));
+                    ui.label("→");
+                    ui.add(egui::DragValue::new(&mut goal.target_level).range(goal.current_level..=max_level));
+                });
+
+                ui.add_enabled(cat.talent_data.is_some(), egui::Checkbox::new(&mut goal.max_talents, "Max"));
+
+                let cost = upgrade::goal_cost(goal, cat);
+                ui.label(format_number(cost.xp));
+                ui.label(format_number(cost.np));
+
+                if ui.button("Remove").clicked() {
+                    remove_index = Some(index);
+                }
+                ui.end_row();
+            }
+        });
+
+    if let Some(index) = remove_index {
+        state.upgrade_goals.remove(index);
+    }
+}
+
+fn form_selector(ui: &mut egui::Ui, id_salt: (&str, usize), form: &mut usize, cat: &CatEntry) {
+    egui::ComboBox::from_id_salt(id_salt)
+        .selected_text(FORM_LABELS[(*form).min(3)])
+        .width(75.0)
+        .show_ui(ui, |ui| {
+            for (form_index, label) in FORM_LABELS.iter().enumerate() {
+                if !cat.forms[form_index] { continue; }
+                ui.selectable_value(form, form_index, *label);
+            }
+        });
+}
+
+fn render_totals(ui: &mut egui::Ui, ctx: &egui::Context, state: &mut PlannerState, cats: &[CatEntry], stages: &StageListState) {
+    let total = upgrade::total_cost(&state.upgrade_goals, cats);
+
+    ui.heading("Totals");
+    if total.catseye_levels > 0 {
+        ui.label(egui::RichText::new(format!("{} levels past the XP cap need Catseyes", total.catseye_levels)).weak());
+    }
+
+    egui::Grid::new("planner_total_grid")
+        .striped(true)
+        .spacing([15.0, 4.0])
+        .show(ui, |ui| {
+            for header in ["", "Resource", "Needed", "Owned", "Remaining"] {
+                ui.label(egui::RichText::new(header).strong());
+            }
+            ui.end_row();
+
+            resource_row(ui, "XP", total.xp, &mut state.inventory.xp);
+            resource_row(ui, "NP", total.np, &mut state.inventory.np);
+
+            for (&item_id, &needed) in &total.materials {
+                let item = icon::resolve(ctx, &mut state.item_cache, item_id, stages);
+                icon::draw_item(ui, item);
+                let name = item.name.clone();
+
+                let owned = state.inventory.items.entry(item_id).or_insert(0);
+                ui.label(name);
+                ui.label(format_number(needed));
+                ui.add(egui::DragValue::new(owned).range(0..=i64::MAX));
+                remaining_label(ui, needed, *owned);
+                ui.end_row();
+            }
+        });
+
+    ui.add_space(5.0);
+    ui.horizontal(|ui| {
+        if ui.button("Export CSV").clicked() {
+            let item_names: HashMap<i32, String> = total.materials.keys()
+                .map(|&item_id| (item_id, icon::resolve(ctx, &mut state.item_cache, item_id, stages).name.clone()))
+                .collect();
+            let csv = upgrade::to_csv(&state.upgrade_goals, cats, &state.inventory, &item_names);
+
+            if let Some(path) = rfd::FileDialog::new().add_filter("CSV", &["csv"]).set_file_name("upgrade_plan.csv").save_file() {
+                state.export_status = Some(match std::fs::write(&path, csv) {
+                    Ok(()) => format!("Saved to {}", path.display()),
+                    Err(error) => format!("Export failed: {}", error),
+                });
+            }
+        }
+        if let Some(status) = &state.export_status {
+            ui.label(egui::RichText::new(status).weak());
+        }
+    });
+}
+
+fn resource_row(ui: &mut egui::Ui, label: &str, needed: i64, owned: &mut i64) {
+    ui.label("");
+    ui.label(label);
+    ui.label(format_number(needed));
+    ui.add(egui::DragValue::new(owned).range(0..=i64::MAX).speed(100.0));
+    remaining_label(ui, needed, *owned);
+    ui.end_row();
+}
+
+fn remaining_label(ui: &mut egui::Ui, needed: i64, owned: i64) {
+    let remaining = upgrade::remaining(needed, owned);
+    let color = if remaining == 0 { egui::Color32::LIGHT_GREEN } else { egui::Color32::from_rgb(230, 80, 80) };
+    ui.label(egui::RichText::new(format_number(remaining)).color(color));
+}
@@ -0,0 +1,2 @@
+pub mod native;
+pub mod profile;
@@ -0,0 +1,167 @@
+use std::collections::BTreeMap;
+use super::profile::OwnedCat;
+
+// Main story chapters in save order, slot 3 sits unused between Empire of Cats and Into the Future
+const STORY_SLOTS: [usize; 9] = [0, 1, 2, 4, 5, 6, 7, 8, 9];
+const SAVED_CHAPTERS: usize = 10;
+const TREASURE_SLOTS: usize = 49;
+const CHAPTER_STAGES: usize = 48;
+
+const MIN_CATS: u32 = 100;
+const MAX_CATS: u32 = 5000;
+const MAX_ENEMIES: u32 = 2000;
+const MAX_LEVEL: u16 = 200;
+const MIN_TALENT_CATS: u32 = 3;
+const MAX_TALENTS: u32 = 8;
+const MAX_TALENT_LEVEL: u32 = 10;
+
+pub struct NativeSave {
+    pub cats: BTreeMap<u32, OwnedCat>,
+    pub treasures: Vec<Vec<i64>>,
+}
+
+struct CatLists {
+    start: usize,
+    end: usize,
+    unlocked: Vec<bool>,
+    levels: Vec<(u16, u16)>,
+    forms: Vec<u32>,
+}
+
+fn read_u32(data: &[u8], pos: usize) -> Option<u32> {
+    let bytes = data.get(pos..pos + 4)?;
+    Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+fn read_u16(data: &[u8], pos: usize) -> Option<u16> {
+    let bytes = data.get(pos..pos + 2)?;
+    Some(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+// Reads the save without ever writing to it. Fields in front of the cat data move between game
+// versions, so the reader looks for the cat lists by their shape and reads outwards from there
+pub fn read(data: &[u8]) -> Result<NativeSave, String> {
+    // The first field is the game version
+    let lists = (4..data.len())
+        .find_map(|pos| cat_lists(data, pos))
+        .ok_or_else(|| "No cat data found, this doesn't look like a Battle Cats save".to_string())?;
+
+    let talents = find_talents(data, lists.end, lists.unlocked.len());
+
+    let mut cats = BTreeMap::new();
+    for (cat_id, &unlocked) in lists.unlocked.iter().enumerate() {
+        if !unlocked { continue; }
+        let (plus_level, base_level) = lists.levels[cat_id];
+        cats.insert(cat_id as u32, OwnedCat {
+            // Ultra forms are flagged in a later block that isn't read, those cats show their true form
+            form: lists.forms[cat_id].min(3) as usize,
+            // Saves store the base level starting from 0
+            level: base_level as i32 + 1,
+            plus_level: plus_level as i32,
+            talents: talents.get(&(cat_id as u32)).cloned().unwrap_or_default(),
+        });
+    }
+
+    Ok(NativeSave { cats, treasures: read_treasures(data, lists.start) })
+}
+
+// Unlock flags, (plus, base) level pairs and current forms, three lists of the same length back to back
+fn cat_lists(data: &[u8], start: usize) -> Option<CatLists> {
+    let count = read_u32(data, start)?;
+    if !(MIN_CATS..=MAX_CATS).contains(&count) { return None; }
+    let count = count as usize;
+
+    let mut pos = start + 4;
+    let mut unlocked = Vec::with_capacity(count);
+    for _ in 0..count {
+        let flag = read_u32(data, pos)?;
+        if flag > 1 { return None; }
+        unlocked.push(flag == 1);
+        pos += 4;
+    }
+    if !unlocked.contains(&true) || read_u32(data, pos)? as usize != count { return None; }
+    pos += 4;
+
+    let mut levels = Vec::with_capacity(count);
+    for _ in 0..count {
+        let (plus_level, base_level) = (read_u16(data, pos)?, read_u16(data, pos + 2)?);
+        if plus_level > MAX_LEVEL || base_level > MAX_LEVEL { return None; }
+        levels.push((plus_level, base_level));
+        pos += 4;
+    }
+    if read_u32(data, pos)? as usize != count { return None; }
+    pos += 4;
+
+    let mut forms = Vec::with_capacity(count);
+    for _ in 0..count {
+        let form = read_u32(data, pos)?;
+        if form > 3 { return None; }
+        forms.push(form);
+        pos += 4;
+    }
+
+    Some(CatLists { start, end: pos, unlocked, levels, forms })
+}
+
+// The enemy guide sits between the story chapters and the cats, its length leads back to the treasures
+fn read_treasures(data: &[u8], cats_start: usize) -> Vec<Vec<i64>> {
+    let guide_start = (1..=MAX_ENEMIES).find(|&length| {
+        let Some(pos) = cats_start.checked_sub(4 + 4 * length as usize) else { return false; };
+        read_u32(data, pos) == Some(length)
+    }).map(|length| cats_start - 4 - 4 * length as usize);
+
+    let Some(treasure_start) = guide_start.and_then(|end| end.checked_sub(SAVED_CHAPTERS * TREASURE_SLOTS * 4)) else { return Vec::new(); };
+
+    let mut treasures = Vec::with_capacity(STORY_SLOTS.len());
+    for slot in STORY_SLOTS {
+        let chapter_start = treasure_start + slot * TREASURE_SLOTS * 4;
+        let levels: Option<Vec<i64>> = (0..CHAPTER_STAGES)
+            .map(|stage| read_u32(data, chapter_start + stage * 4).filter(|&level| level <= 3).map(i64::from))
+            .collect();
+        // Anything outside the treasure grades means the layout guess was off, better to show nothing
+        let Some(levels) = levels else { return Vec::new(); };
+        treasures.push(levels);
+    }
+    treasures
+}
+
+// Talents are stored per cat as (cat id, count, then id and level pairs), ids and levels in talent order
+fn find_talents(data: &[u8], from: usize, cat_count: usize) -> BTreeMap<u32, Vec<(u8, u8)>> {
+    (from..data.len())
+        .filter_map(|pos| talent_block(data, pos, cat_count))
+        .max_by_key(BTreeMap::len)
+        .unwrap_or_default()
+}
+
+fn talent_block(data: &[u8], start: usize, cat_count: usize) -> Option<BTreeMap<u32, Vec<(u8, u8)>>> {
+    let total = read_u32(data, start)?;
+    if total < MIN_TALENT_CATS || total as usize > cat_count { return None; }
+
+    let mut pos = start + 4;
+    let mut talents = BTreeMap::new();
+    let mut previous_cat = None;
+    let mut has_levels = false;
+
+    for _ in 0..total {
+        let cat_id = read_u32(data, pos)?;
+        let count = read_u32(data, pos + 4)?;
+        if cat_id as usize >= cat_count || previous_cat.is_some_and(|previous| cat_id <= previous) { return None; }
+        if count == 0 || count > MAX_TALENTS { return None; }
+        pos += 8;
+
+        let mut slots: Vec<(u8, u8)> = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let (ability_id, level) = (read_u32(data, pos)?, read_u32(data, pos + 4)?);
+            if ability_id == 0 || ability_id > u8::MAX as u32 || level > MAX_TALENT_LEVEL { return None; }
+            if slots.iter().any(|&(existing, _)| existing as u32 == ability_id) { return None; }
+            has_levels |= level > 0;
+            slots.push((ability_id as u8, level as u8));
+            pos += 8;
+        }
+
+        talents.insert(cat_id, slots);
+        previous_cat = Some(cat_id);
+    }
+
+    has_levels.then_some(talents)
+}
@@ -0,0 +1,125 @@
+use std::path::{Path, PathBuf};
+use std::collections::BTreeMap;
+use serde_json::Value;
+use super::native;
+
+// Treasure level that counts as fully collected
+pub const SUPERIOR_TREASURE: i64 = 3;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OwnedCat {
+    pub form: usize,
+    pub level: i32,
+    pub plus_level: i32,
+    pub talents: Vec<(u8, u8)>,
+}
+
+impl OwnedCat {
+    pub fn total_level(&self) -> i32 {
+        self.level + self.plus_level
+    }
+
+    pub fn level_text(&self) -> String {
+        if self.plus_level > 0 {
+            format!("{}+{}", self.level, self.plus_level)
+        } else {
+            self.level.to_string()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PlayerProfile {
+    pub source: PathBuf,
+    pub cats: BTreeMap<u32, OwnedCat>,
+    // Only summarised in the profile window, stat calculations keep assuming full treasures
+    pub treasures: Vec<Vec<i64>>,
+    pub user_rank: i32,
+}
+
+impl PlayerProfile {
+    pub fn owned(&self, cat_id: u32) -> Option<&OwnedCat> {
+        self.cats.get(&cat_id)
+    }
+
+    pub fn superior_treasures(&self, chapter: usize) -> (usize, usize) {
+        let Some(levels) = self.treasures.get(chapter) else { return (0, 0); };
+        let collected = levels.iter().filter(|&&level| level >= SUPERIOR_TREASURE).count();
+        (collected, levels.len())
+    }
+}
+
+// Reads either the game's own save file or a save editor's JSON export of it
+// The file is only ever opened for reading, nothing is written back
+pub fn load(path: &Path) -> Result<PlayerProfile, String> {
+    let bytes = std::fs::read(path).map_err(|error| format!("Could not open save: {}", error))?;
+
+    if bytes.iter().find(|byte| !byte.is_ascii_whitespace()) == Some(&b'{') {
+        return load_json_export(path, &bytes);
+    }
+
+    let save = native::read(&bytes)?;
+    // Rank is the sum of every unit's level
+    let user_rank = save.cats.values().map(OwnedCat::total_level).sum();
+    Ok(PlayerProfile { source: path.to_path_buf(), cats: save.cats, treasures: save.treasures, user_rank })
+}
+
+// Expected shape: { "cats": { "cats": [ { "unlocked", "current_form", "fourth_form",
+// "upgrade": { "base", "plus" }, "talents": [ { "id", "level" } ] } ] },
+// "treasures": [[level per stage] per chapter], "user_rank" }
+fn load_json_export(path: &Path, bytes: &[u8]) -> Result<PlayerProfile, String> {
+    let root: Value = serde_json::from_slice(bytes)
+        .map_err(|error| format!("File is not a JSON save export: {}", error))?;
+
+    let cat_list = root.get("cats")
+        .and_then(|cats| cats.get("cats").or(Some(cats)))
+        .and_then(Value::as_array)
+        .ok_or_else(|| "Export has no cat list".to_string())?;
+
+    let mut cats = BTreeMap::new();
+    for (cat_id, entry) in cat_list.iter().enumerate() {
+        if !as_flag(entry.get("unlocked")) { continue; }
+
+        let upgrade = entry.get("upgrade");
+        // Saves store the base level starting from 0
+        let level = upgrade.and_then(|upgrade| upgrade.get("base")).and_then(Value::as_i64).unwrap_or(0) as i32 + 1;
+        let plus_level = upgrade.and_then(|upgrade| upgrade.get("plus")).and_then(Value::as_i64).unwrap_or(0) as i32;
+
+        let mut form = entry.get("current_form").and_then(Value::as_u64).unwrap_or(0) as usize;
+        if as_flag(entry.get("fourth_form")) { form = 3; }
+
+        let talents = entry.get("talents")
+            .and_then(Value::as_array)
+            .map(|talents| talents.iter().filter_map(|talent| {
+                let id = talent.get("id")?.as_u64()?;
+                let talent_level = talent.get("level")?.as_u64()?;
+                Some((u8::try_from(id).ok()?, u8::try_from(talent_level).ok()?))
+            }).collect())
+            .unwrap_or_default();
+
+        cats.insert(cat_id as u32, OwnedCat { form: form.min(3), level, plus_level, talents });
+    }
+
+    let treasures = root.get("treasures")
+        .and_then(Value::as_array)
+        .map(|chapters| chapters.iter().map(|chapter| {
+            chapter.as_array().map(|stages| stages.iter().filter_map(Value::as_i64).collect()).unwrap_or_default()
+        }).collect())
+        .unwrap_or_default();
+
+    // Older exports don't carry a rank, it is the sum of all unit levels
+    let user_rank = root.get("user_rank")
+        .and_then(Value::as_i64)
+        .map(|rank| rank as i32)
+        .unwrap_or_else(|| cats.values().map(OwnedCat::total_level).sum());
+
+    Ok(PlayerProfile { source: path.to_path_buf(), cats, treasures, user_rank })
+}
+
+fn as_flag(value: Option<&Value>) -> bool {
+    match value {
+        Some(Value::Bool(flag)) => *flag,
+        Some(Value::Number(number)) => number.as_i64().is_some_and(|number| number > 0),
+        _ => false,
+    }
+}
@@ -0,0 +1,80 @@
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use serde::{Deserialize, Serialize};
+use crate::features::cat::data::skillacquisition::TalentRaw;
+use crate::features::cat::logic::filter::CatFilterState;
+use crate::features::save::data::profile::{self, OwnedCat, PlayerProfile};
+
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProfileState {
+    #[serde(alias = "export_path")]
+    pub save_path: Option<PathBuf>,
+    pub owned_only: bool,
+    #[serde(skip)] pub profile: Option<PlayerProfile>,
+    #[serde(skip)] pub error: Option<String>,
+    #[serde(skip)] pub is_open: bool,
+    #[serde(skip)] pub load_attempted: bool,
+}
+
+impl ProfileState {
+    pub fn load(&mut self, path: PathBuf) {
+        self.load_attempted = true;
+        match profile::load(&path) {
+            Ok(loaded) => {
+                self.profile = Some(loaded);
+                self.error = None;
+            }
+            Err(error) => {
+                self.profile = None;
+                self.error = Some(error);
+            }
+        }
+        self.save_path = Some(path);
+    }
+
+    // Picks the remembered save back up on the first frame
+    pub fn ensure_loaded(&mut self) -> bool {
+        if self.load_attempted { return false; }
+        self.load_attempted = true;
+
+        let Some(path) = self.save_path.clone() else { return false; };
+        self.load(path);
+        self.profile.is_some()
+    }
+
+    pub fn clear(&mut self) {
+        self.save_path = None;
+        self.profile = None;
+        self.error = None;
+    }
+
+    pub fn owned(&self, cat_id: u32) -> Option<&OwnedCat> {
+        self.profile.as_ref()?.owned(cat_id)
+    }
+
+    pub fn sync_filter(&self, filter: &mut CatFilterState) {
+        let owned_ids = match (&self.profile, self.owned_only) {
+            (Some(profile), true) => Some(profile.cats.keys().copied().collect::<HashSet<u32>>()),
+            _ => None,
+        };
+        if filter.owned_ids != owned_ids {
+            filter.owned_ids = owned_ids;
+        }
+    }
+}
+
+// Saves list talents by ability id, the selectors are keyed by talent slot
+pub fn talent_levels(owned: &OwnedCat, talent_data: Option<&TalentRaw>) -> HashMap<u8, u8> {
+    let mut levels = HashMap::new();
+    let Some(talent_data) = talent_data else { return levels; };
+
+    for &(ability_id, level) in &owned.talents {
+        let Some(slot) = talent_data.groups.iter().position(|group| group.ability_id == ability_id) else { continue; };
+        let group = &talent_data.groups[slot];
+
+        levels.insert(slot as u8, level.min(group.max_level.max(1)));
+    }
+
+    levels
+}
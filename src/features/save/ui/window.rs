@@ -0,0 +1,89 @@
+use eframe::egui;
+use crate::features::save::logic::state::ProfileState;
+
+const CHAPTER_NAMES: [&str; 9] = [
+    "EoC 1", "EoC 2", "EoC 3",
+    "ItF 1", "ItF 2", "ItF 3",
+    "CotC 1", "CotC 2", "CotC 3",
+];
+
+// Returns true when the loaded profile or the owned filter changed
+pub fn show(ctx: &egui::Context, state: &mut ProfileState) -> bool {
+    if !state.is_open { return false; }
+
+    let mut changed = false;
+    let mut is_open = state.is_open;
+
+    egui::Window::new("Profile Import")
+        .open(&mut is_open)
+        .collapsible(false)
+        .resizable(false)
+        .default_width(320.0)
+        .show(ctx, |ui| {
+            ui.label(egui::RichText::new("Reads a Battle Cats save file or a save editor's JSON export, the file is never modified").weak());
+            ui.add_space(5.0);
+
+            ui.horizontal(|ui| {
+                if ui.button("Open Save...").clicked()
+                    && let Some(path) = rfd::FileDialog::new()
+                        .add_filter("All Files", &["*"])
+                        .add_filter("JSON Export", &["json"])
+                        .pick_file()
+                {
+                    state.load(path);
+                    changed = true;
+                }
+                if let Some(path) = state.save_path.clone()
+                    && ui.button("Reload").clicked()
+                {
+                    state.load(path);
+                    changed = true;
+                }
+                if state.save_path.is_some() && ui.button("Clear").clicked() {
+                    state.clear();
+                    changed = true;
+                }
+            });
+
+            if let Some(error) = &state.error {
+                ui.label(egui::RichText::new(error).color(egui::Color32::from_rgb(230, 80, 80)));
+            }
+
+            let Some(profile) = &state.profile else {
+                ui.label(egui::RichText::new("No save loaded").weak().italics());
+                return;
+            };
+
+            ui.label(egui::RichText::new(profile.source.display().to_string()).monospace().weak());
+            ui.separator();
+
+            egui::Grid::new("profile_summary_grid")
+                .spacing([15.0, 4.0])
+                .show(ui, |ui| {
+                    ui.label(egui::RichText::new("User Rank").strong());
+                    ui.label(profile.user_rank.to_string());
+                    ui.end_row();
+
+                    ui.label(egui::RichText::new("Owned Cats").strong());
+                    ui.label(profile.cats.len().to_string());
+                    ui.end_row();
+
+                    for (chapter, name) in CHAPTER_NAMES.iter().enumerate() {
+                        let (collected, total) = profile.superior_treasures(chapter);
+                        if total == 0 { continue; }
+                        ui.label(egui::RichText::new(format!("{} Treasures", name)).strong());
+                        ui.label(format!("{} / {} superior", collected, total));
+                        ui.end_row();
+                    }
+                });
+            ui.label(egui::RichText::new("Treasures are shown for reference, stats still assume they're all collected").weak().small());
+
+            ui.separator();
+            if ui.checkbox(&mut state.owned_only, "Only show owned cats").changed() {
+                changed = true;
+            }
+        });
+
+    state.is_open = is_open;
+    changed
+}
@@ -0,0 +1,127 @@
+#![allow(dead_code)]
+use std::fs;
+use std::path::Path;
+use crate::features::gacha::data::gatyadataset::BannerKind;
+use crate::features::gacha::data::gatyaevent::{self, BannerBlock};
+use crate::features::schedule::paths;
+use super::window::{EventWindow, FieldCursor, Recurrence, HEADER_LENGTH};
+
+#[derive(Debug, Clone)]
+pub struct GatyaEvent {
+    pub window: EventWindow,
+    pub kind: BannerKind,
+    pub banners: Vec<BannerBlock>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SaleEvent {
+    pub window: EventWindow,
+    pub recurrence: Recurrence,
+    pub ids: Vec<u32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ItemEvent {
+    pub window: EventWindow,
+    pub recurrence: Recurrence,
+    pub item_id: i32,
+    pub amount: i32,
+    pub title: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct EventSchedule {
+    pub gatya: Vec<GatyaEvent>,
+    pub sales: Vec<SaleEvent>,
+    pub items: Vec<ItemEvent>,
+}
+
+impl EventSchedule {
+    pub fn is_empty(&self) -> bool {
+        self.gatya.is_empty() && self.sales.is_empty() && self.items.is_empty()
+    }
+
+    pub fn gatya_on(&self, date: u32) -> Vec<&GatyaEvent> {
+        self.gatya.iter().filter(|event| event.window.contains_date(date)).collect()
+    }
+
+    pub fn sales_on(&self, date: u32, weekday: u32) -> Vec<&SaleEvent> {
+        self.sales.iter()
+            .filter(|event| event.window.contains_date(date) && event.recurrence.matches(date, weekday))
+            .collect()
+    }
+
+    pub fn items_on(&self, date: u32, weekday: u32) -> Vec<&ItemEvent> {
+        self.items.iter()
+            .filter(|event| event.window.contains_date(date) && event.recurrence.matches(date, weekday))
+            .collect()
+    }
+}
+
+pub fn load(events_directory: &Path, priority: &[String]) -> EventSchedule {
+    EventSchedule {
+        gatya: read_lines(events_directory, paths::GATYA_EVENT, priority, parse_gatya),
+        sales: read_lines(events_directory, paths::SALE_EVENT, priority, parse_sale),
+        items: read_lines(events_directory, paths::ITEM_EVENT, priority, parse_item),
+    }
+}
+
+fn read_lines<T>(events_directory: &Path, filename: &str, priority: &[String], parse: fn(&[&str]) -> Option<T>) -> Vec<T> {
+    let mut entries = Vec::new();
+    let Some(file_path) = crate::global::resolver::get(events_directory, [filename], priority).into_iter().next() else {
+        return entries;
+    };
+    let Ok(content) = fs::read_to_string(&file_path) else {
+        return entries;
+    };
+
+    for line in content.lines() {
+        let clean_line = line.trim_end_matches('\r');
+        // Skip the [start]/[end] markers the server wraps the file in
+        if clean_line.trim().is_empty() || clean_line.starts_with('[') { continue; }
+
+        let fields: Vec<&str> = clean_line.split('\t').collect();
+        if let Some(entry) = parse(&fields) {
+            entries.push(entry);
+        }
+    }
+
+    entries
+}
+
+fn parse_gatya(fields: &[&str]) -> Option<GatyaEvent> {
+    let window = EventWindow::parse(fields)?;
+    let kind = gatyaevent::parse_kind(fields)?;
+    let banners = gatyaevent::parse_blocks(fields);
+    if banners.is_empty() { return None; }
+
+    Some(GatyaEvent { window, kind, banners })
+}
+
+// Layout after the recurrence block: id count, then the ids
+fn parse_sale(fields: &[&str]) -> Option<SaleEvent> {
+    let window = EventWindow::parse(fields)?;
+    let mut cursor = FieldCursor::new(fields, HEADER_LENGTH);
+    let recurrence = cursor.next_recurrence()?;
+
+    let id_count = cursor.next_u32()?;
+    let ids: Vec<u32> = (0..id_count).map_while(|_| cursor.next_u32()).collect();
+    if ids.is_empty() { return None; }
+
+    Some(SaleEvent { window, recurrence, ids })
+}
+
+// Layout after the recurrence block: item id, amount, title, message
+fn parse_item(fields: &[&str]) -> Option<ItemEvent> {
+    let window = EventWindow::parse(fields)?;
+    let mut cursor = FieldCursor::new(fields, HEADER_LENGTH);
+    let recurrence = cursor.next_recurrence()?;
+
+    let item_id = cursor.next_i32()?;
+    let amount = cursor.next_i32().unwrap_or(0);
+    let title = cursor.next_str().unwrap_or("").to_string();
+    let message = cursor.next_str().unwrap_or("").to_string();
+
+    Some(ItemEvent { window, recurrence, item_id, amount, title, message })
+}
@@ -0,0 +1,130 @@
+#![allow(dead_code)]
+
+// Shared line header: 0 start date, 1 start time, 2 end date,
+// 3 end time, 4 min version, 5 max version
+pub const HEADER_LENGTH: usize = 6;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct EventWindow {
+    pub start_date: u32,
+    pub start_time: u32,
+    pub end_date: u32,
+    pub end_time: u32,
+    pub min_version: u32,
+    pub max_version: u32,
+}
+
+impl EventWindow {
+    pub fn parse(fields: &[&str]) -> Option<Self> {
+        if fields.len() < HEADER_LENGTH { return None; }
+        let value = |index: usize| fields[index].trim().parse::<u32>().ok();
+
+        Some(Self {
+            start_date: value(0)?,
+            start_time: value(1).unwrap_or(0),
+            end_date: value(2)?,
+            end_time: value(3).unwrap_or(0),
+            min_version: value(4).unwrap_or(0),
+            max_version: value(5).unwrap_or(0),
+        })
+    }
+
+    // Dates are YYYYMMDD, an end time of 0000 means the event closes before that day
+    pub fn contains_date(&self, date: u32) -> bool {
+        if date < self.start_date { return false; }
+        if self.end_time == 0 { date < self.end_date } else { date <= self.end_date }
+    }
+
+    pub fn overlaps_month(&self, year: i32, month: u32) -> bool {
+        let month_start = year as u32 * 10000 + month * 100 + 1;
+        let month_end = month_start + 30;
+        self.start_date <= month_end && self.end_date >= month_start
+    }
+}
+
+// Optional repeat rules that narrow the window down to specific days
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Recurrence {
+    pub yearly_ranges: Vec<(u32, u32)>,
+    pub month_days: Vec<u32>,
+    pub weekday_mask: u32,
+    pub time_ranges: Vec<(u32, u32)>,
+}
+
+impl Recurrence {
+    pub fn is_empty(&self) -> bool {
+        self.yearly_ranges.is_empty() && self.month_days.is_empty() && self.weekday_mask == 0 && self.time_ranges.is_empty()
+    }
+
+    // Weekdays use bit 0 for Sunday
+    pub fn matches(&self, date: u32, weekday: u32) -> bool {
+        let month_day = date % 10000;
+        let day = date % 100;
+
+        if !self.yearly_ranges.is_empty() && !self.yearly_ranges.iter().any(|&(start, end)| {
+            if start <= end { (start..=end).contains(&month_day) } else { month_day >= start || month_day <= end }
+        }) {
+            return false;
+        }
+        if !self.month_days.is_empty() && !self.month_days.contains(&day) { return false; }
+        if self.weekday_mask != 0 && self.weekday_mask & (1 << weekday) == 0 { return false; }
+
+        true
+    }
+}
+
+pub struct FieldCursor<'a> {
+    fields: &'a [&'a str],
+    index: usize,
+}
+
+impl<'a> FieldCursor<'a> {
+    pub fn new(fields: &'a [&'a str], index: usize) -> Self {
+        Self { fields, index }
+    }
+
+    pub fn next_str(&mut self) -> Option<&'a str> {
+        let field = self.fields.get(self.index)?.trim();
+        self.index += 1;
+        Some(field)
+    }
+
+    pub fn next_u32(&mut self) -> Option<u32> {
+        self.next_str()?.parse::<u32>().ok()
+    }
+
+    pub fn next_i32(&mut self) -> Option<i32> {
+        self.next_str()?.parse::<i32>().ok()
+    }
+
+    // Layout: yearly range count, (start MMDD, start HHMM, end MMDD, end HHMM) per range,
+    // month day count, days, weekday mask, time range count, (start HHMM, end HHMM) per range
+    pub fn next_recurrence(&mut self) -> Option<Recurrence> {
+        let mut recurrence = Recurrence::default();
+
+        let yearly_count = self.next_u32()?;
+        for _ in 0..yearly_count {
+            let start = self.next_u32()?;
+            let _start_time = self.next_u32()?;
+            let end = self.next_u32()?;
+            let _end_time = self.next_u32()?;
+            recurrence.yearly_ranges.push((start, end));
+        }
+
+        let day_count = self.next_u32()?;
+        for _ in 0..day_count {
+            recurrence.month_days.push(self.next_u32()?);
+        }
+
+        recurrence.weekday_mask = self.next_u32()?;
+
+        let time_count = self.next_u32()?;
+        for _ in 0..time_count {
+            let start = self.next_u32()?;
+            let end = self.next_u32()?;
+            recurrence.time_ranges.push((start, end));
+        }
+
+        Some(recurrence)
+    }
+}
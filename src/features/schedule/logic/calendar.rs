@@ -0,0 +1,81 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub const WEEKDAY_NAMES: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+pub const MONTH_NAMES: [&str; 12] = [
+    "January", "February", "March", "April", "May", "June",
+    "July", "August", "September", "October", "November", "December",
+];
+
+pub fn to_date(year: i32, month: u32, day: u32) -> u32 {
+    year as u32 * 10000 + month * 100 + day
+}
+
+pub fn split_date(date: u32) -> (i32, u32, u32) {
+    ((date / 10000) as i32, date / 100 % 100, date % 100)
+}
+
+pub fn today() -> u32 {
+    let seconds = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |duration| duration.as_secs());
+    let (year, month, day) = civil_from_days((seconds / 86_400) as i64);
+    to_date(year, month, day)
+}
+
+// Howard Hinnant's days-to-civil conversion
+fn civil_from_days(days: i64) -> (i32, u32, u32) {
+    let shifted = days + 719_468;
+    let era = shifted.div_euclid(146_097);
+    let day_of_era = shifted.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year as i32, month, day)
+}
+
+pub fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+pub fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// Sakamoto's method, 0 is Sunday
+pub fn weekday(year: i32, month: u32, day: u32) -> u32 {
+    const OFFSETS: [i32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+    let adjusted_year = if month < 3 { year - 1 } else { year };
+    let value = adjusted_year + adjusted_year / 4 - adjusted_year / 100 + adjusted_year / 400
+        + OFFSETS[(month - 1) as usize] + day as i32;
+    value.rem_euclid(7) as u32
+}
+
+pub fn weekday_of(date: u32) -> u32 {
+    let (year, month, day) = split_date(date);
+    weekday(year, month, day)
+}
+
+pub fn shift_month(year: i32, month: u32, offset: i32) -> (i32, u32) {
+    let index = year * 12 + month as i32 - 1 + offset;
+    (index.div_euclid(12), index.rem_euclid(12) as u32 + 1)
+}
+
+pub fn format_date(date: u32) -> String {
+    let (year, month, day) = split_date(date);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+pub fn format_time(time: u32) -> String {
+    format!("{:02}:{:02}", time / 100, time % 100)
+}
+
+// Versions are stored as MMmmpp, e.g. 140100 is 14.1.0
+pub fn format_version(version: u32) -> String {
+    format!("{}.{}.{}", version / 10000, version / 100 % 100, version % 100)
+}
@@ -0,0 +1,46 @@
+use std::path::Path;
+use crate::features::schedule::data::events::{self, EventSchedule};
+use crate::features::schedule::paths;
+use super::calendar;
+
+#[derive(serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct ScheduleState {
+    pub view_year: i32,
+    pub view_month: u32,
+    pub selected_date: u32,
+    pub show_gatya: bool,
+    pub show_sales: bool,
+    pub show_items: bool,
+    #[serde(skip)] pub schedule: EventSchedule,
+}
+
+impl Default for ScheduleState {
+    fn default() -> Self {
+        let today = calendar::today();
+        let (view_year, view_month, _) = calendar::split_date(today);
+
+        Self {
+            view_year,
+            view_month,
+            selected_date: today,
+            show_gatya: true,
+            show_sales: true,
+            show_items: true,
+            schedule: EventSchedule::default(),
+        }
+    }
+}
+
+impl ScheduleState {
+    pub fn reload(&mut self, priority: &[String]) {
+        self.schedule = events::load(Path::new(paths::DIR_EVENTS), priority);
+    }
+
+    pub fn select_date(&mut self, date: u32) {
+        let (year, month, _) = calendar::split_date(date);
+        self.selected_date = date;
+        self.view_year = year;
+        self.view_month = month;
+    }
+}
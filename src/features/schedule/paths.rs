@@ -0,0 +1,9 @@
+#![allow(dead_code)]
+
+// Directory Constants
+pub const DIR_EVENTS: &str = "game/tables/events";
+
+// Event Files
+pub const GATYA_EVENT: &str = "gatya.tsv";
+pub const SALE_EVENT: &str = "sale.tsv";
+pub const ITEM_EVENT: &str = "item.tsv";
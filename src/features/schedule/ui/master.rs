@@ -0,0 +1,275 @@
+use eframe::egui;
+use std::collections::HashMap;
+use crate::features::schedule::logic::state::ScheduleState;
+use crate::features::schedule::logic::calendar;
+use crate::features::schedule::data::window::{EventWindow, Recurrence};
+use crate::features::gacha::data::gatyadataset::{BannerKind, GachaData};
+use crate::features::cat::logic::scanner::{self, CatEntry};
+use crate::features::stage::registry::StageRegistry;
+use crate::global::formats::gatyaitemname::GatyaItemName;
+
+const DAY_CELL_SIZE: egui::Vec2 = egui::vec2(38.0, 34.0);
+
+// Rarities listed as the featured units of a banner
+const FEATURED_MIN_RARITY: i32 = 4;
+
+pub enum ScheduleAction {
+    OpenBanner(BannerKind, usize),
+    OpenCat(u32),
+}
+
+pub fn show(
+    ctx: &egui::Context,
+    state: &mut ScheduleState,
+    cats: &[CatEntry],
+    gacha_data: &GachaData,
+    registry: &StageRegistry,
+    item_names: &HashMap<usize, GatyaItemName>,
+) -> Option<ScheduleAction> {
+    let mut action = None;
+
+    if state.schedule.is_empty() {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.centered_and_justified(|ui| {
+                ui.vertical_centered(|ui| {
+                    ui.add_space(ui.available_height() * 0.4);
+                    ui.heading("No Data Found");
+                    ui.label(egui::RichText::new("Could not find gatya.tsv, sale.tsv or item.tsv in game/tables/events").color(ui.visuals().weak_text_color()));
+                });
+            });
+        });
+        return None;
+    }
+
+    egui::SidePanel::left("schedule_calendar_panel")
+        .resizable(false)
+        .default_width(300.0)
+        .show(ctx, |ui| {
+            render_calendar(ui, state);
+        });
+
+    egui::CentralPanel::default().show(ctx, |ui| {
+        let date = state.selected_date;
+        let weekday = calendar::weekday_of(date);
+        ui.heading(format!("{} ({})", calendar::format_date(date), calendar::WEEKDAY_NAMES[weekday as usize]));
+        ui.separator();
+
+        egui::ScrollArea::vertical()
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                if state.show_gatya {
+                    render_gatya(ui, state, cats, gacha_data, &mut action);
+                }
+                if state.show_sales {
+                    render_sales(ui, state, registry);
+                }
+                if state.show_items {
+                    render_items(ui, state, item_names);
+                }
+            });
+    });
+
+    action
+}
+
+fn render_calendar(ui: &mut egui::Ui, state: &mut ScheduleState) {
+    ui.add_space(2.5);
+    ui.horizontal(|ui| {
+        if ui.button("◀").clicked() {
+            (state.view_year, state.view_month) = calendar::shift_month(state.view_year, state.view_month, -1);
+        }
+        let month_name = calendar::MONTH_NAMES[(state.view_month.clamp(1, 12) - 1) as usize];
+        ui.label(egui::RichText::new(format!("{} {}", month_name, state.view_year)).strong().size(16.0));
+        if ui.button("▶").clicked() {
+            (state.view_year, state.view_month) = calendar::shift_month(state.view_year, state.view_month, 1);
+        }
+        if ui.button("Today").clicked() {
+            state.select_date(calendar::today());
+        }
+    });
+    ui.add_space(5.0);
+
+    let year = state.view_year;
+    let month = state.view_month.clamp(1, 12);
+    let first_weekday = calendar::weekday(year, month, 1);
+    let day_count = calendar::days_in_month(year, month);
+    let today = calendar::today();
+
+    egui::Grid::new("schedule_calendar_grid")
+        .spacing([2.0, 2.0])
+        .show(ui, |ui| {
+            for name in calendar::WEEKDAY_NAMES {
+                ui.label(egui::RichText::new(name).weak());
+            }
+            ui.end_row();
+
+            for _ in 0..first_weekday {
+                ui.label("");
+            }
+
+            for day in 1..=day_count {
+                let date = calendar::to_date(year, month, day);
+                let weekday = (first_weekday + day - 1) % 7;
+                let count = event_count(state, date, weekday);
+
+                let mut text = egui::RichText::new(day.to_string());
+                if date == today { text = text.strong().color(egui::Color32::GOLD); }
+                if count == 0 { text = text.weak(); }
+
+                let response = ui.add_sized(DAY_CELL_SIZE, egui::SelectableLabel::new(state.selected_date == date, text));
+                if count > 0 {
+                    let dot = response.rect.center_bottom() - egui::vec2(0.0, 5.0);
+                    ui.painter().circle_filled(dot, 2.5, egui::Color32::from_rgb(31, 106, 165));
+                }
+                if response.on_hover_text(format!("{} events", count)).clicked() {
+                    state.selected_date = date;
+                }
+
+                if weekday == 6 { ui.end_row(); }
+            }
+        });
+
+    ui.separator();
+    ui.checkbox(&mut state.show_gatya, "Gacha Banners");
+    ui.checkbox(&mut state.show_sales, "Stage Events");
+    ui.checkbox(&mut state.show_items, "Item Events");
+}
+
+fn event_count(state: &ScheduleState, date: u32, weekday: u32) -> usize {
+    let schedule = &state.schedule;
+    let mut count = 0;
+    if state.show_gatya { count += schedule.gatya_on(date).len(); }
+    if state.show_sales { count += schedule.sales_on(date, weekday).len(); }
+    if state.show_items { count += schedule.items_on(date, weekday).len(); }
+    count
+}
+
+fn render_gatya(ui: &mut egui::Ui, state: &ScheduleState, cats: &[CatEntry], gacha_data: &GachaData, action: &mut Option<ScheduleAction>) {
+    let events = state.schedule.gatya_on(state.selected_date);
+    if events.is_empty() { return; }
+
+    ui.label(egui::RichText::new("Gacha Banners").strong().size(18.0));
+
+    for event in events {
+        for block in &event.banners {
+            let banner = gacha_data.find(event.kind, block.id);
+            let title = block.message.split("<br>").next().unwrap_or("").trim();
+            let label = match (banner, title.is_empty()) {
+                (Some(banner), true) => banner.label(),
+                (Some(banner), false) => format!("{} - {}", banner.code(), title),
+                (None, false) => title.to_string(),
+                (None, true) => format!("{} Banner {:03}", event.kind.label(), block.id),
+            };
+
+            ui.group(|ui| {
+                ui.set_width(ui.available_width());
+                if ui.link(egui::RichText::new(label).strong()).clicked() {
+                    *action = Some(ScheduleAction::OpenBanner(event.kind, block.id));
+                }
+                render_window(ui, &event.window, None);
+
+                let Some(banner) = banner else { return; };
+                ui.horizontal_wrapped(|ui| {
+                    ui.label(egui::RichText::new("Featured:").weak());
+                    for (_, units) in banner.pool.range(FEATURED_MIN_RARITY..).rev() {
+                        for &cat_id in units {
+                            let name = scanner::find_entry(cats, cat_id).map_or(format!("{:03}", cat_id), |cat| cat.display_name(0));
+                            if ui.link(name).clicked() {
+                                *action = Some(ScheduleAction::OpenCat(cat_id));
+                            }
+                        }
+                    }
+                });
+            });
+        }
+    }
+    ui.add_space(10.0);
+}
+
+fn render_sales(ui: &mut egui::Ui, state: &ScheduleState, registry: &StageRegistry) {
+    let date = state.selected_date;
+    let events = state.schedule.sales_on(date, calendar::weekday_of(date));
+    if events.is_empty() { return; }
+
+    ui.label(egui::RichText::new("Stage Events").strong().size(18.0));
+
+    for event in events {
+        ui.group(|ui| {
+            ui.set_width(ui.available_width());
+            for &id in &event.ids {
+                match registry.find_map_by_event_id(id) {
+                    Some(map) => {
+                        ui.label(egui::RichText::new(format!("{} / {}", map.category_name, map.name)).strong());
+                        let stage_names: Vec<&str> = map.stages.iter()
+                            .filter_map(|stage_id| registry.stages.get(stage_id))
+                            .map(|stage| stage.name.as_str())
+                            .collect();
+                        if !stage_names.is_empty() {
+                            ui.add(egui::Label::new(egui::RichText::new(stage_names.join(", ")).weak()).wrap());
+                        }
+                    }
+                    None => { ui.label(egui::RichText::new(format!("Event {}", id)).strong()); }
+                }
+            }
+            render_window(ui, &event.window, Some(&event.recurrence));
+        });
+    }
+    ui.add_space(10.0);
+}
+
+fn render_items(ui: &mut egui::Ui, state: &ScheduleState, item_names: &HashMap<usize, GatyaItemName>) {
+    let date = state.selected_date;
+    let events = state.schedule.items_on(date, calendar::weekday_of(date));
+    if events.is_empty() { return; }
+
+    ui.label(egui::RichText::new("Item Events").strong().size(18.0));
+
+    for event in events {
+        let item_name = usize::try_from(event.item_id).ok()
+            .and_then(|id| item_names.get(&id))
+            .map_or(format!("Item {}", event.item_id), |item| item.name.clone());
+
+        ui.group(|ui| {
+            ui.set_width(ui.available_width());
+            ui.label(egui::RichText::new(format!("{} x{}", item_name, event.amount)).strong());
+            if !event.title.is_empty() {
+                ui.label(&event.title);
+            }
+            if !event.message.is_empty() {
+                ui.add(egui::Label::new(egui::RichText::new(event.message.replace("<br>", " ")).weak()).wrap());
+            }
+            render_window(ui, &event.window, Some(&event.recurrence));
+        });
+    }
+}
+
+fn render_window(ui: &mut egui::Ui, window: &EventWindow, recurrence: Option<&Recurrence>) {
+    ui.label(egui::RichText::new(format!(
+        "{} {} ~ {} {}  (v{} ~ v{})",
+        calendar::format_date(window.start_date), calendar::format_time(window.start_time),
+        calendar::format_date(window.end_date), calendar::format_time(window.end_time),
+        calendar::format_version(window.min_version), calendar::format_version(window.max_version),
+    )).weak());
+
+    let Some(recurrence) = recurrence.filter(|recurrence| !recurrence.is_empty()) else { return; };
+
+    let mut parts = Vec::new();
+    if recurrence.weekday_mask != 0 {
+        let days: Vec<&str> = (0..7)
+            .filter(|&weekday| recurrence.weekday_mask & (1 << weekday) != 0)
+            .map(|weekday| calendar::WEEKDAY_NAMES[weekday])
+            .collect();
+        parts.push(days.join("/"));
+    }
+    if !recurrence.month_days.is_empty() {
+        let days: Vec<String> = recurrence.month_days.iter().map(|day| day.to_string()).collect();
+        parts.push(format!("Days {}", days.join(", ")));
+    }
+    for &(start, end) in &recurrence.time_ranges {
+        parts.push(format!("{} ~ {}", calendar::format_time(start), calendar::format_time(end)));
+    }
+
+    if !parts.is_empty() {
+        ui.label(egui::RichText::new(parts.join("  ")).weak());
+    }
+}
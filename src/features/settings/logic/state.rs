@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 use super::lang;
 use super::upd::UpdateMode;
+use crate::features::animation::logic::overlay::OverlayToggles;
+use crate::features::animation::logic::onion::OnionSkin;
+use crate::features::animation::logic::playlist::PlaylistPresets;
 
 #[derive(Serialize, Deserialize, Default)]
 #[serde(default)] 
@@ -111,8 +114,12 @@ pub struct AnimSettings {
     pub last_export_format: i32,
     pub last_export_quality: Option<i32>,
     pub last_export_compression: Option<i32>,
+    pub last_export_supersample: u32,
     pub controls_expanded: bool,
     pub export_popup_open: bool,
+    pub overlays: OverlayToggles,
+    pub onion: OnionSkin,
+    pub playlists: PlaylistPresets,
 }
 
 impl Default for AnimSettings {
@@ -130,8 +137,12 @@ impl Default for AnimSettings {
             last_export_format: 0,
             last_export_quality: None,
             last_export_compression: None,
+            last_export_supersample: 1,
             controls_expanded: true,
             export_popup_open: false,
+            overlays: OverlayToggles::default(),
+            onion: OnionSkin::default(),
+            playlists: PlaylistPresets::default(),
         }
     }
 }
@@ -0,0 +1,45 @@
+use std::fs;
+use std::path::Path;
+use std::collections::HashMap;
+use crate::global::resolver;
+use crate::global::utils::detect_csv_separator;
+
+#[derive(Debug, Clone, Default)]
+pub struct BackgroundDef {
+    pub sky_top: [u8; 3],
+    pub sky_bottom: [u8; 3],
+    pub ground_top: [u8; 3],
+    pub ground_bottom: [u8; 3],
+    pub image_id: u32,
+}
+
+// Rows are "id, sky top rgb, sky bottom rgb, ground top rgb, ground bottom rgb, image id, ..."
+pub fn load(dir_path: &Path, filename: &str, lang_priority: &[String]) -> HashMap<u32, BackgroundDef> {
+    let mut backgrounds = HashMap::new();
+    let file_paths = resolver::get(dir_path, [filename], lang_priority);
+
+    let Some(first_path) = file_paths.first() else { return backgrounds; };
+    let Ok(file_content) = fs::read_to_string(first_path) else { return backgrounds; };
+    let csv_separator = detect_csv_separator(&file_content);
+
+    for line in file_content.lines() {
+        let clean_line = line.split("//").next().unwrap_or("").trim();
+        if clean_line.is_empty() { continue; }
+
+        let values: Vec<u32> = clean_line.split(csv_separator)
+            .map_while(|part| part.trim().parse::<u32>().ok())
+            .collect();
+        if values.len() < 13 { continue; }
+
+        let color = |index: usize| [0, 1, 2].map(|offset| values[index + offset].min(255) as u8);
+        backgrounds.insert(values[0], BackgroundDef {
+            sky_top: color(1),
+            sky_bottom: color(4),
+            ground_top: color(7),
+            ground_bottom: color(10),
+            image_id: values.get(13).copied().unwrap_or(values[0]),
+        });
+    }
+
+    backgrounds
+}
@@ -10,4 +10,5 @@ pub mod stage;
 pub mod stage_option;
 pub mod stagename;
 pub mod difficulty_level;
-pub mod drop_chara;
\ No newline at end of file
+pub mod drop_chara;
+pub mod bg;
\ No newline at end of file
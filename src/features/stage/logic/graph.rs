@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+use crate::features::stage::data::map_name;
+use crate::features::stage::registry::{Map, StageRegistry};
+
+// Story categories where every chapter unlocks the next one
+const SEQUEL_CATEGORIES: [&str; 3] = ["N", "NA", "ND"];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EdgeKind {
+    ExInvasion,
+    Sequel,
+}
+
+impl EdgeKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            EdgeKind::ExInvasion => "EX Invasion",
+            EdgeKind::Sequel => "Next Chapter",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GraphNode {
+    pub map_key: String,
+    pub name: String,
+    pub category: String,
+    pub category_name: String,
+    pub component: usize,
+    pub layer: usize,
+    pub row: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct GraphEdge {
+    pub from: usize,
+    pub to: usize,
+    pub kind: EdgeKind,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct GraphComponent {
+    pub nodes: Vec<usize>,
+    pub layers: usize,
+    pub rows: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct UnlockGraph {
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<GraphEdge>,
+    pub components: Vec<GraphComponent>,
+}
+
+impl UnlockGraph {
+    pub fn find(&self, map_key: &str) -> Option<usize> {
+        self.nodes.iter().position(|node| node.map_key == map_key)
+    }
+
+    pub fn outgoing(&self, node: usize) -> impl Iterator<Item = &GraphEdge> {
+        self.edges.iter().filter(move |edge| edge.from == node)
+    }
+
+    pub fn incoming(&self, node: usize) -> impl Iterator<Item = &GraphEdge> {
+        self.edges.iter().filter(move |edge| edge.to == node)
+    }
+}
+
+// EX_option points at global map ids. Event and continuation maps share a base id,
+// so continuation maps win when both exist
+fn global_map_keys(registry: &StageRegistry) -> HashMap<u32, String> {
+    let mut keys: HashMap<u32, String> = HashMap::new();
+    for map in registry.maps.values() {
+        let Some(global_id) = map_name::get_global_map_id(&map.category, map.map_id) else { continue; };
+        let replace = keys.get(&global_id)
+            .and_then(|key| registry.maps.get(key))
+            .is_none_or(|existing| existing.category != "EX" && map.category == "EX");
+        if replace {
+            keys.insert(global_id, map.id.clone());
+        }
+    }
+    keys
+}
+
+fn map_order(a: &Map, b: &Map) -> std::cmp::Ordering {
+    map_name::get_category_sort_order(&a.category).cmp(&map_name::get_category_sort_order(&b.category))
+        .then_with(|| a.category.cmp(&b.category))
+        .then(a.map_id.cmp(&b.map_id))
+}
+
+pub fn build(registry: &StageRegistry) -> UnlockGraph {
+    let global_keys = global_map_keys(registry);
+    let mut links: Vec<(&str, &str, EdgeKind)> = Vec::new();
+
+    for map in registry.maps.values() {
+        if let Some(target_id) = map.ex_invasion
+            && let Some(target_key) = global_keys.get(&target_id)
+            && target_key != &map.id
+        {
+            links.push((&map.id, target_key, EdgeKind::ExInvasion));
+        }
+    }
+
+    for category in SEQUEL_CATEGORIES {
+        let mut chapters: Vec<&Map> = registry.maps.values().filter(|map| map.category == category).collect();
+        chapters.sort_by_key(|map| map.map_id);
+        for pair in chapters.windows(2) {
+            links.push((&pair[0].id, &pair[1].id, EdgeKind::Sequel));
+        }
+    }
+
+    let mut maps: Vec<&Map> = links.iter()
+        .flat_map(|&(from, to, _)| [from, to])
+        .filter_map(|key| registry.maps.get(key))
+        .collect();
+    maps.sort_by(|a, b| map_order(a, b));
+    maps.dedup_by(|a, b| a.id == b.id);
+
+    let positions: HashMap<&str, usize> = maps.iter().enumerate().map(|(index, map)| (map.id.as_str(), index)).collect();
+    let mut graph = UnlockGraph {
+        nodes: maps.iter().map(|map| GraphNode {
+            map_key: map.id.clone(),
+            name: map.name.clone(),
+            category: map.category.clone(),
+            category_name: map.category_name.clone(),
+            component: 0,
+            layer: 0,
+            row: 0,
+        }).collect(),
+        edges: links.iter().map(|&(from, to, kind)| GraphEdge { from: positions[from], to: positions[to], kind }).collect(),
+        components: Vec::new(),
+    };
+    graph.edges.sort_by_key(|edge| (edge.from, edge.to));
+
+    layout(&mut graph);
+    graph
+}
+
+fn find_root(parents: &mut [usize], node: usize) -> usize {
+    let mut root = node;
+    while parents[root] != root { root = parents[root]; }
+    parents[node] = root;
+    root
+}
+
+// Nodes sit one layer past their deepest parent, connected maps share a band of rows
+fn layout(graph: &mut UnlockGraph) {
+    let count = graph.nodes.len();
+
+    // Capped at the node count so a cycle can't loop forever
+    let mut layers = vec![0usize; count];
+    for _ in 0..count {
+        let mut changed = false;
+        for edge in &graph.edges {
+            if layers[edge.to] <= layers[edge.from] {
+                layers[edge.to] = layers[edge.from] + 1;
+                changed = true;
+            }
+        }
+        if !changed { break; }
+    }
+
+    let mut parents: Vec<usize> = (0..count).collect();
+    for edge in &graph.edges {
+        let (from_root, to_root) = (find_root(&mut parents, edge.from), find_root(&mut parents, edge.to));
+        parents[to_root] = from_root;
+    }
+
+    let mut component_ids: HashMap<usize, usize> = HashMap::new();
+    for node in 0..count {
+        let root = find_root(&mut parents, node);
+        let next_id = component_ids.len();
+        let component = *component_ids.entry(root).or_insert(next_id);
+        if component == graph.components.len() {
+            graph.components.push(GraphComponent::default());
+        }
+
+        let entry = &mut graph.components[component];
+        let row = entry.nodes.iter().filter(|&&other| layers[other] == layers[node]).count();
+        entry.nodes.push(node);
+        entry.layers = entry.layers.max(layers[node] + 1);
+        entry.rows = entry.rows.max(row + 1);
+
+        let graph_node = &mut graph.nodes[node];
+        graph_node.component = component;
+        graph_node.layer = layers[node];
+        graph_node.row = row;
+    }
+}
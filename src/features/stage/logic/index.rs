@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+use crate::features::cat::data::unitbuy::UnitBuyRow;
+use crate::features::stage::data::map_name;
+use crate::features::stage::data::mapstagedata::RewardStructure;
+use crate::features::stage::data::stage::{BossType, EnemyAmount};
+use crate::features::stage::registry::{Stage, StageRegistry};
+use crate::global::formats::gatyaitembuy::GatyaItemBuy;
+use super::treasure;
+
+#[derive(Debug, Clone)]
+pub struct EnemyAppearance {
+    pub stage_key: String,
+    pub category_name: String,
+    pub map_name: String,
+    pub stage_name: String,
+    pub sort_key: (u32, u32, u32),
+    pub magnification: u32,
+    pub atk_magnification: u32,
+    pub boss_type: BossType,
+    pub amount: EnemyAmount,
+}
+
+pub type EnemyIndex = HashMap<u32, Vec<EnemyAppearance>>;
+
+// Orders stages the same way the stage browser lists categories, maps and stages
+pub fn stage_sort_key(stage: &Stage) -> (u32, u32, u32) {
+    (map_name::get_meta(&stage.category).sort_order, stage.map_id, stage.stage_id)
+}
+
+fn map_name(registry: &StageRegistry, stage: &Stage) -> String {
+    registry.maps.get(&stage.map_key()).map(|map| map.name.clone()).unwrap_or_default()
+}
+
+pub fn build_enemy_index(registry: &StageRegistry) -> EnemyIndex {
+    let mut index = EnemyIndex::new();
+
+    for stage in registry.stages.values() {
+        for enemy_line in &stage.enemies {
+            index.entry(enemy_line.id).or_default().push(EnemyAppearance {
+                stage_key: stage.id.clone(),
+                category_name: stage.category_name.clone(),
+                map_name: map_name(registry, stage),
+                stage_name: stage.name.clone(),
+                sort_key: stage_sort_key(stage),
+                magnification: enemy_line.magnification,
+                atk_magnification: enemy_line.atk_magnification,
+                boss_type: enemy_line.boss_type.clone(),
+                amount: enemy_line.amount.clone(),
+            });
+        }
+    }
+
+    for appearances in index.values_mut() {
+        appearances.sort_by_key(|appearance| appearance.sort_key);
+    }
+
+    index
+}
+
+#[derive(Debug, Clone)]
+pub struct DropSource {
+    pub stage_key: String,
+    pub category_name: String,
+    pub map_name: String,
+    pub stage_name: String,
+    pub sort_key: (u32, u32, u32),
+    pub chance: String,
+    pub amount: u32,
+    pub energy: u32,
+    pub form: usize,
+}
+
+#[derive(Default)]
+pub struct DropIndex {
+    pub items: HashMap<u32, Vec<DropSource>>,
+    pub cats: HashMap<u32, Vec<DropSource>>,
+}
+
+impl DropIndex {
+    pub fn item_sources(&self, item_id: u32) -> &[DropSource] {
+        self.items.get(&item_id).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    pub fn cat_sources(&self, cat_id: u32) -> &[DropSource] {
+        self.cats.get(&cat_id).map(Vec::as_slice).unwrap_or_default()
+    }
+}
+
+pub struct DropTables<'a> {
+    pub item_buy: &'a HashMap<u32, GatyaItemBuy>,
+    pub drop_chara: &'a HashMap<u32, u32>,
+    pub true_forms: &'a HashMap<u32, u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RewardTarget {
+    Item(u32),
+    Cat { cat_id: u32, form: usize },
+}
+
+// Reward ids resolve in the same order as treasure::resolve_drop: items, then base cats, then true forms
+pub fn reward_target(reward_id: u32, tables: &DropTables) -> Option<RewardTarget> {
+    if tables.item_buy.contains_key(&reward_id) {
+        return Some(RewardTarget::Item(reward_id));
+    }
+    if let Some(&cat_id) = tables.drop_chara.get(&reward_id) {
+        return Some(RewardTarget::Cat { cat_id, form: 0 });
+    }
+    tables.true_forms.get(&reward_id).map(|&cat_id| RewardTarget::Cat { cat_id, form: 2 })
+}
+
+// Maps each true form reward id back to the cat it belongs to
+pub fn build_true_forms(unit_buy: &HashMap<u32, UnitBuyRow>) -> HashMap<u32, u32> {
+    unit_buy.iter()
+        .filter_map(|(&cat_id, row)| Some((u32::try_from(row.true_form_id).ok()?, cat_id)))
+        .collect()
+}
+
+fn push_reward(index: &mut DropIndex, tables: &DropTables, reward_id: u32, source: DropSource) {
+    match reward_target(reward_id, tables) {
+        Some(RewardTarget::Item(item_id)) => index.items.entry(item_id).or_default().push(source),
+        Some(RewardTarget::Cat { cat_id, form }) => index.cats.entry(cat_id).or_default().push(DropSource { form, ..source }),
+        None => {},
+    }
+}
+
+pub fn build_drop_index(registry: &StageRegistry, tables: &DropTables) -> DropIndex {
+    let mut index = DropIndex::default();
+
+    for stage in registry.stages.values() {
+        let source = |chance: String, amount: u32| DropSource {
+            stage_key: stage.id.clone(),
+            category_name: stage.category_name.clone(),
+            map_name: map_name(registry, stage),
+            stage_name: stage.name.clone(),
+            sort_key: stage_sort_key(stage),
+            chance,
+            amount,
+            energy: stage.energy,
+            form: 0,
+        };
+
+        match &stage.rewards {
+            RewardStructure::Treasure { drop_rule, drops } => {
+                for drop in drops.iter().filter(|drop| drop.chance > 0) {
+                    push_reward(&mut index, tables, drop.id, source(treasure::format_drop_chance(drop.chance, *drop_rule), drop.amount));
+                }
+            },
+            RewardStructure::Timed(scores) => {
+                for score in scores {
+                    push_reward(&mut index, tables, score.id, source(format!("{} pts", score.score), score.amount));
+                }
+            },
+            RewardStructure::None => {},
+        }
+
+        // Map wide DropItem rows only count when they point at a drop_chara unit
+        let Some(drop_items) = registry.maps.get(&stage.map_key()).and_then(|map| map.drop_items.as_ref()) else { continue; };
+        for value in drop_items.raw_data.iter().skip(1) {
+            let Ok(drop_id) = value.parse::<u32>() else { continue; };
+            let Some(&cat_id) = tables.drop_chara.get(&drop_id) else { continue; };
+            index.cats.entry(cat_id).or_default().push(source("Map drop".to_string(), 1));
+        }
+    }
+
+    for sources in index.items.values_mut().chain(index.cats.values_mut()) {
+        sources.sort_by_key(|source| source.sort_key);
+        sources.dedup_by(|a, b| a.stage_key == b.stage_key && a.chance == b.chance && a.amount == b.amount);
+    }
+
+    index
+}
@@ -1,9 +1,15 @@
 use crate::features::stage::logic::state::StageListState;
 use crate::features::settings::logic::state::ScannerConfig;
-use super::scanner;
+use std::sync::Arc;
+use super::{graph, index, scanner, threat};
 
 pub fn restart_scan(state: &mut StageListState, config: ScannerConfig) {
     state.registry.clear_cache();
+    state.enemy_index.clear();
+    state.drop_index = Default::default();
+    state.unlock_graph = Default::default();
+    state.threats.clear();
+    state.searched = None;
     
     state.scan_receiver = Some(scanner::start_scan(&config));
 }
@@ -12,7 +18,12 @@ pub fn update_data(state: &mut StageListState) {
     let Some(rx) = &state.scan_receiver else { return };
 
     if let Ok(new_registry) = rx.try_recv() {
+        state.enemy_index = index::build_enemy_index(&new_registry);
+        state.drop_index = Arc::new(index::build_drop_index(&new_registry, &state.drop_tables()));
+        state.unlock_graph = graph::build(&new_registry);
+        state.threats = threat::compute_all(&new_registry, &state.enemy_registry);
         state.registry = new_registry;
+        state.searched = None;
         state.scan_receiver = None;
     }
 }
\ No newline at end of file
@@ -4,4 +4,9 @@ pub mod scanner;
 pub mod state;
 pub mod battleground;
 pub mod info;
-pub mod treasure;
\ No newline at end of file
+pub mod treasure;
+pub mod index;
+pub mod search;
+pub mod threat;
+pub mod graph;
+pub mod preview;
\ No newline at end of file
@@ -0,0 +1,204 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use eframe::egui;
+use image::{imageops, Rgba, RgbaImage};
+use crate::features::enemy::logic::scanner::EnemyEntry;
+use crate::features::stage::data::bg::BackgroundDef;
+use crate::features::stage::paths;
+use crate::features::stage::registry::Stage;
+use crate::global::resolver;
+use crate::global::utils::{autocrop, detect_csv_separator};
+
+// Battlefield units to preview pixels
+const SCALE: f32 = 0.4;
+const MIN_HEIGHT: u32 = 300;
+const ANIM_BASE_SIZE: u32 = 96;
+// Battle places the enemy base this many units from the left edge and the cat base as far from the right edge
+const BASE_OFFSET: f32 = 800.0;
+
+// Castle sets by base id thousand: regular, Empire of Cats, Into the Future, Cats of the Cosmos
+const CASTLE_SETS: [&str; 4] = ["rc", "ec", "wc", "sc"];
+
+// The battle sheet cuts the ground strip first and the sky strip at index 20 when it has one
+const GROUND_CUT: usize = 0;
+const SKY_CUT: usize = 20;
+
+// Default castle design, drawn from its first part sheet
+const CAT_CASTLE_NAME: &str = "nyankoCastle_000_00";
+
+pub struct BattlefieldPreview {
+    pub stage_id: String,
+    pub image: RgbaImage,
+    pub texture: egui::TextureHandle,
+}
+
+fn read_cuts(imgcut_path: &Path) -> HashMap<usize, [u32; 4]> {
+    let Ok(content) = fs::read_to_string(imgcut_path) else { return HashMap::new(); };
+    let separator = detect_csv_separator(&content);
+
+    content.lines()
+        .map(|line| line.split("//").next().unwrap_or("").trim())
+        .filter(|line| line.contains(separator))
+        .filter_map(|line| {
+            let values: Vec<u32> = line.split(separator).take(4).filter_map(|part| part.trim().parse().ok()).collect();
+            (values.len() == 4).then(|| [values[0], values[1], values[2], values[3]])
+        })
+        .enumerate()
+        .collect()
+}
+
+fn load_background(background: &BackgroundDef, lang_priority: &[String]) -> Option<(RgbaImage, HashMap<usize, [u32; 4]>)> {
+    let directory = Path::new(paths::DIR_STAGES).join("backgrounds").join("battle").join(format!("{:03}", background.image_id));
+    let png_name = format!("bg{:03}.png", background.image_id);
+    let cut_name = format!("bg{:03}.imgcut", background.image_id);
+
+    let png_path = resolver::get(&directory, &[png_name], lang_priority).into_iter().next()?;
+    let sheet = image::open(png_path).ok()?.to_rgba8();
+    let cuts = resolver::get(&directory, &[cut_name], lang_priority).into_iter().next()
+        .map(|cut_path| read_cuts(&cut_path))
+        .unwrap_or_default();
+    Some((sheet, cuts))
+}
+
+pub fn castle_path(base_id: i32, lang_priority: &[String]) -> Option<PathBuf> {
+    if base_id < 0 { return None; }
+    let set = CASTLE_SETS.get(base_id as usize / 1000)?;
+    let number = base_id % 1000;
+    let directory = Path::new(paths::DIR_STAGES).join("castles").join(set);
+    let candidates = [format!("{}{:03}_00.png", set, number), format!("{}{:03}.png", set, number)];
+    resolver::get(&directory, &candidates, lang_priority).into_iter().next()
+}
+
+fn crop(sheet: &RgbaImage, cut: [u32; 4]) -> Option<RgbaImage> {
+    let [x, y, width, height] = cut;
+    if width == 0 || height == 0 || x + width > sheet.width() || y + height > sheet.height() { return None; }
+    Some(imageops::crop_imm(sheet, x, y, width, height).to_image())
+}
+
+fn scaled(image: &RgbaImage, factor: f32) -> RgbaImage {
+    let width = ((image.width() as f32 * factor).round() as u32).max(1);
+    let height = ((image.height() as f32 * factor).round() as u32).max(1);
+    imageops::resize(image, width, height, imageops::FilterType::Triangle)
+}
+
+fn fill_gradient(canvas: &mut RgbaImage, top: u32, bottom: u32, from: [u8; 3], to: [u8; 3]) {
+    let span = bottom.saturating_sub(top).max(1) as f32;
+    for y in top..bottom.min(canvas.height()) {
+        let t = (y - top) as f32 / span;
+        let channel = |index: usize| (from[index] as f32 + (to[index] as f32 - from[index] as f32) * t).round() as u8;
+        let color = Rgba([channel(0), channel(1), channel(2), 255]);
+        for x in 0..canvas.width() {
+            canvas.put_pixel(x, y, color);
+        }
+    }
+}
+
+fn tile_row(canvas: &mut RgbaImage, strip: &RgbaImage, top: i64) {
+    let mut x = 0;
+    while x < canvas.width() as i64 {
+        imageops::overlay(canvas, strip, x, top);
+        x += strip.width().max(1) as i64;
+    }
+}
+
+fn cat_base_image(lang_priority: &[String]) -> Option<RgbaImage> {
+    let directory = Path::new(paths::DIR_STAGES).join("castles").join("nyanko");
+    let png_path = resolver::get(&directory, &[format!("{}.png", CAT_CASTLE_NAME)], lang_priority).into_iter().next()?;
+    let sheet = image::open(png_path).ok()?.to_rgba8();
+
+    let cut = resolver::get(&directory, &[format!("{}.imgcut", CAT_CASTLE_NAME)], lang_priority).into_iter().next()
+        .and_then(|cut_path| read_cuts(&cut_path).get(&0).copied());
+    let sprite = cut.and_then(|cut| crop(&sheet, cut)).unwrap_or(sheet);
+    Some(scaled(&autocrop(sprite), SCALE))
+}
+
+fn enemy_base_image(stage: &Stage, enemy_registry: &HashMap<u32, EnemyEntry>, lang_priority: &[String]) -> Option<RgbaImage> {
+    // Animated bases are enemies standing in for the castle, their icon marks the spot
+    if stage.anim_base_id != 0 {
+        let enemy_id = stage.anim_base_id.saturating_sub(2);
+        let icon_path = enemy_registry.get(&enemy_id)?.icon_path.as_ref()?;
+        let icon = autocrop(image::open(icon_path).ok()?.to_rgba8());
+        return Some(imageops::resize(&icon, ANIM_BASE_SIZE, ANIM_BASE_SIZE, imageops::FilterType::Triangle));
+    }
+
+    let castle = image::open(castle_path(stage.base_id, lang_priority)?).ok()?.to_rgba8();
+    Some(scaled(&autocrop(castle), SCALE))
+}
+
+pub fn render(
+    stage: &Stage,
+    backgrounds: &HashMap<u32, BackgroundDef>,
+    enemy_registry: &HashMap<u32, EnemyEntry>,
+    lang_priority: &[String],
+) -> RgbaImage {
+    let background = backgrounds.get(&stage.background_id).cloned().unwrap_or(BackgroundDef {
+        sky_top: [120, 170, 220],
+        sky_bottom: [200, 225, 240],
+        ground_top: [150, 120, 80],
+        ground_bottom: [90, 70, 45],
+        image_id: stage.background_id,
+    });
+
+    let sheet = load_background(&background, lang_priority);
+    let strip = |index: usize| {
+        let (image, cuts) = sheet.as_ref()?;
+        crop(image, *cuts.get(&index)?).map(|strip| scaled(&strip, SCALE))
+    };
+    let ground = strip(GROUND_CUT);
+    let sky = strip(SKY_CUT);
+
+    let ground_height = ground.as_ref().map_or((200.0 * SCALE) as u32, |strip| strip.height());
+    let sky_height = sky.as_ref().map_or(0, |strip| strip.height());
+    let width = ((stage.width.max(1000) as f32 * SCALE).round() as u32).max(1);
+    let height = (sky_height + ground_height + 60).max(MIN_HEIGHT);
+    let ground_y = height - ground_height;
+
+    let mut canvas = RgbaImage::new(width, height);
+    fill_gradient(&mut canvas, 0, ground_y, background.sky_top, background.sky_bottom);
+    fill_gradient(&mut canvas, ground_y, height, background.ground_top, background.ground_bottom);
+
+    if let Some(sky) = &sky {
+        tile_row(&mut canvas, sky, ground_y as i64 - sky.height() as i64);
+    }
+    if let Some(ground) = &ground {
+        tile_row(&mut canvas, ground, ground_y as i64);
+    }
+
+    // Bases stand a little into the ground strip, the enemy base on the left like in battle
+    // Both face each other with their front edge at the game's base position
+    let base_floor = ground_y as i64 + (ground_height as f32 * 0.3) as i64;
+    let enemy_front = (BASE_OFFSET * SCALE) as i64;
+    let cat_front = ((stage.width as f32 - BASE_OFFSET).max(BASE_OFFSET) * SCALE) as i64;
+
+    if let Some(enemy_base) = enemy_base_image(stage, enemy_registry, lang_priority) {
+        let x = enemy_front - enemy_base.width() as i64;
+        imageops::overlay(&mut canvas, &enemy_base, x.max(0), base_floor - enemy_base.height() as i64);
+    }
+    if let Some(cat_base) = cat_base_image(lang_priority) {
+        imageops::overlay(&mut canvas, &cat_base, cat_front, base_floor - cat_base.height() as i64);
+    }
+
+    canvas
+}
+
+// Rebuilds the preview only when another stage gets selected
+pub fn ensure<'a>(
+    slot: &'a mut Option<BattlefieldPreview>,
+    ctx: &egui::Context,
+    stage: &Stage,
+    backgrounds: &HashMap<u32, BackgroundDef>,
+    enemy_registry: &HashMap<u32, EnemyEntry>,
+    lang_priority: &[String],
+) -> &'a BattlefieldPreview {
+    if slot.as_ref().is_some_and(|preview| preview.stage_id == stage.id) {
+        return slot.as_ref().unwrap();
+    }
+
+    let image = render(stage, backgrounds, enemy_registry, lang_priority);
+    let size = [image.width() as usize, image.height() as usize];
+    let color_image = egui::ColorImage::from_rgba_unmultiplied(size, image.as_flat_samples().as_slice());
+    let texture = ctx.load_texture("stage_battlefield", color_image, egui::TextureOptions::LINEAR);
+
+    slot.insert(BattlefieldPreview { stage_id: stage.id.clone(), image, texture })
+}
@@ -0,0 +1,173 @@
+use serde::{Deserialize, Serialize};
+use crate::features::enemy::registry::{DisplayGroup, ENEMY_ABILITY_REGISTRY};
+use crate::features::stage::data::mapstagedata::RewardStructure;
+use crate::features::stage::data::stage::BossType;
+use crate::features::stage::registry::{Map, Stage};
+use super::index::{self, RewardTarget};
+use super::threat;
+use super::state::StageListState;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum FlagFilter {
+    #[default]
+    Any,
+    Yes,
+    No,
+}
+
+impl FlagFilter {
+    pub const ALL: [FlagFilter; 3] = [FlagFilter::Any, FlagFilter::Yes, FlagFilter::No];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            FlagFilter::Any => "Any",
+            FlagFilter::Yes => "Yes",
+            FlagFilter::No => "No",
+        }
+    }
+
+    pub fn matches(self, value: bool) -> bool {
+        match self {
+            FlagFilter::Any => true,
+            FlagFilter::Yes => value,
+            FlagFilter::No => !value,
+        }
+    }
+}
+
+// Zero on either end of a range means no limit
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StageSearch {
+    pub enemy_ids: String,
+    pub enemy_trait: Option<String>,
+    pub boss: FlagFilter,
+    pub min_magnification: u32,
+    pub max_magnification: u32,
+    pub min_energy: u32,
+    pub max_energy: u32,
+    pub crowns: Option<u8>,
+    pub special_rules: FlagFilter,
+    pub restricted: FlagFilter,
+    pub no_continues: FlagFilter,
+    pub reward: String,
+    #[serde(skip)] pub enemy_id_list: Vec<u32>,
+    #[serde(skip)] parsed_enemy_ids: Option<String>,
+}
+
+impl StageSearch {
+    // Re-parses the id list only when its text changed, call before searching
+    pub fn sync_enemy_ids(&mut self) {
+        if self.parsed_enemy_ids.as_ref() == Some(&self.enemy_ids) { return; }
+
+        // The "-E" suffix lets ids be pasted the way the enemy header shows them, like "045-E"
+        self.enemy_id_list = self.enemy_ids.split([',', ' '])
+            .filter_map(|part| part.trim().trim_end_matches("-E").parse().ok())
+            .collect();
+        self.parsed_enemy_ids = Some(self.enemy_ids.clone());
+    }
+}
+
+pub fn trait_names() -> impl Iterator<Item = &'static str> {
+    ENEMY_ABILITY_REGISTRY.iter()
+        .filter(|definition| definition.group == DisplayGroup::Type)
+        .map(|definition| definition.name)
+}
+
+fn in_range(value: u32, min: u32, max: u32) -> bool {
+    (min == 0 || value >= min) && (max == 0 || value <= max)
+}
+
+fn has_trait(state: &StageListState, enemy_id: u32, trait_name: &str) -> bool {
+    let Some(enemy) = state.enemy_registry.get(&enemy_id) else { return false; };
+    threat::enemy_traits(&enemy.stats).any(|name| name == trait_name)
+}
+
+fn reward_matches(state: &StageListState, stage: &Stage, query: &str) -> bool {
+    let reward_ids: Vec<u32> = match &stage.rewards {
+        RewardStructure::Treasure { drops, .. } => drops.iter().filter(|drop| drop.chance > 0).map(|drop| drop.id).collect(),
+        RewardStructure::Timed(scores) => scores.iter().map(|score| score.id).collect(),
+        RewardStructure::None => Vec::new(),
+    };
+
+    if let Ok(item_id) = query.parse::<u32>() {
+        return reward_ids.contains(&item_id);
+    }
+
+    let tables = state.drop_tables();
+    reward_ids.iter().any(|&reward_id| {
+        let Some(RewardTarget::Item(item_id)) = index::reward_target(reward_id, &tables) else { return false; };
+        state.item_buy_registry.get(&item_id)
+            .and_then(|item_buy| state.item_name_registry.get(&item_buy.row_index))
+            .is_some_and(|item_name| item_name.name.to_lowercase().contains(query))
+    })
+}
+
+pub fn matches(state: &StageListState, stage: &Stage, map: Option<&Map>, query: &str) -> bool {
+    let search = &state.search;
+
+    if !query.is_empty() {
+        let map_name = map.map(|map| map.name.to_lowercase()).unwrap_or_default();
+        if !stage.name.to_lowercase().contains(query) && !map_name.contains(query) && !stage.id.to_lowercase().contains(query) {
+            return false;
+        }
+    }
+
+    if !search.enemy_id_list.iter().all(|enemy_id| stage.enemies.iter().any(|line| line.id == *enemy_id)) { return false; }
+
+    if let Some(trait_name) = &search.enemy_trait
+        && !stage.enemies.iter().any(|line| has_trait(state, line.id, trait_name))
+    {
+        return false;
+    }
+
+    let has_boss = stage.enemies.iter().any(|line| line.boss_type != BossType::None);
+    if !search.boss.matches(has_boss) { return false; }
+
+    if (search.min_magnification > 0 || search.max_magnification > 0)
+        && !stage.enemies.iter().any(|line| in_range(line.magnification, search.min_magnification, search.max_magnification))
+    {
+        return false;
+    }
+
+    if !in_range(stage.energy, search.min_energy, search.max_energy) { return false; }
+
+    if let Some(crowns) = search.crowns
+        && map.map_or(1, |map| map.max_crowns.max(1)) != crowns
+    {
+        return false;
+    }
+
+    if !search.special_rules.matches(map.is_some_and(|map| map.special_rules.is_some())) { return false; }
+    if !search.restricted.matches(stage.charagroup.is_some()) { return false; }
+    if !search.no_continues.matches(stage.is_no_continues) { return false; }
+
+    let reward_query = search.reward.trim().to_lowercase();
+    if !reward_query.is_empty() && !reward_matches(state, stage, &reward_query) { return false; }
+
+    true
+}
+
+// Searches every category, ordered like the stage browser
+pub fn search(state: &StageListState) -> Vec<&Stage> {
+    let query = state.search_query.trim().to_lowercase();
+    let mut results: Vec<&Stage> = state.registry.stages.values()
+        .filter(|stage| matches(state, stage, state.registry.maps.get(&stage.map_key()), &query))
+        .collect();
+    results.sort_by_key(|stage| index::stage_sort_key(stage));
+    results
+}
+
+// Re-runs the search only when the query or the filters changed since the last call
+pub fn sync_results(state: &mut StageListState) {
+    state.search.sync_enemy_ids();
+    if let Some((query, search)) = &state.searched
+        && *query == state.search_query
+        && *search == state.search
+    {
+        return;
+    }
+
+    state.search_results = search(state).into_iter().map(|stage| stage.id.clone()).collect();
+    state.searched = Some((state.search_query.clone(), state.search.clone()));
+}
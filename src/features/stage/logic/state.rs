@@ -1,5 +1,6 @@
 use std::sync::mpsc::Receiver;
 use std::collections::HashMap;
+use std::sync::Arc;
 use std::path::Path;
 use eframe::egui;
 use crate::features::settings::logic::state::ScannerConfig;
@@ -9,6 +10,12 @@ use crate::features::stage::data::drop_chara;
 use crate::features::cat::data::unitbuy::{self, UnitBuyRow};
 use crate::global::formats::gatyaitembuy::{self, GatyaItemBuy};
 use crate::global::formats::gatyaitemname::{self, GatyaItemName};
+use super::index::{self, DropIndex, DropTables, EnemyIndex};
+use super::graph::UnlockGraph;
+use super::preview::BattlefieldPreview;
+use crate::features::stage::data::bg::{self, BackgroundDef};
+use super::search::StageSearch;
+use super::threat::{self, StageSort, StageThreat};
 use super::loader;
 
 #[derive(serde::Deserialize, serde::Serialize)]
@@ -16,14 +23,27 @@ use super::loader;
 pub struct StageListState {
     #[serde(skip)] pub registry: StageRegistry,
     pub search_query: String,
+    pub search: StageSearch,
+    #[serde(skip)] pub search_results: Vec<String>,
+    #[serde(skip)] pub searched: Option<(String, StageSearch)>,
+    #[serde(skip)] pub is_search_open: bool,
+    #[serde(skip)] pub is_graph_open: bool,
+    pub graph_category: Option<String>,
+    #[serde(skip)] pub graph_selected: Option<String>,
     pub selected_category: Option<String>, 
     pub selected_map: Option<String>,      
     pub selected_stage: Option<String>,    
+    pub stage_sort: StageSort,
     pub is_list_open: bool,
 
     #[serde(skip)] pub scan_receiver: Option<Receiver<StageRegistry>>,
+    #[serde(skip)] pub enemy_index: EnemyIndex,
+    #[serde(skip)] pub drop_index: Arc<DropIndex>,
+    #[serde(skip)] pub unlock_graph: UnlockGraph,
+    #[serde(skip)] pub threats: HashMap<String, StageThreat>,
     
     #[serde(skip)] pub enemy_registry: HashMap<u32, EnemyEntry>,
+    #[serde(skip)] pub enemy_generation: Option<u64>,
     #[serde(skip)] pub enemy_texture_cache: HashMap<u32, egui::TextureHandle>,
     #[serde(skip)] pub enemy_name_registry: Vec<String>,
 
@@ -31,7 +51,11 @@ pub struct StageListState {
     #[serde(skip)] pub item_name_registry: HashMap<usize, GatyaItemName>,
     #[serde(skip)] pub drop_chara_registry: HashMap<u32, u32>,
     #[serde(skip)] pub unit_buy_registry: HashMap<u32, UnitBuyRow>,
+    #[serde(skip)] pub true_form_registry: HashMap<u32, u32>,
     #[serde(skip)] pub item_texture_cache: HashMap<u32, egui::TextureHandle>,
+    #[serde(skip)] pub background_registry: HashMap<u32, BackgroundDef>,
+    #[serde(skip)] pub battlefield_preview: Option<BattlefieldPreview>,
+    #[serde(skip)] pub preview_status: Option<String>,
     
     #[serde(skip)] pub active_language_priority: Vec<String>,
 }
@@ -41,19 +65,36 @@ impl Default for StageListState {
         Self {
             registry: StageRegistry::default(),
             search_query: String::new(),
+            search: StageSearch::default(),
+            search_results: Vec::new(),
+            searched: None,
+            is_search_open: false,
+            is_graph_open: false,
+            graph_category: None,
+            graph_selected: None,
             selected_category: None,
             selected_map: None,
             selected_stage: None,
+            stage_sort: StageSort::default(),
             scan_receiver: None,
+            enemy_index: EnemyIndex::new(),
+            drop_index: Arc::default(),
+            unlock_graph: UnlockGraph::default(),
+            threats: HashMap::new(),
             is_list_open: true,
             enemy_registry: HashMap::new(),
+            enemy_generation: None,
             enemy_texture_cache: HashMap::new(),
             enemy_name_registry: Vec::new(),
             item_buy_registry: HashMap::new(),
             item_name_registry: HashMap::new(),
             drop_chara_registry: HashMap::new(),
             unit_buy_registry: HashMap::new(),
+            true_form_registry: HashMap::new(),
             item_texture_cache: HashMap::new(),
+            background_registry: HashMap::new(),
+            battlefield_preview: None,
+            preview_status: None,
             active_language_priority: Vec::new(),
         }
     }
@@ -90,11 +131,19 @@ impl StageListState {
             &scanner_configuration.language_priority
         );
 
+        self.background_registry = bg::load(
+            &stages_directory_path.join("backgrounds").join("battle"),
+            "bg.csv",
+            &scanner_configuration.language_priority
+        );
+        self.battlefield_preview = None;
+
         let cats_directory_path = Path::new("game/cats");
         self.unit_buy_registry = unitbuy::load_unitbuy(
             cats_directory_path, 
             &scanner_configuration.language_priority
         );
+        self.true_form_registry = index::build_true_forms(&self.unit_buy_registry);
 
         loader::restart_scan(self, scanner_configuration);
     }
@@ -103,7 +152,36 @@ impl StageListState {
         loader::update_data(self);
     }
 
-    pub fn sync_enemies(&mut self, extracted_enemies_array: &[EnemyEntry]) {
+    pub fn drop_tables(&self) -> DropTables<'_> {
+        DropTables {
+            item_buy: &self.item_buy_registry,
+            drop_chara: &self.drop_chara_registry,
+            true_forms: &self.true_form_registry,
+        }
+    }
+
+    pub fn select_stage(&mut self, stage_key: &str) {
+        let Some(stage) = self.registry.stages.get(stage_key) else { return; };
+        self.selected_category = Some(stage.category.clone());
+        self.selected_map = Some(stage.map_key());
+        self.selected_stage = Some(stage.id.clone());
+    }
+
+    pub fn select_map(&mut self, map_key: &str) {
+        let Some(map) = self.registry.maps.get(map_key) else { return; };
+        self.selected_category = Some(map.category.clone());
+        self.selected_map = Some(map.id.clone());
+        self.selected_stage = None;
+    }
+
+    // Only rebuilds when the enemy list finished a scan or had an enemy reloaded since the last sync
+    pub fn sync_enemies(&mut self, extracted_enemies_array: &[EnemyEntry], generation: u64) {
+        if self.enemy_generation == Some(generation) { return; }
+        self.enemy_generation = Some(generation);
+
         self.enemy_registry = extracted_enemies_array.iter().map(|enemy_entry| (enemy_entry.id, enemy_entry.clone())).collect();
+        self.threats = threat::compute_all(&self.registry, &self.enemy_registry);
+        // Trait filters read the enemy stats
+        self.searched = None;
     }
 }
\ No newline at end of file
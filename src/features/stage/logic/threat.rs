@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use crate::features::enemy::data::t_unit::EnemyRaw;
+use crate::features::enemy::logic::scanner::EnemyEntry;
+use crate::features::enemy::registry::{DisplayGroup, ENEMY_ABILITY_REGISTRY};
+use crate::features::stage::data::stage::{EnemyAmount, EnemyLine};
+use crate::features::stage::registry::{Stage, StageRegistry};
+
+// Infinite spawns and the DPS timeline are only followed for the first three minutes
+const HORIZON_FRAMES: u32 = 5400;
+const SAMPLE_FRAMES: u32 = 30;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum StageSort {
+    #[default]
+    Default,
+    TotalHp,
+    PeakDps,
+    CrowdControl,
+    WavesSurges,
+}
+
+impl StageSort {
+    pub const ALL: [StageSort; 5] = [
+        StageSort::Default, StageSort::TotalHp, StageSort::PeakDps, StageSort::CrowdControl, StageSort::WavesSurges,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            StageSort::Default => "Stage Order",
+            StageSort::TotalHp => "Total HP",
+            StageSort::PeakDps => "Peak DPS",
+            StageSort::CrowdControl => "Crowd Control",
+            StageSort::WavesSurges => "Waves & Surges",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StageThreat {
+    pub total_hp: i64,
+    pub has_infinite: bool,
+    pub peak_dps: i64,
+    pub peak_frame: u32,
+    pub traits: Vec<&'static str>,
+    pub crowd_control: u32,
+    pub waves: u32,
+    pub surges: u32,
+}
+
+impl StageThreat {
+    pub fn sort_value(&self, sort: StageSort) -> i64 {
+        match sort {
+            StageSort::Default => 0,
+            StageSort::TotalHp => self.total_hp,
+            StageSort::PeakDps => self.peak_dps,
+            StageSort::CrowdControl => self.crowd_control as i64,
+            StageSort::WavesSurges => (self.waves + self.surges) as i64,
+        }
+    }
+}
+
+pub fn enemy_traits(stats: &EnemyRaw) -> impl Iterator<Item = &'static str> + '_ {
+    ENEMY_ABILITY_REGISTRY.iter()
+        .filter(|definition| definition.group == DisplayGroup::Type && !(definition.get_attributes)(stats).is_empty())
+        .map(|definition| definition.name)
+}
+
+fn has_crowd_control(stats: &EnemyRaw) -> bool {
+    [stats.freeze_chance, stats.slow_chance, stats.weaken_chance, stats.knockback_chance, stats.curse_chance, stats.warp_chance]
+        .iter()
+        .any(|&chance| chance > 0)
+}
+
+fn line_dps(line: &EnemyLine, enemy: &EnemyEntry) -> f64 {
+    let stats = &enemy.stats;
+    let cycle = stats.attack_cycle(enemy.atk_anim_frames);
+    if cycle <= 0 { return 0.0; }
+
+    let damage = (stats.attack_1 + stats.attack_2 + stats.attack_3) as f64 * line.atk_magnification as f64 / 100.0;
+    damage * 30.0 / cycle as f64
+}
+
+// Lines gated behind base HP or kill counts are treated as if the trigger was hit straight away
+fn spawned_by(line: &EnemyLine, frame: u32) -> u32 {
+    if frame < line.start_frame { return 0; }
+
+    let respawn = (line.respawn_min + line.respawn_max) / 2;
+    let spawned = 1 + (frame - line.start_frame).checked_div(respawn).unwrap_or(0);
+
+    match line.amount {
+        EnemyAmount::Limit(limit) => spawned.min(limit),
+        EnemyAmount::Infinite => spawned,
+    }
+}
+
+// Keyed like the registry, rebuilt whenever the stages or the enemies reload
+pub fn compute_all(registry: &StageRegistry, enemy_registry: &HashMap<u32, EnemyEntry>) -> HashMap<String, StageThreat> {
+    registry.stages.iter().map(|(stage_key, stage)| (stage_key.clone(), compute(stage, enemy_registry))).collect()
+}
+
+pub fn compute(stage: &Stage, enemy_registry: &HashMap<u32, EnemyEntry>) -> StageThreat {
+    let mut threat = StageThreat::default();
+    let lines: Vec<(&EnemyLine, &EnemyEntry)> = stage.enemies.iter()
+        .filter(|line| !line.is_base)
+        .filter_map(|line| enemy_registry.get(&line.id).map(|enemy| (line, enemy)))
+        .collect();
+
+    for &(line, enemy) in &lines {
+        let stats = &enemy.stats;
+        let count = match line.amount {
+            EnemyAmount::Limit(limit) => limit,
+            EnemyAmount::Infinite => {
+                threat.has_infinite = true;
+                spawned_by(line, HORIZON_FRAMES.max(line.start_frame))
+            }
+        };
+        threat.total_hp += stats.hitpoints as i64 * line.magnification as i64 / 100 * count as i64;
+
+        for name in enemy_traits(stats) {
+            if !threat.traits.contains(&name) {
+                threat.traits.push(name);
+            }
+        }
+
+        if has_crowd_control(stats) { threat.crowd_control += 1; }
+        if stats.wave_chance > 0 { threat.waves += 1; }
+        if stats.surge_chance > 0 || stats.death_surge_chance > 0 { threat.surges += 1; }
+    }
+
+    // Every spawn is assumed to stay alive, the field cap thins the crowd evenly
+    let dps: Vec<f64> = lines.iter().map(|&(line, enemy)| line_dps(line, enemy)).collect();
+    for frame in (0..=HORIZON_FRAMES).step_by(SAMPLE_FRAMES as usize) {
+        let mut on_field = 0;
+        let mut total = 0.0;
+        for (&(line, _), &line_dps) in lines.iter().zip(&dps) {
+            let spawned = spawned_by(line, frame);
+            on_field += spawned;
+            total += line_dps * spawned as f64;
+        }
+
+        if stage.max_enemies > 0 && on_field > stage.max_enemies {
+            total *= stage.max_enemies as f64 / on_field as f64;
+        }
+
+        let total = total.round() as i64;
+        if total > threat.peak_dps {
+            threat.peak_dps = total;
+            threat.peak_frame = frame;
+        }
+    }
+
+    threat
+}
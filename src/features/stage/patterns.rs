@@ -20,6 +20,7 @@ pub const LEGACY_STAGE_NAME_PATTERN: &str = r"^([a-zA-Z][cC])(\d+)_([a-zA-Z]{1,3
 
 // Castles
 pub const CASTLE_PATTERN: &str = r"^(?:castle_)?([a-zA-Z][cC])(\d+)(?:_\d+)?(?:_[a-zA-Z]{2})?\.(png|imgcut|maanim|mamodel)$";
+pub const CAT_CASTLE_PATTERN: &str = r"^nyankoCastle_(\d{3})_(\d{2})\.(png|imgcut)$";
 
 // Backgrounds
 pub const BG_MAP_PATTERN: &str = r"^map(\d+)(?:_[0-9_]+)?\.(png|imgcut|maanim|mamodel|json)$";
@@ -42,6 +42,12 @@ pub struct Stage {
     pub charagroup: Option<data::charagroup::CharaGroup>,
 }
 
+impl Stage {
+    pub fn map_key(&self) -> String {
+        format!("{}_{}", self.category, self.map_id)
+    }
+}
+
 #[derive(Default, Debug, Clone, serde::Deserialize, serde::Serialize)]
 pub struct Map {
     pub id: String,
@@ -79,4 +85,13 @@ impl StageRegistry {
         self.maps.clear();
         self.stages.clear();
     }
+
+    // Event schedules refer to maps as category base id * 1000 + map id
+    pub fn find_map_by_event_id(&self, event_id: u32) -> Option<&Map> {
+        let base_id = event_id / 1000;
+        let map_id = event_id % 1000;
+        self.maps.values()
+            .filter(|map| map.map_id == map_id && data::map_name::get_meta(&map.category).base_id == Some(base_id))
+            .min_by_key(|map| data::map_name::get_meta(&map.category).sort_order)
+    }
 }
\ No newline at end of file
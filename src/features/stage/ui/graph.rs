@@ -0,0 +1,222 @@
+use eframe::egui;
+use crate::features::stage::data::map_option::ResetType;
+use crate::features::stage::logic::graph::{EdgeKind, UnlockGraph};
+use crate::features::stage::logic::state::StageListState;
+use crate::features::stage::registry::StageRegistry;
+
+const NODE_WIDTH: f32 = 150.0;
+const NODE_HEIGHT: f32 = 34.0;
+const COLUMN_WIDTH: f32 = 200.0;
+const ROW_HEIGHT: f32 = 46.0;
+const BAND_GAP: f32 = 18.0;
+
+const NODE_COLOR: egui::Color32 = egui::Color32::from_rgb(50, 50, 50);
+const EX_NODE_COLOR: egui::Color32 = egui::Color32::from_rgb(120, 75, 30);
+const SELECTED_COLOR: egui::Color32 = egui::Color32::from_rgb(31, 106, 165);
+const INVASION_COLOR: egui::Color32 = egui::Color32::from_rgb(230, 150, 60);
+
+enum GraphAction {
+    Select(String),
+    OpenMap(String),
+}
+
+pub fn show(ctx: &egui::Context, state: &mut StageListState) {
+    if !state.is_graph_open { return; }
+
+    let mut is_open = state.is_graph_open;
+    let mut action = None;
+
+    egui::Window::new("Unlock Graph")
+        .open(&mut is_open)
+        .collapsible(false)
+        .default_width(820.0)
+        .default_height(560.0)
+        .show(ctx, |ui| {
+            let graph = &state.unlock_graph;
+            if graph.nodes.is_empty() {
+                ui.label(egui::RichText::new("No EX or chapter links found").weak());
+                return;
+            }
+
+            render_filter(ui, graph, &mut state.graph_category);
+            ui.separator();
+
+            if let Some(map_key) = &state.graph_selected
+                && let Some(node) = graph.find(map_key)
+            {
+                if let Some(details_action) = render_details(ui, graph, &state.registry, node) {
+                    action = Some(details_action);
+                }
+                ui.separator();
+            }
+
+            egui::ScrollArea::both()
+                .auto_shrink([false, false])
+                .show(ui, |ui| {
+                    if let Some(canvas_action) = render_canvas(ui, graph, state.graph_category.as_deref(), state.graph_selected.as_deref()) {
+                        action = Some(canvas_action);
+                    }
+                });
+        });
+
+    match action {
+        Some(GraphAction::Select(map_key)) => state.graph_selected = Some(map_key),
+        Some(GraphAction::OpenMap(map_key)) => state.select_map(&map_key),
+        None => {}
+    }
+    state.is_graph_open = is_open;
+}
+
+fn render_filter(ui: &mut egui::Ui, graph: &UnlockGraph, category: &mut Option<String>) {
+    let mut categories: Vec<(&str, &str)> = graph.nodes.iter()
+        .map(|node| (node.category.as_str(), node.category_name.as_str()))
+        .collect();
+    categories.sort();
+    categories.dedup();
+
+    let selected_name = category.as_deref()
+        .and_then(|prefix| categories.iter().find(|(other, _)| *other == prefix))
+        .map_or("All Categories", |(_, name)| *name);
+
+    ui.horizontal(|ui| {
+        ui.label("Category");
+        egui::ComboBox::from_id_salt("unlock_graph_category")
+            .selected_text(selected_name)
+            .show_ui(ui, |ui| {
+                ui.selectable_value(category, None, "All Categories");
+                for (prefix, name) in &categories {
+                    ui.selectable_value(category, Some(prefix.to_string()), *name);
+                }
+            });
+
+        ui.add_space(10.0);
+        ui.label(egui::RichText::new("━ Next Chapter").color(egui::Color32::GRAY));
+        ui.label(egui::RichText::new("━ EX Invasion").color(INVASION_COLOR));
+    });
+}
+
+fn reset_label(reset_type: &ResetType) -> String {
+    match reset_type {
+        ResetType::None => "-".to_string(),
+        ResetType::ResetRewards => "Rewards".to_string(),
+        ResetType::ResetRewardsAndClear => "Rewards & Clears".to_string(),
+        ResetType::ResetMaxClears => "Clear Limit".to_string(),
+        ResetType::Unknown(value) => format!("Unknown ({})", value),
+    }
+}
+
+fn render_details(ui: &mut egui::Ui, graph: &UnlockGraph, registry: &StageRegistry, node_index: usize) -> Option<GraphAction> {
+    let node = &graph.nodes[node_index];
+    let map = registry.maps.get(&node.map_key)?;
+    let mut action = None;
+
+    ui.horizontal(|ui| {
+        ui.strong(&node.name);
+        ui.label(egui::RichText::new(format!("{} ({})", node.category_name, node.map_key)).weak());
+        if ui.button("Open Map").clicked() {
+            action = Some(GraphAction::OpenMap(node.map_key.clone()));
+        }
+    });
+
+    egui::Grid::new("unlock_graph_details")
+        .spacing([15.0, 4.0])
+        .show(ui, |ui| {
+            ui.label(egui::RichText::new("Stages").strong());
+            ui.label(map.stages.len().to_string());
+            ui.label(egui::RichText::new("Crowns").strong());
+            ui.label(map.max_crowns.max(1).to_string());
+            ui.label(egui::RichText::new("Reset").strong());
+            ui.label(reset_label(&map.reset_type));
+            ui.label(egui::RichText::new("Hidden After Clear").strong());
+            ui.label(if map.hidden_upon_clear { "Yes" } else { "No" });
+            ui.end_row();
+        });
+
+    let links = [
+        ("Unlocked By", graph.incoming(node_index).map(|edge| (edge.from, edge.kind)).collect::<Vec<_>>()),
+        ("Leads To", graph.outgoing(node_index).map(|edge| (edge.to, edge.kind)).collect::<Vec<_>>()),
+    ];
+    for (title, targets) in links {
+        if targets.is_empty() { continue; }
+        ui.horizontal_wrapped(|ui| {
+            ui.label(egui::RichText::new(title).strong());
+            for (target, kind) in targets {
+                let target_node = &graph.nodes[target];
+                let link = ui.link(&target_node.name).on_hover_text(kind.label());
+                if link.clicked() {
+                    action = Some(GraphAction::Select(target_node.map_key.clone()));
+                }
+            }
+        });
+    }
+
+    action
+}
+
+fn render_canvas(ui: &mut egui::Ui, graph: &UnlockGraph, category: Option<&str>, selected: Option<&str>) -> Option<GraphAction> {
+    // Whole chains stay visible when any of their maps is in the filtered category
+    let mut band_offsets = vec![None; graph.components.len()];
+    let mut height = 0.0;
+    let mut width: f32 = 0.0;
+    for (index, component) in graph.components.iter().enumerate() {
+        let is_visible = category.is_none_or(|prefix| component.nodes.iter().any(|&node| graph.nodes[node].category == prefix));
+        if !is_visible { continue; }
+        band_offsets[index] = Some(height);
+        height += component.rows as f32 * ROW_HEIGHT + BAND_GAP;
+        width = width.max(component.layers as f32 * COLUMN_WIDTH);
+    }
+
+    let (canvas, _) = ui.allocate_exact_size(egui::vec2(width, height), egui::Sense::hover());
+    let node_rect = |index: usize| -> Option<egui::Rect> {
+        let node = &graph.nodes[index];
+        let band = band_offsets[node.component]?;
+        let min = canvas.min + egui::vec2(node.layer as f32 * COLUMN_WIDTH, band + node.row as f32 * ROW_HEIGHT);
+        Some(egui::Rect::from_min_size(min, egui::vec2(NODE_WIDTH, NODE_HEIGHT)))
+    };
+
+    let painter = ui.painter_at(canvas);
+    for edge in &graph.edges {
+        let (Some(from), Some(to)) = (node_rect(edge.from), node_rect(edge.to)) else { continue; };
+        let color = match edge.kind {
+            EdgeKind::ExInvasion => INVASION_COLOR,
+            EdgeKind::Sequel => egui::Color32::GRAY,
+        };
+        let start = from.right_center();
+        painter.arrow(start, to.left_center() - start, egui::Stroke::new(1.5, color));
+    }
+
+    let mut action = None;
+    for (index, node) in graph.nodes.iter().enumerate() {
+        let Some(rect) = node_rect(index) else { continue; };
+        let response = ui.interact(rect, ui.id().with(("unlock_graph_node", index)), egui::Sense::click());
+
+        let fill = if selected == Some(node.map_key.as_str()) {
+            SELECTED_COLOR
+        } else if node.category == "EX" {
+            EX_NODE_COLOR
+        } else {
+            NODE_COLOR
+        };
+        let stroke = if response.hovered() { egui::Stroke::new(1.0, egui::Color32::WHITE) } else { egui::Stroke::NONE };
+        painter.rect(rect, 5.0, fill, stroke);
+
+        let galley = ui.fonts(|fonts| fonts.layout(
+            node.name.clone(),
+            egui::FontId::proportional(12.0),
+            egui::Color32::WHITE,
+            NODE_WIDTH - 8.0,
+        ));
+        let text_pos = rect.center() - galley.size() / 2.0;
+        painter.with_clip_rect(rect.shrink(2.0)).galley(text_pos, galley, egui::Color32::WHITE);
+
+        let response = response.on_hover_text(format!("{}\n{}", node.name, node.category_name));
+        if response.clicked() {
+            action = Some(GraphAction::Select(node.map_key.clone()));
+        }
+        if response.double_clicked() {
+            action = Some(GraphAction::OpenMap(node.map_key.clone()));
+        }
+    }
+
+    action
+}
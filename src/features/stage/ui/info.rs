@@ -1,6 +1,8 @@
 use eframe::egui;
 use crate::features::stage::registry::Stage;
 use crate::features::stage::logic::info as info_logic;
+use crate::features::stage::logic::threat::StageThreat;
+use crate::global::utils::format_number;
 
 fn center_header(ui: &mut egui::Ui, display_text: &str) {
     ui.centered_and_justified(|ui| {
@@ -67,4 +69,40 @@ pub fn draw(ui: &mut egui::Ui, stage_data: &Stage) {
             center_text(grid, formatted_crown);
             grid.end_row();
         });
+}
+
+pub fn draw_threat(ui: &mut egui::Ui, threat: &StageThreat) {
+    ui.strong("Threat Summary");
+    ui.separator();
+
+    let hp_suffix = if threat.has_infinite { "+" } else { "" };
+    let peak_time = format!("{:.0}s", threat.peak_frame as f32 / 30.0);
+
+    egui::Grid::new("stage_threat_grid")
+        .striped(true)
+        .spacing([15.0, 8.0])
+        .show(ui, |grid| {
+            center_header(grid, "Total HP");
+            center_header(grid, "Peak DPS");
+            center_header(grid, "Peak At");
+            center_header(grid, "Crowd Control");
+            center_header(grid, "Wave");
+            center_header(grid, "Surge");
+            grid.end_row();
+
+            center_text(grid, format!("{}{}", format_number(threat.total_hp), hp_suffix));
+            center_text(grid, format_number(threat.peak_dps));
+            center_text(grid, peak_time);
+            center_text(grid, threat.crowd_control.to_string());
+            center_text(grid, threat.waves.to_string());
+            center_text(grid, threat.surges.to_string());
+            grid.end_row();
+        });
+
+    ui.add_space(5.0);
+    let traits = if threat.traits.is_empty() { "-".to_string() } else { threat.traits.join(", ") };
+    ui.label(format!("Traits: {}", traits));
+    if threat.has_infinite {
+        ui.label(egui::RichText::new("Infinite spawns are counted over the first three minutes").weak());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,11 @@
+use eframe::egui;
+
+// The stage browser is only built into debug builds, release builds show the name without a link
+pub fn stage_link(ui: &mut egui::Ui, stage_name: &str) -> bool {
+    if cfg!(debug_assertions) {
+        ui.link(stage_name).on_hover_text("Open in the stage browser").clicked()
+    } else {
+        ui.label(stage_name);
+        false
+    }
+}
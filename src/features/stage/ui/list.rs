@@ -1,5 +1,8 @@
 use eframe::egui;
 use crate::features::stage::logic::{state::StageListState, navigate};
+use crate::features::stage::logic::threat::StageSort;
+use crate::features::stage::registry::Stage;
+use crate::global::utils::format_number;
 
 pub const BTN_SPACING_X: f32 = 14.0; // Total horizontal space given to the separator
 pub const BTN_SPACING_Y: f32 = 6.0;  // Vertical padding between list buttons
@@ -57,6 +60,14 @@ fn draw_categories(ui: &mut egui::Ui, state: &mut StageListState, categories: &[
             .show(ui, |ui| {
                 ui.spacing_mut().item_spacing.y = BTN_SPACING_Y; 
                 ui.add_space(BTN_SPACING_Y);
+
+                if draw_sidebar_btn(ui, "Search Stages", state.is_search_open) {
+                    state.is_search_open = !state.is_search_open;
+                }
+                if draw_sidebar_btn(ui, "Unlock Graph", state.is_graph_open) {
+                    state.is_graph_open = !state.is_graph_open;
+                }
+                ui.separator();
                 
                 for (cat_prefix, cat_name) in categories {
                     let is_selected = state.selected_category.as_deref() == Some(cat_prefix);
@@ -118,11 +129,29 @@ fn draw_stages(ui: &mut egui::Ui, state: &mut StageListState) {
                 ui.spacing_mut().item_spacing.y = BTN_SPACING_Y;
                 ui.add_space(BTN_SPACING_Y);
                 
-                let stages = navigate::get_stages(&state.registry, map_id);
-                for stage in stages {
+                egui::ComboBox::from_id_salt("stage_sort")
+                    .selected_text(state.stage_sort.label())
+                    .width(ui.available_width())
+                    .show_ui(ui, |ui| {
+                        for sort in StageSort::ALL {
+                            ui.selectable_value(&mut state.stage_sort, sort, sort.label());
+                        }
+                    });
+
+                let sort = state.stage_sort;
+                let mut stages: Vec<(Stage, i64)> = navigate::get_stages(&state.registry, map_id).into_iter()
+                    .map(|stage| {
+                        let value = if sort == StageSort::Default { 0 } else { state.threats.get(&stage.id).map_or(0, |threat| threat.sort_value(sort)) };
+                        (stage, value)
+                    })
+                    .collect();
+                stages.sort_by_key(|(_, value)| std::cmp::Reverse(*value));
+
+                for (stage, value) in stages {
                     let is_selected = state.selected_stage.as_ref() == Some(&stage.id);
+                    let label = if sort == StageSort::Default { stage.name.clone() } else { format!("{} ({})", stage.name, format_number(value)) };
                     
-                    if draw_sidebar_btn(ui, &stage.name, is_selected) {
+                    if draw_sidebar_btn(ui, &label, is_selected) {
                         state.selected_stage = Some(stage.id);
                     }
                 }
@@ -1,7 +1,7 @@
 use eframe::egui;
 use crate::features::stage::logic::state::StageListState;
 use crate::features::settings::logic::Settings;
-use super::{list, view};
+use super::{graph, list, search, view};
 
 const ANIM_SPEED: f32 = 0.15; // Open/close speed multiplier (duration in seconds)
 const TOGGLE_BTN_GAP: f32 = 5.0; // Left padding for the toggle button (and distance from list)
@@ -99,4 +99,7 @@ pub fn show(ctx: &egui::Context, state: &mut StageListState, _settings: &mut Set
                 state.is_list_open = !state.is_list_open;
             }
         });
-}
\ No newline at end of file
+
+    search::show(ctx, state);
+    graph::show(ctx, state);
+}
@@ -3,4 +3,8 @@ pub mod master;
 pub mod view;
 pub mod info;
 pub mod treasure;
-pub mod battleground;
\ No newline at end of file
+pub mod battleground;
+pub mod search;
+pub mod graph;
+pub mod preview;
+pub mod link;
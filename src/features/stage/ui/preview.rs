@@ -0,0 +1,28 @@
+use eframe::egui;
+use crate::features::stage::logic::preview::BattlefieldPreview;
+
+const MAX_PREVIEW_HEIGHT: f32 = 260.0;
+
+pub fn draw(ui: &mut egui::Ui, preview: &BattlefieldPreview, status: &mut Option<String>) {
+    ui.strong("Battlefield");
+    ui.separator();
+
+    let texture_size = preview.texture.size_vec2();
+    let scale = (ui.available_width() / texture_size.x).min(MAX_PREVIEW_HEIGHT / texture_size.y).min(1.0);
+    ui.add(egui::Image::new(&preview.texture).fit_to_exact_size(texture_size * scale));
+
+    ui.horizontal(|ui| {
+        if ui.button("Export PNG").clicked() {
+            let file_name = format!("{}_battlefield.png", preview.stage_id);
+            if let Some(path) = rfd::FileDialog::new().add_filter("PNG", &["png"]).set_file_name(file_name).save_file() {
+                *status = Some(match preview.image.save(&path) {
+                    Ok(()) => format!("Saved to {}", path.display()),
+                    Err(error) => format!("Export failed: {}", error),
+                });
+            }
+        }
+        if let Some(status) = status {
+            ui.label(egui::RichText::new(status.as_str()).weak());
+        }
+    });
+}
@@ -0,0 +1,124 @@
+use eframe::egui;
+use crate::features::stage::logic::search::{self, FlagFilter, StageSearch};
+use crate::features::stage::logic::state::StageListState;
+
+const RESULT_ROW_HEIGHT: f32 = 22.0;
+
+pub fn show(ctx: &egui::Context, state: &mut StageListState) {
+    if !state.is_search_open { return; }
+
+    let mut is_open = state.is_search_open;
+    let mut selected_stage = None;
+
+    egui::Window::new("Stage Search")
+        .open(&mut is_open)
+        .collapsible(false)
+        .default_width(420.0)
+        .default_height(560.0)
+        .show(ctx, |ui| {
+            ui.add(egui::TextEdit::singleline(&mut state.search_query)
+                .hint_text(egui::RichText::new("Stage or map name...").color(egui::Color32::GRAY))
+                .desired_width(f32::INFINITY));
+
+            render_filters(ui, &mut state.search);
+
+            ui.horizontal(|ui| {
+                if ui.button("Clear Filters").clicked() {
+                    state.search = StageSearch::default();
+                    state.search_query.clear();
+                }
+            });
+            ui.separator();
+
+            search::sync_results(state);
+            ui.label(egui::RichText::new(format!("{} stages", state.search_results.len())).weak());
+
+            egui::ScrollArea::vertical()
+                .auto_shrink([false, false])
+                .show_rows(ui, RESULT_ROW_HEIGHT, state.search_results.len(), |ui, row_range| {
+                    for stage_key in &state.search_results[row_range] {
+                        let Some(stage) = state.registry.stages.get(stage_key) else { continue; };
+                        let map_name = state.registry.maps.get(&stage.map_key()).map(|map| map.name.as_str()).unwrap_or_default();
+                        let label = format!("{} / {} / {}", stage.category_name, map_name, stage.name);
+                        let is_selected = state.selected_stage.as_ref() == Some(&stage.id);
+
+                        if ui.selectable_label(is_selected, label).clicked() {
+                            selected_stage = Some(stage.id.clone());
+                        }
+                    }
+                });
+        });
+
+    if let Some(stage_key) = selected_stage {
+        state.select_stage(&stage_key);
+    }
+    state.is_search_open = is_open;
+}
+
+fn render_filters(ui: &mut egui::Ui, search: &mut StageSearch) {
+    egui::Grid::new("stage_search_filters")
+        .spacing([10.0, 4.0])
+        .show(ui, |ui| {
+            ui.label("Enemy IDs");
+            ui.add(egui::TextEdit::singleline(&mut search.enemy_ids)
+                .hint_text(egui::RichText::new("e.g. 2, 45").color(egui::Color32::GRAY)));
+            ui.end_row();
+
+            ui.label("Enemy Trait");
+            egui::ComboBox::from_id_salt("stage_search_trait")
+                .selected_text(search.enemy_trait.as_deref().unwrap_or("Any"))
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut search.enemy_trait, None, "Any");
+                    for trait_name in search::trait_names() {
+                        ui.selectable_value(&mut search.enemy_trait, Some(trait_name.to_string()), trait_name);
+                    }
+                });
+            ui.end_row();
+
+            ui.label("Magnification %");
+            range_input(ui, &mut search.min_magnification, &mut search.max_magnification);
+            ui.end_row();
+
+            ui.label("Energy");
+            range_input(ui, &mut search.min_energy, &mut search.max_energy);
+            ui.end_row();
+
+            ui.label("Crowns");
+            ui.horizontal(|ui| {
+                ui.selectable_value(&mut search.crowns, None, "Any");
+                for crowns in 1..=4 {
+                    ui.selectable_value(&mut search.crowns, Some(crowns), crowns.to_string());
+                }
+            });
+            ui.end_row();
+
+            flag_row(ui, "Boss", &mut search.boss);
+            flag_row(ui, "Special Rules", &mut search.special_rules);
+            flag_row(ui, "Unit Restrictions", &mut search.restricted);
+            flag_row(ui, "No Continues", &mut search.no_continues);
+
+            ui.label("Reward");
+            ui.add(egui::TextEdit::singleline(&mut search.reward)
+                .hint_text(egui::RichText::new("Item name or id").color(egui::Color32::GRAY)));
+            ui.end_row();
+        });
+}
+
+fn range_input(ui: &mut egui::Ui, min: &mut u32, max: &mut u32) {
+    ui.horizontal(|ui| {
+        ui.add(egui::DragValue::new(min).range(0..=u32::MAX));
+        ui.label("to");
+        ui.add(egui::DragValue::new(max).range(0..=u32::MAX));
+        ui.label(egui::RichText::new("0 = no limit").weak());
+    });
+}
+
+fn flag_row(ui: &mut egui::Ui, label: &str, flag: &mut FlagFilter) {
+    ui.label(label);
+    ui.horizontal(|ui| {
+        for option in FlagFilter::ALL {
+            ui.selectable_value(flag, option, option.label());
+        }
+    });
+    ui.end_row();
+}
@@ -1,5 +1,6 @@
 use eframe::egui;
 use crate::features::stage::logic::state::StageListState;
+use crate::features::stage::logic::preview;
 
 pub fn draw(ctx: &egui::Context, ui: &mut egui::Ui, state: &mut StageListState) {
     let Some(stage_id) = &state.selected_stage else {
@@ -17,8 +18,12 @@ pub fn draw(ctx: &egui::Context, ui: &mut egui::Ui, state: &mut StageListState)
     let active_language_priority_array = &state.active_language_priority;
     
     let enemy_registry = &state.enemy_registry;
+    let threats = &state.threats;
     let enemy_name_registry = &state.enemy_name_registry; // <-- Grab it from state
     let texture_cache = &mut state.enemy_texture_cache;
+    let background_registry = &state.background_registry;
+    let battlefield_preview = &mut state.battlefield_preview;
+    let preview_status = &mut state.preview_status;
     
     let Some(stage) = state.registry.stages.get(stage_id) else { return; };
 
@@ -43,6 +48,15 @@ pub fn draw(ctx: &egui::Context, ui: &mut egui::Ui, state: &mut StageListState)
 
                     super::info::draw(ui, stage);
                     ui.add_space(20.0);
+
+                    if let Some(threat) = threats.get(stage_id) {
+                        super::info::draw_threat(ui, threat);
+                        ui.add_space(20.0);
+                    }
+
+                    let battlefield = preview::ensure(battlefield_preview, ctx, stage, background_registry, enemy_registry, active_language_priority_array);
+                    super::preview::draw(ui, battlefield, preview_status);
+                    ui.add_space(20.0);
                     
                     super::treasure::draw(
                         ctx, 
@@ -3,19 +3,19 @@ use std::path::Path;
 use crate::global::utils;
 
 // Math Helpers
-fn gcd(number1: i32, number2: i32) -> i32 {
+fn gcd(number1: i64, number2: i64) -> i64 {
     if number2 == 0 { number1 } else { gcd(number2, number1 % number2) }
 }
 
-fn lcm(number1: i32, number2: i32) -> i64 {
+pub fn lcm(number1: i64, number2: i64) -> i64 {
     if number1 == 0 || number2 == 0 { 
         0 
     } else { 
-        (number1 as i64 * number2 as i64).abs() / gcd(number1, number2) as i64 
+        (number1 / gcd(number1, number2)).saturating_mul(number2).abs()
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Keyframe {
     pub frame: i32,
     pub value: i32,
@@ -23,14 +23,32 @@ pub struct Keyframe {
     pub ease_power: i32,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct AnimModification {
     pub part_id: usize,
     pub modification_type: i32,
     pub loop_count: i32,
     pub keyframes: Vec<Keyframe>,
-    #[allow(dead_code)] pub min_frame: i32,
-    #[allow(dead_code)] pub max_frame: i32,
+    pub min_frame: i32,
+    pub max_frame: i32,
+    // Header columns past the first five are kept as-is for saving
+    pub trailing: Vec<String>,
+}
+
+impl AnimModification {
+    // (settle frame, period): from the settle frame on the track repeats every `period` frames
+    // Mirrors the animator, any loop count other than 1 wraps forever
+    pub fn cycle(&self) -> (i32, i32) {
+        let (Some(first_keyframe), Some(last_keyframe)) = (self.keyframes.first(), self.keyframes.last()) else { return (0, 1); };
+        let duration = last_keyframe.frame - first_keyframe.frame;
+        if self.loop_count == 1 {
+            (last_keyframe.frame.max(0), 1)
+        } else if duration > 0 {
+            (0, duration)
+        } else {
+            (0, 1)
+        }
+    }
 }
 
 #[derive(Clone, Debug, Default)]
@@ -113,49 +131,70 @@ impl Animation {
                     keyframes,
                     min_frame,
                     max_frame,
+                    trailing: parts[5..].iter().map(|part| part.trim().to_string()).collect(),
                 });
             }
         }
 
-        let mut max_len = 0;
-        for curve in &curves {
-            if let Some(last_keyframe) = curve.keyframes.last() {
-                if last_keyframe.frame > max_len { max_len = last_keyframe.frame; }
-            }
-        }
+        let mut animation = Self { curves, max_frame: 0 };
+        animation.refresh_max_frame();
+        Some(animation)
+    }
 
-        Some(Self { curves, max_frame: max_len })
+    pub fn refresh_max_frame(&mut self) {
+        self.max_frame = self.curves.iter()
+            .filter_map(|curve| curve.keyframes.last().map(|keyframe| keyframe.frame))
+            .max()
+            .unwrap_or(0)
+            .max(0);
     }
 
-    pub fn calculate_true_loop(&self) -> Option<i32> {
-        let mut overall_lcm: i64 = 1;
-        let mut found_looping_part = false;
-        
-        for curve in &self.curves {
-            if curve.loop_count == 1 {
-                return None;
-            }
+    pub fn to_maanim_string(&self) -> String {
+        let mut lines = vec!["[modelanim:animation]".to_string(), "1".to_string(), self.curves.len().to_string()];
 
-            if curve.loop_count != 1 {
-                if let (Some(first_keyframe), Some(last_keyframe)) = (curve.keyframes.first(), curve.keyframes.last()) {
-                    let duration = (last_keyframe.frame - first_keyframe.frame) as i32; 
-                    if duration > 0 {
-                        overall_lcm = lcm(overall_lcm as i32, duration);
-                        found_looping_part = true;
-                    }
-                }
+        for curve in &self.curves {
+            let mut header = vec![
+                curve.part_id.to_string(),
+                curve.modification_type.to_string(),
+                curve.loop_count.to_string(),
+                curve.min_frame.to_string(),
+                curve.max_frame.to_string(),
+            ];
+            header.extend(curve.trailing.iter().cloned());
+            lines.push(header.join(","));
+            lines.push(curve.keyframes.len().to_string());
+
+            for keyframe in &curve.keyframes {
+                lines.push(format!("{},{},{},{}", keyframe.frame, keyframe.value, keyframe.ease_mode, keyframe.ease_power));
             }
         }
-        
-        if !found_looping_part {
-            return Some(self.max_frame);
-        }
-        
-        if overall_lcm > 999_999 {
-            return None; 
+
+        lines.join("\n") + "\n"
+    }
+
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
         }
+        fs::write(path, self.to_maanim_string())
+    }
+
+    // (loop start, loop length) worked out from the curves, one-shot tracks push the start back
+    // until they have settled and looping tracks combine into the length
+    pub fn loop_cycle(&self) -> (i32, i64) {
+        self.curves.iter()
+            .map(AnimModification::cycle)
+            .fold((0, 1), |(start, length), (settle, period)| (start.max(settle), lcm(length, period as i64)))
+    }
 
-        Some(std::cmp::max(overall_lcm as i32, self.max_frame))
+    // Where the first cycle ends, never short of the last keyframe, None when it's too long to play
+    pub fn calculate_true_loop(&self) -> Option<i32> {
+        let (start, length) = self.loop_cycle();
+        let end = (start as i64).saturating_add(length);
+        if end > 999_999 {
+            return None;
+        }
+        Some(std::cmp::max(end as i32, self.max_frame))
     }
 
     pub fn scan_duration(file_content: &str) -> i32 {
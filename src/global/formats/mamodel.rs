@@ -19,7 +19,7 @@ pub struct ModelPart {
     pub glow_mode: i32,
     pub flip_x: bool,
     pub flip_y: bool,
-    #[allow(dead_code)] pub name: String,
+    pub name: String,
 }
 
 impl Default for ModelPart {
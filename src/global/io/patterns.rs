@@ -17,6 +17,12 @@ pub const GATYA_ITEM_D_PATTERN: &str = r"^gatyaitemD_(\d{2,3})_([fz])\.png$";
 pub const GATYA_ITEM_BUY_PATTERN: &str = r"^Gatyaitembuy\.csv$";
 pub const GATYA_ITEM_NAME_PATTERN: &str = r"^GatyaitemName(?:_([a-z]{2}))?\.csv$";
 
+// Gatya Banners
+pub const GATYA_DATA_SET_PATTERN: &str = r"^GatyaDataSet[NRE]1\.csv$";
+
+// Event Schedules
+pub const EVENT_SCHEDULE_PATTERN: &str = r"^(gatya|sale|item)\.tsv$";
+
 // Country Codes
 pub const GLOBAL_CODES: &[&str] = &["de", "en", "es", "fr", "it", "th"];
 pub const REGION_CODES: &[&str] = &["en", "jp", "kr", "tw"];
@@ -90,4 +90,15 @@ pub fn process_markdown(ui: &mut egui::Ui, raw_text: &str) {
             }
         });
     }
-}
\ No newline at end of file
+}
+
+// Groups thousands with commas, "1234567" becomes "1,234,567"
+pub fn format_number(value: i64) -> String {
+    let digits = value.abs().to_string();
+    let mut grouped = String::new();
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) { grouped.push(','); }
+        grouped.push(digit);
+    }
+    if value < 0 { format!("-{}", grouped) } else { grouped }
+}